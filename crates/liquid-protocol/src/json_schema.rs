@@ -0,0 +1,43 @@
+//! JSON Schema Export
+//!
+//! Generates a JSON Schema (draft 2020-12) document for Liquid Protocol v1.0
+//! directly from the serde types in [`crate::schema`], so the document always
+//! matches what the Rust side accepts (including `xAxis`/`yAxis` renames and
+//! the `type`-tagged `Layout`/`Component` enums).
+
+use crate::schema::LiquidViewSchema;
+use serde_json::Value;
+
+/// JSON Schema dialect used by the generated document
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Generates the JSON Schema for [`LiquidViewSchema`]
+///
+/// Nested types are emitted under `$defs` and referenced with `$ref`.
+pub fn liquid_view_json_schema() -> Value {
+    let schema = schemars::schema_for!(LiquidViewSchema);
+    schema.to_value()
+}
+
+/// Generates the JSON Schema for [`LiquidViewSchema`] as a pretty-printed string
+pub fn liquid_view_json_schema_string() -> String {
+    serde_json::to_string_pretty(&liquid_view_json_schema())
+        .expect("JSON Schema serialization cannot fail")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_declares_draft_2020_12() {
+        let schema = liquid_view_json_schema();
+        assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+    }
+
+    #[test]
+    fn test_schema_string_roundtrip() {
+        let parsed: Value = serde_json::from_str(&liquid_view_json_schema_string()).unwrap();
+        assert_eq!(parsed, liquid_view_json_schema());
+    }
+}
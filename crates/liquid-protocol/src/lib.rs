@@ -3,9 +3,11 @@
 //! This crate provides Rust type definitions and validators for the Liquid Protocol,
 //! mirroring the TypeScript specification for cross-language compatibility.
 
+pub mod json_schema;
 pub mod schema;
 pub mod validator;
 
 // Re-export main types
+pub use json_schema::{liquid_view_json_schema, liquid_view_json_schema_string};
 pub use schema::*;
-pub use validator::{SchemaValidator, ValidationError, ValidationResult};
+pub use validator::{FieldCatalog, SchemaValidator, ValidationError, ValidationResult};
@@ -6,6 +6,7 @@
 //! This module provides Rust type definitions that mirror the TypeScript
 //! protocol specification for cross-language compatibility.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Protocol version
@@ -13,9 +14,10 @@ use serde::{Deserialize, Serialize};
 pub type ProtocolVersion = String;
 
 /// Root schema object defining complete UI structure
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct LiquidViewSchema {
     /// Protocol version
+    #[schemars(extend("const" = "1.0"))]
     pub version: ProtocolVersion,
     /// UI layout structure
     pub layout: Layout,
@@ -25,25 +27,61 @@ pub struct LiquidViewSchema {
 }
 
 /// Layout types
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Layout {
     /// CSS Grid-based layout
     Grid {
         props: GridLayoutProps,
-        children: Vec<Component>,
+        children: Vec<LayoutChild>,
     },
     /// Flexbox-based layout
     Stack {
         props: StackLayoutProps,
-        children: Vec<Component>,
+        children: Vec<LayoutChild>,
     },
 }
 
+impl Layout {
+    /// Child nodes of the layout
+    pub fn children(&self) -> &[LayoutChild] {
+        match self {
+            Layout::Grid { children, .. } => children,
+            Layout::Stack { children, .. } => children,
+        }
+    }
+}
+
+/// Layout child: either a nested layout or a component
+///
+/// Both variants are tagged by `type` with disjoint values
+/// (`grid`/`stack` vs. `chart`/`table`), so no extra wrapper is needed on the wire.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum LayoutChild {
+    /// Nested layout
+    Layout(Layout),
+    /// UI component
+    Component(Component),
+}
+
+impl From<Layout> for LayoutChild {
+    fn from(layout: Layout) -> Self {
+        LayoutChild::Layout(layout)
+    }
+}
+
+impl From<Component> for LayoutChild {
+    fn from(component: Component) -> Self {
+        LayoutChild::Component(component)
+    }
+}
+
 /// Grid layout properties
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct GridLayoutProps {
     /// Number of columns (must be >= 1)
+    #[schemars(range(min = 1))]
     pub columns: u32,
     /// Grid gap in pixels (default: 16)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,7 +89,7 @@ pub struct GridLayoutProps {
 }
 
 /// Stack layout properties
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct StackLayoutProps {
     /// Stack direction
     pub direction: StackDirection,
@@ -61,7 +99,7 @@ pub struct StackLayoutProps {
 }
 
 /// Stack direction
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum StackDirection {
     Horizontal,
@@ -69,7 +107,7 @@ pub enum StackDirection {
 }
 
 /// UI Component types
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Component {
     /// Chart component (Recharts integration)
@@ -82,7 +120,7 @@ pub enum Component {
         #[serde(skip_serializing_if = "Option::is_none", rename = "xAxis")]
         x_axis: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none", rename = "yAxis")]
-        y_axis: Option<String>,
+        y_axis: Option<AxisFields>,
     },
     /// Table component
     Table {
@@ -93,11 +131,68 @@ pub enum Component {
         columns: Vec<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         sortable: Option<bool>,
+        /// How the table pages through its data source (page size is the source's `limit`)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pagination: Option<PaginationMode>,
     },
 }
 
+/// Field(s) bound to a chart axis (a list draws one series per field)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum AxisFields {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl AxisFields {
+    /// Bound fields with their JSON path suffix ("" or "[index]")
+    pub fn fields(&self) -> Vec<(String, &str)> {
+        match self {
+            AxisFields::Single(field) => vec![(String::new(), field.as_str())],
+            AxisFields::Multiple(fields) => fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| (format!("[{}]", index), field.as_str()))
+                .collect(),
+        }
+    }
+}
+
+impl From<&str> for AxisFields {
+    fn from(field: &str) -> Self {
+        AxisFields::Single(field.to_string())
+    }
+}
+
+impl From<String> for AxisFields {
+    fn from(field: String) -> Self {
+        AxisFields::Single(field)
+    }
+}
+
+/// Table paging controls
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaginationMode {
+    /// Numbered pages (`{"offset": n}`)
+    Offset,
+    /// Next-page links following a server-issued cursor (`{"after": cursor}`)
+    Cursor,
+}
+
+impl PaginationMode {
+    /// Wire name of the mode (e.g. "cursor")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaginationMode::Offset => "offset",
+            PaginationMode::Cursor => "cursor",
+        }
+    }
+}
+
 /// Chart variants
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ChartVariant {
     Bar,
@@ -106,49 +201,198 @@ pub enum ChartVariant {
     Area,
 }
 
+impl ChartVariant {
+    /// Wire name of the chart variant (e.g. "bar")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChartVariant::Bar => "bar",
+            ChartVariant::Line => "line",
+            ChartVariant::Pie => "pie",
+            ChartVariant::Area => "area",
+        }
+    }
+}
+
 /// Data source definition
 /// Converted to ORM queries in backend
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct DataSource {
     /// Resource name (table/model name)
     pub resource: String,
-    /// Filter conditions
+    /// Filter conditions (all top-level nodes must match)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filters: Option<Vec<Filter>>,
-    /// Aggregation method
+    pub filters: Option<Vec<FilterNode>>,
+    /// Single aggregation (shorthand for one metric aliased `{field}_{type}`)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub aggregation: Option<Aggregation>,
-    /// Sort condition
+    /// Named metrics computed per group (cannot be combined with `aggregation`)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<Sort>,
-    /// Result limit
+    pub metrics: Option<Vec<Metric>>,
+    /// GROUP BY fields for `metrics`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<Vec<String>>,
+    /// Sort condition (a list orders by each key in turn)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SortKeys>,
+    /// Result limit (the page size when paging)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    /// Page of the results to return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<Page>,
+}
+
+impl DataSource {
+    /// Whether rows are aggregated (`aggregation` or `metrics`)
+    pub fn is_aggregated(&self) -> bool {
+        self.aggregation.is_some() || self.metrics.is_some()
+    }
+
+    /// Metrics computed by the source, with the `aggregation` shorthand expanded
+    pub fn output_metrics(&self) -> Vec<Metric> {
+        match (&self.aggregation, &self.metrics) {
+            (_, Some(metrics)) => metrics.clone(),
+            (Some(aggregation), None) => vec![aggregation.metric()],
+            (None, None) => Vec::new(),
+        }
+    }
+
+    /// GROUP BY fields (`group_by`, or `aggregation.by`)
+    pub fn group_by_fields(&self) -> Vec<&str> {
+        match (&self.aggregation, &self.group_by) {
+            (_, Some(group_by)) => group_by.iter().map(String::as_str).collect(),
+            (Some(aggregation), None) => aggregation.by.as_deref().into_iter().collect(),
+            (None, None) => Vec::new(),
+        }
+    }
+}
+
+/// Node of a boolean filter tree
+///
+/// Groups are single-key objects (`{"and": [...]}`, `{"or": [...]}`,
+/// `{"not": {...}}`); any other object is a [`Filter`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum FilterNode {
+    /// All child nodes must match
+    And { and: Vec<FilterNode> },
+    /// At least one child node must match
+    Or { or: Vec<FilterNode> },
+    /// The child node must not match
+    Not { not: Box<FilterNode> },
+    /// Single field condition
+    Filter(Filter),
+}
+
+impl FilterNode {
+    /// Nesting depth of the node (a single filter is depth 1)
+    pub fn depth(&self) -> usize {
+        match self {
+            FilterNode::And { and: children } | FilterNode::Or { or: children } => {
+                1 + children.iter().map(FilterNode::depth).max().unwrap_or(0)
+            }
+            FilterNode::Not { not } => 1 + not.depth(),
+            FilterNode::Filter(_) => 1,
+        }
+    }
+
+    /// All leaf filters of the tree, in document order
+    pub fn filters(&self) -> Vec<&Filter> {
+        match self {
+            FilterNode::And { and: children } | FilterNode::Or { or: children } => {
+                children.iter().flat_map(FilterNode::filters).collect()
+            }
+            FilterNode::Not { not } => not.filters(),
+            FilterNode::Filter(filter) => vec![filter],
+        }
+    }
+}
+
+impl From<Filter> for FilterNode {
+    fn from(filter: Filter) -> Self {
+        FilterNode::Filter(filter)
+    }
 }
 
 /// Filter condition
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct Filter {
     /// Field name
     pub field: String,
     /// Filter operator
     pub op: FilterOperator,
-    /// Filter value
+    /// Filter value (omitted for `is_null` / `is_not_null`)
+    #[serde(default, skip_serializing_if = "FilterValue::is_null")]
     pub value: FilterValue,
 }
 
 /// Filter value types
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(untagged)]
 pub enum FilterValue {
     String(String),
     Number(f64),
     Boolean(bool),
     Array(Vec<FilterValueScalar>),
+    /// Date relative to the time the query runs (e.g. `{"relative": "start_of_month"}`)
+    Relative(RelativeDate),
+    /// No value (`is_null` / `is_not_null`)
+    #[default]
+    Null,
+}
+
+impl FilterValue {
+    /// Whether the value is absent
+    pub fn is_null(&self) -> bool {
+        matches!(self, FilterValue::Null)
+    }
+}
+
+/// Date relative to the time the query runs
+///
+/// Resolved by the backend into the start of a calendar day in the dashboard
+/// timezone, so saved dashboards keep meaning "this month" as time passes.
+/// Combine with `gte` / `lt` to express periods, e.g. "last 30 days" is
+/// `gte {"relative": "last_n_days", "n": 30}`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(tag = "relative", rename_all = "snake_case", deny_unknown_fields)]
+pub enum RelativeDate {
+    /// Start of today
+    Today,
+    /// Start of yesterday
+    Yesterday,
+    /// Start of the current week (Monday)
+    StartOfWeek,
+    /// First day of the current month
+    StartOfMonth,
+    /// First day of the current quarter
+    StartOfQuarter,
+    /// First day of the current year
+    StartOfYear,
+    /// Start of the day `n` days before today
+    LastNDays { n: u32 },
+    /// Same day `n` months before today (clamped to the end of shorter months)
+    LastNMonths { n: u32 },
+}
+
+impl RelativeDate {
+    /// Wire name of the relative date (e.g. "last_n_days")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelativeDate::Today => "today",
+            RelativeDate::Yesterday => "yesterday",
+            RelativeDate::StartOfWeek => "start_of_week",
+            RelativeDate::StartOfMonth => "start_of_month",
+            RelativeDate::StartOfQuarter => "start_of_quarter",
+            RelativeDate::StartOfYear => "start_of_year",
+            RelativeDate::LastNDays { .. } => "last_n_days",
+            RelativeDate::LastNMonths { .. } => "last_n_months",
+        }
+    }
 }
 
 /// Scalar filter values (for array elements)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(untagged)]
 pub enum FilterValueScalar {
     String(String),
@@ -156,7 +400,7 @@ pub enum FilterValueScalar {
 }
 
 /// Filter operators
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum FilterOperator {
     /// Equal
@@ -173,12 +417,73 @@ pub enum FilterOperator {
     Lte,
     /// In array
     In,
-    /// Partial match
+    /// Not in array
+    NotIn,
+    /// Inclusive range (`[low, high]`)
+    Between,
+    /// Value is NULL (no value)
+    IsNull,
+    /// Value is not NULL (no value)
+    IsNotNull,
+    /// Partial match (case-sensitive)
     Contains,
+    /// Partial match (case-insensitive)
+    #[serde(rename = "icontains")]
+    IContains,
+    /// Prefix match (case-sensitive)
+    StartsWith,
+    /// Suffix match (case-sensitive)
+    EndsWith,
+}
+
+impl FilterOperator {
+    /// Wire name of the filter operator (e.g. "not_in")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterOperator::Eq => "eq",
+            FilterOperator::Neq => "neq",
+            FilterOperator::Gt => "gt",
+            FilterOperator::Gte => "gte",
+            FilterOperator::Lt => "lt",
+            FilterOperator::Lte => "lte",
+            FilterOperator::In => "in",
+            FilterOperator::NotIn => "not_in",
+            FilterOperator::Between => "between",
+            FilterOperator::IsNull => "is_null",
+            FilterOperator::IsNotNull => "is_not_null",
+            FilterOperator::Contains => "contains",
+            FilterOperator::IContains => "icontains",
+            FilterOperator::StartsWith => "starts_with",
+            FilterOperator::EndsWith => "ends_with",
+        }
+    }
+
+    /// Shape of the value the operator takes
+    pub fn value_shape(&self) -> FilterValueShape {
+        match self {
+            FilterOperator::In | FilterOperator::NotIn => FilterValueShape::Array,
+            FilterOperator::Between => FilterValueShape::Range,
+            FilterOperator::IsNull | FilterOperator::IsNotNull => FilterValueShape::None,
+            _ => FilterValueShape::Scalar,
+        }
+    }
+}
+
+/// Value shape expected by a filter operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterValueShape {
+    /// String, number or boolean
+    Scalar,
+    /// Array of strings or numbers
+    Array,
+    /// Two-element array `[low, high]`
+    Range,
+    /// No value
+    None,
 }
 
 /// Aggregation method
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct Aggregation {
     /// Aggregation type
     #[serde(rename = "type")]
@@ -188,10 +493,84 @@ pub struct Aggregation {
     /// GROUP BY field (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub by: Option<String>,
+    /// Truncate the `by` date/timestamp field to calendar buckets (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<TimeBucket>,
+}
+
+/// Calendar bucket applied to the GROUP BY field
+///
+/// Each group is keyed by the first day of its bucket (`YYYY-MM-DD`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TimeBucket {
+    /// Bucket size
+    pub unit: TimeUnit,
+    /// IANA timezone the calendar is evaluated in (default: the backend timezone)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Emit empty buckets between the first and last group
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub zero_fill: bool,
+}
+
+/// Calendar units for time buckets (weeks start on Monday)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeUnit {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl TimeUnit {
+    /// Wire name of the unit (e.g. "month")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeUnit::Day => "day",
+            TimeUnit::Week => "week",
+            TimeUnit::Month => "month",
+            TimeUnit::Quarter => "quarter",
+            TimeUnit::Year => "year",
+        }
+    }
+}
+
+impl Aggregation {
+    /// Output column name of the aggregated value (e.g. "amount_sum")
+    pub fn alias(&self) -> String {
+        format!("{}_{}", self.field, self.agg_type.as_str())
+    }
+
+    /// The aggregation as a named metric
+    pub fn metric(&self) -> Metric {
+        Metric {
+            alias: self.alias(),
+            agg_type: self.agg_type.clone(),
+            field: self.field.clone(),
+        }
+    }
+}
+
+/// Named aggregated value
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct Metric {
+    /// Output column name (referenced by chart axes, table columns and sort)
+    pub alias: String,
+    /// Aggregation type
+    #[serde(rename = "type")]
+    pub agg_type: AggregationType,
+    /// Target field for aggregation
+    pub field: String,
 }
 
 /// Aggregation types
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// Serialized as a string (`"sum"`), except `percentile` which carries its
+/// rank: `{"percentile": 90}`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AggregationType {
     Sum,
@@ -199,25 +578,132 @@ pub enum AggregationType {
     Count,
     Min,
     Max,
+    /// Number of distinct non-null values
+    CountDistinct,
+    /// Continuous median (50th percentile)
+    Median,
+    /// Continuous percentile, p in 0..=100
+    Percentile(#[schemars(range(min = 0, max = 100))] f64),
+}
+
+impl AggregationType {
+    /// Wire name of the aggregation type (e.g. "sum")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AggregationType::Sum => "sum",
+            AggregationType::Avg => "avg",
+            AggregationType::Count => "count",
+            AggregationType::Min => "min",
+            AggregationType::Max => "max",
+            AggregationType::CountDistinct => "count_distinct",
+            AggregationType::Median => "median",
+            AggregationType::Percentile(_) => "percentile",
+        }
+    }
+
+    /// Percentile rank as a fraction (0.0..=1.0) for median / percentile
+    pub fn percentile_fraction(&self) -> Option<f64> {
+        match self {
+            AggregationType::Median => Some(0.5),
+            AggregationType::Percentile(p) => Some(p / 100.0),
+            _ => None,
+        }
+    }
+
+    /// Whether the rank of a percentile lies in 0..=100
+    pub fn is_valid(&self) -> bool {
+        match self {
+            AggregationType::Percentile(p) => (0.0..=100.0).contains(p),
+            _ => true,
+        }
+    }
 }
 
 /// Sort condition
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct Sort {
     /// Sort field
     pub field: String,
     /// Sort direction
     pub direction: SortDirection,
+    /// Position of null values (database default when omitted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<NullsOrder>,
 }
 
 /// Sort directions
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SortDirection {
     Asc,
     Desc,
 }
 
+/// Page of a data source's results
+///
+/// Cursors are opaque tokens issued and signed by the backend; they only
+/// resume the query (and user) they were issued for.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Page {
+    /// Skip the first `offset` rows
+    Offset(u32),
+    /// Continue after the last row of the page that issued the cursor
+    After(String),
+}
+
+/// Position of null values in a sort
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NullsOrder {
+    First,
+    Last,
+}
+
+impl NullsOrder {
+    /// Wire name of the position (e.g. "last")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NullsOrder::First => "first",
+            NullsOrder::Last => "last",
+        }
+    }
+}
+
+/// Sort key(s) of a data source (later keys break ties of earlier ones)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum SortKeys {
+    Single(Sort),
+    Multiple(Vec<Sort>),
+}
+
+impl SortKeys {
+    /// Sort keys with their JSON path suffix ("" or "[index]")
+    pub fn keys(&self) -> Vec<(String, &Sort)> {
+        match self {
+            SortKeys::Single(sort) => vec![(String::new(), sort)],
+            SortKeys::Multiple(sorts) => sorts
+                .iter()
+                .enumerate()
+                .map(|(index, sort)| (format!("[{}]", index), sort))
+                .collect(),
+        }
+    }
+}
+
+impl From<Sort> for SortKeys {
+    fn from(sort: Sort) -> Self {
+        SortKeys::Single(sort)
+    }
+}
+
+impl From<Vec<Sort>> for SortKeys {
+    fn from(sorts: Vec<Sort>) -> Self {
+        SortKeys::Multiple(sorts)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,6 +728,57 @@ mod tests {
         assert_eq!(json, "\"eq\"");
     }
 
+    #[test]
+    fn test_filter_operator_as_str_matches_serde() {
+        for op in [
+            FilterOperator::Eq,
+            FilterOperator::Neq,
+            FilterOperator::Gt,
+            FilterOperator::Gte,
+            FilterOperator::Lt,
+            FilterOperator::Lte,
+            FilterOperator::In,
+            FilterOperator::NotIn,
+            FilterOperator::Between,
+            FilterOperator::IsNull,
+            FilterOperator::IsNotNull,
+            FilterOperator::Contains,
+            FilterOperator::IContains,
+            FilterOperator::StartsWith,
+            FilterOperator::EndsWith,
+        ] {
+            let json = serde_json::to_string(&op).unwrap();
+            assert_eq!(json, format!("\"{}\"", op.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_filter_without_value() {
+        let filter: Filter = serde_json::from_str(r#"{"field":"memo","op":"is_null"}"#).unwrap();
+        assert_eq!(filter.value, FilterValue::Null);
+        assert_eq!(
+            serde_json::to_string(&filter).unwrap(),
+            r#"{"field":"memo","op":"is_null"}"#
+        );
+    }
+
+    #[test]
+    fn test_relative_date_filter_value() {
+        let value: FilterValue =
+            serde_json::from_str(r#"{"relative":"last_n_days","n":30}"#).unwrap();
+        assert_eq!(
+            value,
+            FilterValue::Relative(RelativeDate::LastNDays { n: 30 })
+        );
+
+        let value: FilterValue = serde_json::from_str(r#"{"relative":"start_of_month"}"#).unwrap();
+        assert_eq!(value, FilterValue::Relative(RelativeDate::StartOfMonth));
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            r#"{"relative":"start_of_month"}"#
+        );
+    }
+
     #[test]
     fn test_aggregation_type_serialization() {
         let agg = AggregationType::Sum;
@@ -249,6 +786,90 @@ mod tests {
         assert_eq!(json, "\"sum\"");
     }
 
+    #[test]
+    fn test_aggregation_type_as_str_matches_serde() {
+        for agg in [
+            AggregationType::Sum,
+            AggregationType::Avg,
+            AggregationType::Count,
+            AggregationType::Min,
+            AggregationType::Max,
+            AggregationType::CountDistinct,
+            AggregationType::Median,
+        ] {
+            let json = serde_json::to_string(&agg).unwrap();
+            assert_eq!(json, format!("\"{}\"", agg.as_str()));
+        }
+
+        // Percentile carries its rank under the wire name
+        let percentile = AggregationType::Percentile(90.0);
+        let json = serde_json::to_value(&percentile).unwrap();
+        assert_eq!(json, serde_json::json!({ percentile.as_str(): 90.0 }));
+    }
+
+    #[test]
+    fn test_layout_child_deserializes_by_type() {
+        let child: LayoutChild = serde_json::from_str(
+            r#"{"type":"stack","props":{"direction":"vertical"},"children":[]}"#,
+        )
+        .unwrap();
+        assert!(matches!(child, LayoutChild::Layout(Layout::Stack { .. })));
+
+        let child: LayoutChild =
+            serde_json::from_str(r#"{"type":"table","columns":["a"]}"#).unwrap();
+        assert!(matches!(
+            child,
+            LayoutChild::Component(Component::Table { .. })
+        ));
+    }
+
+    #[test]
+    fn test_chart_variant_as_str_matches_serde() {
+        for variant in [
+            ChartVariant::Bar,
+            ChartVariant::Line,
+            ChartVariant::Pie,
+            ChartVariant::Area,
+        ] {
+            let json = serde_json::to_string(&variant).unwrap();
+            assert_eq!(json, format!("\"{}\"", variant.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_aggregation_alias() {
+        let aggregation = Aggregation {
+            agg_type: AggregationType::Sum,
+            field: "amount".to_string(),
+            by: None,
+            bucket: None,
+        };
+        assert_eq!(aggregation.alias(), "amount_sum");
+    }
+
+    #[test]
+    fn test_filter_node_deserializes_groups() {
+        let node: FilterNode = serde_json::from_str(
+            r#"{"and":[{"or":[{"field":"a","op":"eq","value":1}]},{"not":{"field":"b","op":"eq","value":"x"}}]}"#,
+        )
+        .unwrap();
+        assert!(matches!(node, FilterNode::And { .. }));
+        assert_eq!(node.depth(), 3);
+        let fields: Vec<&str> = node.filters().iter().map(|f| f.field.as_str()).collect();
+        assert_eq!(fields, vec!["a", "b"]);
+
+        let leaf: FilterNode =
+            serde_json::from_str(r#"{"field":"a","op":"in","value":[1,2]}"#).unwrap();
+        assert!(matches!(leaf, FilterNode::Filter(_)));
+        assert_eq!(leaf.depth(), 1);
+    }
+
+    #[test]
+    fn test_filter_node_rejects_mixed_group_keys() {
+        let result = serde_json::from_str::<FilterNode>(r#"{"and":[],"or":[]}"#);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_sort_direction_serialization() {
         let dir = SortDirection::Asc;
@@ -3,6 +3,9 @@
 //! Implements strict validation according to Protocol Specification v1.0
 
 use crate::schema::*;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::sync::Arc;
 use thiserror::Error;
 
 /// Validation error types
@@ -26,9 +29,21 @@ pub enum ValidationError {
     #[error("Invalid filter operator at {path}")]
     InvalidFilterOp { path: String },
 
+    #[error(
+        "Invalid relative date at {path}. Valid values: today, yesterday, start_of_week, \
+         start_of_month, start_of_quarter, start_of_year, last_n_days (with n), last_n_months (with n)"
+    )]
+    InvalidRelativeDate { path: String },
+
     #[error("Invalid aggregation type at {path}")]
     InvalidAggregationType { path: String },
 
+    #[error("Percentile must be between 0 and 100 at {path}")]
+    InvalidPercentile { path: String },
+
+    #[error("Invalid time bucket unit at {path}. Valid values: day, week, month, quarter, year")]
+    InvalidTimeUnit { path: String },
+
     #[error("Grid columns must be >= 1, got: {value} at {path}")]
     InvalidGridColumns { value: u32, path: String },
 
@@ -44,7 +59,10 @@ pub enum ValidationError {
     #[error("Invalid type at {path}: {message}")]
     InvalidType { path: String, message: String },
 
-    #[error("Filter operator 'in' requires an array value at {path}")]
+    #[error(
+        "Filter value does not match its operator at {path} \
+         (in/not_in: array, between: [low, high], is_null/is_not_null: no value, others: scalar)"
+    )]
     InvalidFilterValueType { path: String },
 
     #[error("Filter must have a {field} property at {path}")]
@@ -53,14 +71,79 @@ pub enum ValidationError {
     #[error("Aggregation must have a {field} field at {path}")]
     MissingAggregationField { field: String, path: String },
 
+    #[error("DataSource cannot combine aggregation with metrics or group_by at {path}")]
+    ConflictingAggregation { path: String },
+
+    #[error("DataSource metrics cannot be empty at {path}")]
+    EmptyMetrics { path: String },
+
+    #[error(
+        "Metric alias '{alias}' must start with a letter or underscore and contain only \
+         letters, digits and underscores at {path}"
+    )]
+    InvalidMetricAlias { alias: String, path: String },
+
+    #[error(
+        "Metric alias '{alias}' is already used by another metric or group_by field at {path}"
+    )]
+    DuplicateMetricAlias { alias: String, path: String },
+
     #[error("Invalid sort direction at {path}. Valid values: asc, desc")]
     InvalidSortDirection { path: String },
 
     #[error("Sort must have a {field} property at {path}")]
     MissingSortField { field: String, path: String },
 
+    #[error("Invalid sort nulls position at {path}. Valid values: first, last")]
+    InvalidSortNulls { path: String },
+
+    #[error("Sort field '{field}' is not a group_by field or metric alias at {path}")]
+    UnknownSortField { field: String, path: String },
+
     #[error("Limit must be a positive integer, got: {value} at {path}")]
     InvalidLimit { value: String, path: String },
+
+    #[error("Page must be {{\"offset\": n}} or {{\"after\": cursor}} at {path}")]
+    InvalidPage { path: String },
+
+    #[error("Invalid pagination mode at {path}. Valid values: offset, cursor")]
+    InvalidPaginationMode { path: String },
+
+    #[error("Pagination requires a limit (page size) at {path}")]
+    PaginationRequiresLimit { path: String },
+
+    #[error("Cursor pagination requires a sort on a non-aggregated data_source at {path}")]
+    CursorRequiresSort { path: String },
+
+    #[error("Layout nesting exceeds maximum depth of {max_depth} at {path}")]
+    LayoutTooDeep { max_depth: usize, path: String },
+
+    #[error("Filter nesting exceeds maximum depth of {max_depth} at {path}")]
+    FilterTooDeep { max_depth: usize, path: String },
+
+    #[error("Filter group '{group}' must contain at least one filter at {path}")]
+    EmptyFilterGroup { group: String, path: String },
+
+    #[error("Chart variant {variant} requires an aggregated data_source at {path}")]
+    ChartRequiresAggregation { variant: String, path: String },
+
+    #[error("Chart {axis} field '{field}' is not produced by data_source {data_source} at {path}")]
+    UnknownChartAxisField {
+        axis: String,
+        field: String,
+        data_source: String,
+        path: String,
+    },
+
+    #[error("Unknown resource: {resource} at {path}")]
+    UnknownResource { resource: String, path: String },
+
+    #[error("Unknown field: {resource}.{field} at {path}")]
+    UnknownField {
+        resource: String,
+        field: String,
+        path: String,
+    },
 }
 
 /// Validation result
@@ -95,12 +178,43 @@ impl ValidationResult {
 
 const SUPPORTED_VERSIONS: &[&str] = &["1.0"];
 
+/// Maximum nesting depth of layouts (the root layout is depth 1)
+pub const MAX_LAYOUT_DEPTH: usize = 4;
+
+/// Maximum nesting depth of filter groups (a top-level filter is depth 1)
+pub const MAX_FILTER_DEPTH: usize = 4;
+
+/// Keys of the `and`/`or`/`not` filter groups
+const FILTER_GROUP_KEYS: &[&str] = &["and", "or", "not"];
+
+/// Catalog of queryable resources and their fields
+///
+/// Lets the validator check field references without depending on the backend
+/// (e.g. implemented by the backend's resource registry).
+pub trait FieldCatalog: Send + Sync {
+    /// Whether the resource exists
+    fn has_resource(&self, resource: &str) -> bool;
+
+    /// Whether the resource exposes the field
+    fn has_field(&self, resource: &str, field: &str) -> bool;
+}
+
 /// Schema validator
-pub struct SchemaValidator;
+#[derive(Default)]
+pub struct SchemaValidator {
+    catalog: Option<Arc<dyn FieldCatalog>>,
+}
 
 impl SchemaValidator {
     pub fn new() -> Self {
-        Self
+        Self { catalog: None }
+    }
+
+    /// Also checks resources and fields against the given catalog
+    pub fn with_field_catalog(catalog: impl FieldCatalog + 'static) -> Self {
+        Self {
+            catalog: Some(Arc::new(catalog)),
+        }
     }
 
     /// Validates a Liquid Protocol schema
@@ -113,47 +227,630 @@ impl SchemaValidator {
         }
 
         // Validate layout
-        self.validate_layout(&schema.layout, &mut errors);
+        self.validate_layout(&schema.layout, &mut errors, "layout", 1);
 
         // Validate data_sources
         self.validate_data_sources(&schema.data_sources, &mut errors);
 
         // Cross-reference validation
         self.validate_data_source_references(&schema.layout, &schema.data_sources, &mut errors);
+        self.validate_chart_bindings(&schema.layout, &schema.data_sources, &mut errors);
+        self.validate_table_pagination(&schema.layout, &schema.data_sources, &mut errors);
 
         ValidationResult::errors(errors)
     }
 
-    fn validate_layout(&self, layout: &Layout, errors: &mut Vec<ValidationError>) {
-        match layout {
-            Layout::Grid { props, children } => {
-                // Validate grid columns
-                if props.columns < 1 {
-                    errors.push(ValidationError::InvalidGridColumns {
-                        value: props.columns,
-                        path: "layout.props.columns".to_string(),
-                    });
+    /// Validates an untyped JSON document (e.g. raw AI output)
+    ///
+    /// Walks the document without deserializing it first, so every problem is
+    /// reported with its JSON path instead of stopping at the first serde error.
+    /// Documents that are structurally valid are then deserialized and checked
+    /// with [`SchemaValidator::validate`].
+    pub fn validate_json(&self, value: &Value) -> ValidationResult {
+        let mut errors = Vec::new();
+
+        let Some(root) = value.as_object() else {
+            errors.push(ValidationError::InvalidType {
+                path: "root".to_string(),
+                message: format!("schema must be an object, got {}", json_type(value)),
+            });
+            return ValidationResult::errors(errors);
+        };
+
+        // Validate version
+        match root.get("version") {
+            None => errors.push(missing_field("version", "version")),
+            Some(Value::String(version)) => {
+                if !SUPPORTED_VERSIONS.contains(&version.as_str()) {
+                    errors.push(ValidationError::UnsupportedVersion(version.clone()));
+                }
+            }
+            Some(other) => errors.push(invalid_type("version", "string", other)),
+        }
+
+        // Validate layout
+        match root.get("layout") {
+            None => errors.push(missing_field("layout", "layout")),
+            Some(layout) => self.check_layout(layout, &mut errors, "layout", 1),
+        }
+
+        // Validate data_sources (optional, defaults to {})
+        let data_source_keys: Vec<&str> = match root.get("data_sources") {
+            None => Vec::new(),
+            Some(Value::Object(data_sources)) => {
+                for (key, ds) in data_sources {
+                    self.check_data_source(ds, &mut errors, &format!("data_sources.{}", key));
+                }
+                data_sources.keys().map(String::as_str).collect()
+            }
+            Some(other) => {
+                errors.push(invalid_type("data_sources", "object", other));
+                Vec::new()
+            }
+        };
+
+        // Cross-reference validation
+        if let Some(layout) = root.get("layout") {
+            check_data_source_references(layout, &data_source_keys, &mut errors, "layout");
+        }
+
+        if !errors.is_empty() {
+            return ValidationResult::errors(errors);
+        }
+
+        match serde_json::from_value::<LiquidViewSchema>(value.clone()) {
+            Ok(schema) => self.validate(&schema),
+            Err(err) => ValidationResult::error(ValidationError::InvalidType {
+                path: "root".to_string(),
+                message: err.to_string(),
+            }),
+        }
+    }
+
+    fn check_layout(
+        &self,
+        layout: &Value,
+        errors: &mut Vec<ValidationError>,
+        path: &str,
+        depth: usize,
+    ) {
+        if depth > MAX_LAYOUT_DEPTH {
+            errors.push(ValidationError::LayoutTooDeep {
+                max_depth: MAX_LAYOUT_DEPTH,
+                path: path.to_string(),
+            });
+            return;
+        }
+        let Some(layout) = as_object(layout, errors, path) else {
+            return;
+        };
+
+        let layout_type = layout.get("type").and_then(Value::as_str);
+        if !matches!(layout_type, Some("grid" | "stack")) {
+            errors.push(ValidationError::InvalidLayoutType {
+                path: format!("{}.type", path),
+            });
+        }
+
+        // Validate props
+        let props_path = format!("{}.props", path);
+        match layout.get("props") {
+            None if layout_type.is_some() => errors.push(missing_field("props", &props_path)),
+            None => {}
+            Some(props) => {
+                if let Some(props) = as_object(props, errors, &props_path) {
+                    match layout_type {
+                        Some("grid") => check_grid_props(props, errors, &props_path),
+                        Some("stack") => check_stack_props(props, errors, &props_path),
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // Validate children
+        let children_path = format!("{}.children", path);
+        match layout.get("children") {
+            None => errors.push(missing_field("children", &children_path)),
+            Some(Value::Array(children)) => {
+                for (index, child) in children.iter().enumerate() {
+                    let child_path = format!("{}[{}]", children_path, index);
+                    if is_layout_node(child) {
+                        self.check_layout(child, errors, &child_path, depth + 1);
+                    } else {
+                        self.check_component(child, errors, &child_path);
+                    }
+                }
+            }
+            Some(other) => errors.push(invalid_type(&children_path, "array", other)),
+        }
+    }
+
+    fn check_component(&self, component: &Value, errors: &mut Vec<ValidationError>, path: &str) {
+        let Some(component) = as_object(component, errors, path) else {
+            return;
+        };
+
+        for field in ["title", "data_source"] {
+            check_optional_string(component, field, errors, path);
+        }
+
+        match component.get("type").and_then(Value::as_str) {
+            Some("chart") => {
+                match component.get("variant") {
+                    None => errors.push(missing_field("variant", &format!("{}.variant", path))),
+                    Some(variant) => {
+                        if !is_variant::<ChartVariant>(variant) {
+                            errors.push(ValidationError::InvalidChartVariant {
+                                path: format!("{}.variant", path),
+                            });
+                        }
+                    }
+                }
+                check_optional_string(component, "xAxis", errors, path);
+                let y_axis_path = format!("{}.yAxis", path);
+                match component.get("yAxis") {
+                    None | Some(Value::String(_)) => {}
+                    Some(Value::Array(fields)) => {
+                        for (index, field) in fields.iter().enumerate() {
+                            if !field.is_string() {
+                                errors.push(invalid_type(
+                                    &format!("{}[{}]", y_axis_path, index),
+                                    "string",
+                                    field,
+                                ));
+                            }
+                        }
+                    }
+                    Some(other) => errors.push(invalid_type(
+                        &y_axis_path,
+                        "string or array of strings",
+                        other,
+                    )),
                 }
+            }
+            Some("table") => {
+                let columns_path = format!("{}.columns", path);
+                match component.get("columns") {
+                    None => errors.push(missing_field("columns", &columns_path)),
+                    Some(Value::Array(columns)) if columns.is_empty() => {
+                        errors.push(ValidationError::EmptyTableColumns { path: columns_path });
+                    }
+                    Some(Value::Array(columns)) => {
+                        for (index, column) in columns.iter().enumerate() {
+                            if !column.is_string() {
+                                errors.push(invalid_type(
+                                    &format!("{}[{}]", columns_path, index),
+                                    "string",
+                                    column,
+                                ));
+                            }
+                        }
+                    }
+                    Some(other) => errors.push(invalid_type(&columns_path, "array", other)),
+                }
+                if let Some(sortable) = component.get("sortable") {
+                    if !sortable.is_boolean() {
+                        errors.push(invalid_type(
+                            &format!("{}.sortable", path),
+                            "boolean",
+                            sortable,
+                        ));
+                    }
+                }
+                if let Some(pagination) = component.get("pagination") {
+                    if !is_variant::<PaginationMode>(pagination) {
+                        errors.push(ValidationError::InvalidPaginationMode {
+                            path: format!("{}.pagination", path),
+                        });
+                    }
+                }
+            }
+            _ => errors.push(ValidationError::InvalidComponentType {
+                path: format!("{}.type", path),
+            }),
+        }
+    }
+
+    fn check_data_source(&self, ds: &Value, errors: &mut Vec<ValidationError>, path: &str) {
+        let Some(ds) = as_object(ds, errors, path) else {
+            return;
+        };
+
+        // Validate resource
+        match ds.get("resource") {
+            Some(Value::String(resource)) if !resource.is_empty() => {}
+            Some(Value::String(_)) | None => errors.push(ValidationError::MissingResource {
+                path: format!("{}.resource", path),
+            }),
+            Some(other) => {
+                errors.push(invalid_type(&format!("{}.resource", path), "string", other))
+            }
+        }
 
-                // Validate children
-                for (index, component) in children.iter().enumerate() {
-                    self.validate_component(
-                        component,
+        // Validate filters
+        let filters_path = format!("{}.filters", path);
+        match ds.get("filters") {
+            None => {}
+            Some(Value::Array(filters)) => {
+                for (index, filter) in filters.iter().enumerate() {
+                    self.check_filter_node(
+                        filter,
                         errors,
-                        &format!("layout.children[{}]", index),
+                        &format!("{}[{}]", filters_path, index),
+                        1,
                     );
                 }
             }
-            Layout::Stack { children, .. } => {
-                // Validate children
-                for (index, component) in children.iter().enumerate() {
-                    self.validate_component(
-                        component,
+            Some(other) => errors.push(invalid_type(&filters_path, "array", other)),
+        }
+
+        // Validate aggregation
+        if let Some(aggregation) = ds.get("aggregation") {
+            self.check_aggregation(aggregation, errors, &format!("{}.aggregation", path));
+        }
+
+        // Validate metrics
+        let metrics_path = format!("{}.metrics", path);
+        match ds.get("metrics") {
+            None => {}
+            Some(Value::Array(metrics)) => {
+                for (index, metric) in metrics.iter().enumerate() {
+                    self.check_metric(metric, errors, &format!("{}[{}]", metrics_path, index));
+                }
+            }
+            Some(other) => errors.push(invalid_type(&metrics_path, "array", other)),
+        }
+
+        // Validate group_by
+        let group_by_path = format!("{}.group_by", path);
+        match ds.get("group_by") {
+            None => {}
+            Some(Value::Array(fields)) => {
+                for (index, field) in fields.iter().enumerate() {
+                    if !field.is_string() {
+                        errors.push(invalid_type(
+                            &format!("{}[{}]", group_by_path, index),
+                            "string",
+                            field,
+                        ));
+                    }
+                }
+            }
+            Some(other) => errors.push(invalid_type(&group_by_path, "array", other)),
+        }
+
+        // Validate sort (one key or a list of keys)
+        let sort_path = format!("{}.sort", path);
+        match ds.get("sort") {
+            None => {}
+            Some(Value::Array(sorts)) => {
+                for (index, sort) in sorts.iter().enumerate() {
+                    self.check_sort(sort, errors, &format!("{}[{}]", sort_path, index));
+                }
+            }
+            Some(sort) => self.check_sort(sort, errors, &sort_path),
+        }
+
+        // Validate limit (positive integer within u32)
+        if let Some(limit) = ds.get("limit") {
+            let valid = limit
+                .as_u64()
+                .is_some_and(|n| n >= 1 && n <= u64::from(u32::MAX));
+            if !valid {
+                errors.push(ValidationError::InvalidLimit {
+                    value: limit.to_string(),
+                    path: format!("{}.limit", path),
+                });
+            }
+        }
+
+        // Validate page ({"offset": n} or {"after": cursor})
+        if let Some(page) = ds.get("page") {
+            if serde_json::from_value::<Page>(page.clone()).is_err() {
+                errors.push(ValidationError::InvalidPage {
+                    path: format!("{}.page", path),
+                });
+            }
+        }
+    }
+
+    fn check_filter_node(
+        &self,
+        node: &Value,
+        errors: &mut Vec<ValidationError>,
+        path: &str,
+        depth: usize,
+    ) {
+        if depth > MAX_FILTER_DEPTH {
+            errors.push(ValidationError::FilterTooDeep {
+                max_depth: MAX_FILTER_DEPTH,
+                path: path.to_string(),
+            });
+            return;
+        }
+        let Some(object) = node.as_object() else {
+            errors.push(invalid_type(path, "object", node));
+            return;
+        };
+        if !FILTER_GROUP_KEYS
+            .iter()
+            .any(|key| object.contains_key(*key))
+        {
+            self.check_filter(node, errors, path);
+            return;
+        }
+
+        // Groups are single-key objects
+        let mut keys = object.keys();
+        let (Some(group), None) = (keys.next(), keys.next()) else {
+            errors.push(ValidationError::InvalidType {
+                path: path.to_string(),
+                message: "filter group must have exactly one of: and, or, not".to_string(),
+            });
+            return;
+        };
+        let group_path = format!("{}.{}", path, group);
+        match (group.as_str(), &object[group]) {
+            ("not", child) => self.check_filter_node(child, errors, &group_path, depth + 1),
+            (_, Value::Array(children)) if children.is_empty() => {
+                errors.push(ValidationError::EmptyFilterGroup {
+                    group: group.clone(),
+                    path: group_path,
+                });
+            }
+            (_, Value::Array(children)) => {
+                for (index, child) in children.iter().enumerate() {
+                    self.check_filter_node(
+                        child,
                         errors,
-                        &format!("layout.children[{}]", index),
+                        &format!("{}[{}]", group_path, index),
+                        depth + 1,
                     );
                 }
             }
+            (_, other) => errors.push(invalid_type(&group_path, "array", other)),
+        }
+    }
+
+    fn check_filter(&self, filter: &Value, errors: &mut Vec<ValidationError>, path: &str) {
+        let Some(filter) = as_object(filter, errors, path) else {
+            return;
+        };
+
+        let shape = filter
+            .get("op")
+            .and_then(|op| serde_json::from_value::<FilterOperator>(op.clone()).ok())
+            .map(|op| op.value_shape());
+        let takes_value = shape != Some(FilterValueShape::None);
+
+        for field in ["field", "op", "value"] {
+            if !filter.contains_key(field) && (field != "value" || takes_value) {
+                errors.push(ValidationError::MissingFilterField {
+                    field: field.to_string(),
+                    path: format!("{}.{}", path, field),
+                });
+            }
+        }
+
+        if let Some(field) = filter.get("field") {
+            if !field.is_string() {
+                errors.push(invalid_type(&format!("{}.field", path), "string", field));
+            }
+        }
+
+        if let Some(op) = filter.get("op") {
+            if !is_variant::<FilterOperator>(op) {
+                errors.push(ValidationError::InvalidFilterOp {
+                    path: format!("{}.op", path),
+                });
+            }
+        }
+
+        let Some(value) = filter.get("value") else {
+            return;
+        };
+        let value_path = format!("{}.value", path);
+        match value {
+            Value::Null if !takes_value => return,
+            Value::Object(object) if object.contains_key("relative") => {
+                if serde_json::from_value::<RelativeDate>(value.clone()).is_err() {
+                    errors.push(ValidationError::InvalidRelativeDate { path: value_path });
+                    return;
+                }
+            }
+            Value::String(_) | Value::Number(_) | Value::Bool(_) => {}
+            Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    if !(item.is_string() || item.is_number()) {
+                        errors.push(invalid_type(
+                            &format!("{}[{}]", value_path, index),
+                            "string or number",
+                            item,
+                        ));
+                    }
+                }
+            }
+            other => {
+                errors.push(invalid_type(
+                    &value_path,
+                    "string, number, boolean, array or relative date",
+                    other,
+                ));
+                return;
+            }
+        }
+
+        // Validate value shape based on operator
+        if let Some(shape) = shape {
+            let matches = match shape {
+                FilterValueShape::Scalar => !value.is_array(),
+                FilterValueShape::Array => value.is_array(),
+                FilterValueShape::Range => value.as_array().is_some_and(|items| items.len() == 2),
+                FilterValueShape::None => false,
+            };
+            if !matches {
+                errors.push(ValidationError::InvalidFilterValueType { path: value_path });
+            }
+        }
+    }
+
+    fn check_aggregation(
+        &self,
+        aggregation: &Value,
+        errors: &mut Vec<ValidationError>,
+        path: &str,
+    ) {
+        let Some(aggregation) = as_object(aggregation, errors, path) else {
+            return;
+        };
+
+        for field in ["type", "field"] {
+            if !aggregation.contains_key(field) {
+                errors.push(ValidationError::MissingAggregationField {
+                    field: field.to_string(),
+                    path: format!("{}.{}", path, field),
+                });
+            }
+        }
+
+        if let Some(agg_type) = aggregation.get("type") {
+            check_aggregation_type(agg_type, errors, &format!("{}.type", path));
+        }
+        if let Some(field) = aggregation.get("field") {
+            if !field.is_string() {
+                errors.push(invalid_type(&format!("{}.field", path), "string", field));
+            }
+        }
+        check_optional_string(aggregation, "by", errors, path);
+
+        if let Some(bucket) = aggregation.get("bucket") {
+            self.check_time_bucket(bucket, errors, &format!("{}.bucket", path));
+        }
+    }
+
+    fn check_time_bucket(&self, bucket: &Value, errors: &mut Vec<ValidationError>, path: &str) {
+        let Some(bucket) = as_object(bucket, errors, path) else {
+            return;
+        };
+
+        match bucket.get("unit") {
+            None => errors.push(ValidationError::MissingAggregationField {
+                field: "unit".to_string(),
+                path: format!("{}.unit", path),
+            }),
+            Some(unit) if !is_variant::<TimeUnit>(unit) => {
+                errors.push(ValidationError::InvalidTimeUnit {
+                    path: format!("{}.unit", path),
+                })
+            }
+            Some(_) => {}
+        }
+        check_optional_string(bucket, "timezone", errors, path);
+        if let Some(zero_fill) = bucket.get("zero_fill") {
+            if !zero_fill.is_boolean() {
+                errors.push(invalid_type(
+                    &format!("{}.zero_fill", path),
+                    "boolean",
+                    zero_fill,
+                ));
+            }
+        }
+    }
+
+    fn check_metric(&self, metric: &Value, errors: &mut Vec<ValidationError>, path: &str) {
+        let Some(metric) = as_object(metric, errors, path) else {
+            return;
+        };
+
+        for field in ["alias", "type", "field"] {
+            if !metric.contains_key(field) {
+                errors.push(ValidationError::MissingAggregationField {
+                    field: field.to_string(),
+                    path: format!("{}.{}", path, field),
+                });
+            }
+        }
+
+        if let Some(agg_type) = metric.get("type") {
+            check_aggregation_type(agg_type, errors, &format!("{}.type", path));
+        }
+        for field in ["alias", "field"] {
+            check_optional_string(metric, field, errors, path);
+        }
+    }
+
+    fn check_sort(&self, sort: &Value, errors: &mut Vec<ValidationError>, path: &str) {
+        let Some(sort) = as_object(sort, errors, path) else {
+            return;
+        };
+
+        for field in ["field", "direction"] {
+            if !sort.contains_key(field) {
+                errors.push(ValidationError::MissingSortField {
+                    field: field.to_string(),
+                    path: format!("{}.{}", path, field),
+                });
+            }
+        }
+
+        if let Some(field) = sort.get("field") {
+            if !field.is_string() {
+                errors.push(invalid_type(&format!("{}.field", path), "string", field));
+            }
+        }
+        if let Some(direction) = sort.get("direction") {
+            if !is_variant::<SortDirection>(direction) {
+                errors.push(ValidationError::InvalidSortDirection {
+                    path: format!("{}.direction", path),
+                });
+            }
+        }
+        if let Some(nulls) = sort.get("nulls") {
+            if !is_variant::<NullsOrder>(nulls) {
+                errors.push(ValidationError::InvalidSortNulls {
+                    path: format!("{}.nulls", path),
+                });
+            }
+        }
+    }
+
+    fn validate_layout(
+        &self,
+        layout: &Layout,
+        errors: &mut Vec<ValidationError>,
+        path: &str,
+        depth: usize,
+    ) {
+        if depth > MAX_LAYOUT_DEPTH {
+            errors.push(ValidationError::LayoutTooDeep {
+                max_depth: MAX_LAYOUT_DEPTH,
+                path: path.to_string(),
+            });
+            return;
+        }
+
+        // Validate grid columns
+        if let Layout::Grid { props, .. } = layout {
+            if props.columns < 1 {
+                errors.push(ValidationError::InvalidGridColumns {
+                    value: props.columns,
+                    path: format!("{}.props.columns", path),
+                });
+            }
+        }
+
+        // Validate children
+        for (index, child) in layout.children().iter().enumerate() {
+            let child_path = format!("{}.children[{}]", path, index);
+            match child {
+                LayoutChild::Layout(nested) => {
+                    self.validate_layout(nested, errors, &child_path, depth + 1)
+                }
+                LayoutChild::Component(component) => {
+                    self.validate_component(component, errors, &child_path)
+                }
+            }
         }
     }
 
@@ -188,53 +885,260 @@ impl SchemaValidator {
         }
     }
 
-    fn validate_data_source(
+    fn validate_data_source(&self, ds: &DataSource, errors: &mut Vec<ValidationError>, path: &str) {
+        // Resource is always present due to struct definition
+
+        // Validate filters
+        if let Some(filters) = &ds.filters {
+            for (index, filter) in filters.iter().enumerate() {
+                self.validate_filter_node(
+                    filter,
+                    errors,
+                    &format!("{}.filters[{}]", path, index),
+                    1,
+                );
+            }
+        }
+
+        // Validate aggregation / metrics
+        self.validate_metrics(ds, errors, path);
+
+        // Validate sort
+        self.validate_sort(ds, errors, path);
+
+        // Validate limit (the u32 type rules out negative values)
+        if ds.limit == Some(0) {
+            errors.push(ValidationError::InvalidLimit {
+                value: "0".to_string(),
+                path: format!("{}.limit", path),
+            });
+        }
+
+        // Validate page
+        if let Some(page) = &ds.page {
+            if ds.limit.is_none() {
+                errors.push(ValidationError::PaginationRequiresLimit {
+                    path: format!("{}.limit", path),
+                });
+            }
+            if matches!(page, Page::After(_)) && !supports_cursor(ds) {
+                errors.push(ValidationError::CursorRequiresSort {
+                    path: format!("{}.page", path),
+                });
+            }
+        }
+
+        // Validate resource and field references against the catalog
+        if let Some(catalog) = &self.catalog {
+            self.validate_catalog_fields(catalog.as_ref(), ds, errors, path);
+        }
+    }
+
+    fn validate_metrics(&self, ds: &DataSource, errors: &mut Vec<ValidationError>, path: &str) {
+        if let Some(aggregation) = &ds.aggregation {
+            if !aggregation.agg_type.is_valid() {
+                errors.push(ValidationError::InvalidPercentile {
+                    path: format!("{}.aggregation.type", path),
+                });
+            }
+        }
+        for (index, metric) in ds.metrics.iter().flatten().enumerate() {
+            if !metric.agg_type.is_valid() {
+                errors.push(ValidationError::InvalidPercentile {
+                    path: format!("{}.metrics[{}].type", path, index),
+                });
+            }
+        }
+
+        // A time bucket truncates the GROUP BY field
+        if let Some(aggregation) = &ds.aggregation {
+            if aggregation.bucket.is_some() && aggregation.by.is_none() {
+                errors.push(ValidationError::MissingAggregationField {
+                    field: "by".to_string(),
+                    path: format!("{}.aggregation.by", path),
+                });
+            }
+        }
+
+        if ds.aggregation.is_some() && (ds.metrics.is_some() || ds.group_by.is_some()) {
+            errors.push(ValidationError::ConflictingAggregation {
+                path: path.to_string(),
+            });
+            return;
+        }
+
+        match &ds.metrics {
+            Some(metrics) if metrics.is_empty() => errors.push(ValidationError::EmptyMetrics {
+                path: format!("{}.metrics", path),
+            }),
+            Some(_) => {}
+            None if ds.group_by.is_some() => {
+                errors.push(missing_field("metrics", &format!("{}.metrics", path)))
+            }
+            None => {}
+        }
+
+        // Aliases become result columns next to the group_by fields
+        let mut columns: Vec<&str> = ds.group_by.iter().flatten().map(String::as_str).collect();
+        for (index, metric) in ds.metrics.iter().flatten().enumerate() {
+            let alias_path = format!("{}.metrics[{}].alias", path, index);
+            if !is_identifier(&metric.alias) {
+                errors.push(ValidationError::InvalidMetricAlias {
+                    alias: metric.alias.clone(),
+                    path: alias_path,
+                });
+            } else if columns.contains(&metric.alias.as_str()) {
+                errors.push(ValidationError::DuplicateMetricAlias {
+                    alias: metric.alias.clone(),
+                    path: alias_path,
+                });
+            }
+            columns.push(&metric.alias);
+        }
+    }
+
+    /// Aggregated results can only be sorted by their group_by fields and metric aliases
+    fn validate_sort(&self, ds: &DataSource, errors: &mut Vec<ValidationError>, path: &str) {
+        let Some(sort) = &ds.sort else {
+            return;
+        };
+        if !ds.is_aggregated() {
+            return;
+        }
+
+        let group_by = ds.group_by_fields();
+        let metrics = ds.output_metrics();
+        for (suffix, key) in sort.keys() {
+            let in_result = group_by.contains(&key.field.as_str())
+                || metrics.iter().any(|metric| metric.alias == key.field);
+            if !in_result {
+                errors.push(ValidationError::UnknownSortField {
+                    field: key.field.clone(),
+                    path: format!("{}.sort{}.field", path, suffix),
+                });
+            }
+        }
+    }
+
+    fn validate_catalog_fields(
         &self,
+        catalog: &dyn FieldCatalog,
         ds: &DataSource,
         errors: &mut Vec<ValidationError>,
         path: &str,
     ) {
-        // Resource is always present due to struct definition
+        if !catalog.has_resource(&ds.resource) {
+            errors.push(ValidationError::UnknownResource {
+                resource: ds.resource.clone(),
+                path: format!("{}.resource", path),
+            });
+            return;
+        }
+
+        let mut require = |field: &str, field_path: String| {
+            if !catalog.has_field(&ds.resource, field) {
+                errors.push(ValidationError::UnknownField {
+                    resource: ds.resource.clone(),
+                    field: field.to_string(),
+                    path: field_path,
+                });
+            }
+        };
 
-        // Validate filters
         if let Some(filters) = &ds.filters {
-            for (index, filter) in filters.iter().enumerate() {
-                self.validate_filter(filter, errors, &format!("{}.filters[{}]", path, index));
+            for (index, node) in filters.iter().enumerate() {
+                let node_path = format!("{}.filters[{}]", path, index);
+                visit_filters(node, &node_path, &mut |filter, filter_path| {
+                    require(&filter.field, format!("{}.field", filter_path));
+                });
             }
         }
-
-        // Validate aggregation
-        if let Some(_aggregation) = &ds.aggregation {
-            // Aggregation validation handled by struct
+        if let Some(aggregation) = &ds.aggregation {
+            require(&aggregation.field, format!("{}.aggregation.field", path));
+            if let Some(by) = &aggregation.by {
+                require(by, format!("{}.aggregation.by", path));
+            }
+        }
+        for (index, metric) in ds.metrics.iter().flatten().enumerate() {
+            require(&metric.field, format!("{}.metrics[{}].field", path, index));
         }
+        for (index, field) in ds.group_by.iter().flatten().enumerate() {
+            require(field, format!("{}.group_by[{}]", path, index));
+        }
+        // Aggregated sort keys are checked against the result columns instead
+        if !ds.is_aggregated() {
+            for (suffix, key) in ds.sort.iter().flat_map(SortKeys::keys) {
+                require(&key.field, format!("{}.sort{}.field", path, suffix));
+            }
+        }
+    }
 
-        // Validate sort
-        if let Some(_sort) = &ds.sort {
-            // Sort validation handled by struct
+    fn validate_filter_node(
+        &self,
+        node: &FilterNode,
+        errors: &mut Vec<ValidationError>,
+        path: &str,
+        depth: usize,
+    ) {
+        if depth > MAX_FILTER_DEPTH {
+            errors.push(ValidationError::FilterTooDeep {
+                max_depth: MAX_FILTER_DEPTH,
+                path: path.to_string(),
+            });
+            return;
         }
 
-        // Validate limit (already validated by u32 type)
+        let (group, children) = match node {
+            FilterNode::And { and } => ("and", and),
+            FilterNode::Or { or } => ("or", or),
+            FilterNode::Not { not } => {
+                self.validate_filter_node(not, errors, &format!("{}.not", path), depth + 1);
+                return;
+            }
+            FilterNode::Filter(filter) => {
+                self.validate_filter(filter, errors, path);
+                return;
+            }
+        };
+        let group_path = format!("{}.{}", path, group);
+        if children.is_empty() {
+            errors.push(ValidationError::EmptyFilterGroup {
+                group: group.to_string(),
+                path: group_path,
+            });
+            return;
+        }
+        for (index, child) in children.iter().enumerate() {
+            self.validate_filter_node(
+                child,
+                errors,
+                &format!("{}[{}]", group_path, index),
+                depth + 1,
+            );
+        }
     }
 
     fn validate_filter(&self, filter: &Filter, errors: &mut Vec<ValidationError>, path: &str) {
-        // Validate value type based on operator
-        match filter.op {
-            FilterOperator::In => {
-                // "in" operator requires array value
-                if !matches!(filter.value, FilterValue::Array(_)) {
-                    errors.push(ValidationError::InvalidFilterValueType {
-                        path: format!("{}.value", path),
-                    });
-                }
-            }
-            _ => {
-                // Other operators require scalar value
-                if matches!(filter.value, FilterValue::Array(_)) {
-                    errors.push(ValidationError::InvalidFilterValueType {
-                        path: format!("{}.value", path),
-                    });
-                }
+        // Validate value shape based on operator
+        let shape = filter.op.value_shape();
+        let matches = match (&filter.value, shape) {
+            (FilterValue::Null, FilterValueShape::None) => true,
+            (FilterValue::Null, FilterValueShape::Scalar) => {
+                errors.push(ValidationError::MissingFilterField {
+                    field: "value".to_string(),
+                    path: format!("{}.value", path),
+                });
+                return;
             }
+            (FilterValue::Array(_), FilterValueShape::Array) => true,
+            (FilterValue::Array(items), FilterValueShape::Range) => items.len() == 2,
+            (FilterValue::Array(_), _) => false,
+            (_, shape) => shape == FilterValueShape::Scalar,
+        };
+        if !matches {
+            errors.push(ValidationError::InvalidFilterValueType {
+                path: format!("{}.value", path),
+            });
         }
     }
 
@@ -244,12 +1148,7 @@ impl SchemaValidator {
         data_sources: &std::collections::HashMap<String, DataSource>,
         errors: &mut Vec<ValidationError>,
     ) {
-        let children = match layout {
-            Layout::Grid { children, .. } => children,
-            Layout::Stack { children, .. } => children,
-        };
-
-        for (index, component) in children.iter().enumerate() {
+        visit_components(layout, "layout", &mut |component, path| {
             let data_source_ref = match component {
                 Component::Chart { data_source, .. } => data_source,
                 Component::Table { data_source, .. } => data_source,
@@ -259,17 +1158,326 @@ impl SchemaValidator {
                 if !data_sources.contains_key(ds_ref) {
                     errors.push(ValidationError::DanglingDataSourceRef {
                         data_source: ds_ref.clone(),
-                        path: format!("layout.children[{}].data_source", index),
+                        path: format!("{}.data_source", path),
                     });
                 }
             }
+        });
+    }
+
+    /// Checks chart axes against the shape of the referenced data source
+    ///
+    /// Aggregated sources produce the group-by field and the aggregated value
+    /// (the metric alias, `{field}_{type}` for the `aggregation` shorthand);
+    /// un-aggregated sources produce the resource fields, checked when a
+    /// catalog is set.
+    fn validate_chart_bindings(
+        &self,
+        layout: &Layout,
+        data_sources: &std::collections::HashMap<String, DataSource>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        visit_components(layout, "layout", &mut |component, path| {
+            let Component::Chart {
+                data_source: Some(ds_ref),
+                variant,
+                x_axis,
+                y_axis,
+                ..
+            } = component
+            else {
+                return;
+            };
+            // Dangling references are reported separately
+            let Some(ds) = data_sources.get(ds_ref) else {
+                return;
+            };
+
+            if !ds.is_aggregated() && *variant == ChartVariant::Pie {
+                errors.push(ValidationError::ChartRequiresAggregation {
+                    variant: variant.as_str().to_string(),
+                    path: format!("{}.data_source", path),
+                });
+            }
+
+            let metrics = ds.output_metrics();
+            let group_by = ds.group_by_fields();
+            let axes = x_axis
+                .iter()
+                .map(|field| ("xAxis", String::new(), field.as_str()))
+                .chain(
+                    y_axis
+                        .iter()
+                        .flat_map(AxisFields::fields)
+                        .map(|(suffix, field)| ("yAxis", suffix, field)),
+                );
+
+            for (axis, suffix, field) in axes {
+                let produced = if ds.is_aggregated() {
+                    match axis {
+                        "xAxis" => group_by.contains(&field),
+                        _ => metrics.iter().any(|metric| metric.alias == field),
+                    }
+                } else {
+                    self.catalog
+                        .as_ref()
+                        .map_or(true, |catalog| catalog.has_field(&ds.resource, field))
+                };
+                if !produced {
+                    errors.push(ValidationError::UnknownChartAxisField {
+                        axis: axis.to_string(),
+                        field: field.to_string(),
+                        data_source: ds_ref.clone(),
+                        path: format!("{}.{}{}", path, axis, suffix),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Checks that paged tables are bound to a data source that can page
+    fn validate_table_pagination(
+        &self,
+        layout: &Layout,
+        data_sources: &std::collections::HashMap<String, DataSource>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        visit_components(layout, "layout", &mut |component, path| {
+            let Component::Table {
+                data_source: Some(ds_ref),
+                pagination: Some(pagination),
+                ..
+            } = component
+            else {
+                return;
+            };
+            // Dangling references are reported separately
+            let Some(ds) = data_sources.get(ds_ref) else {
+                return;
+            };
+
+            let pagination_path = format!("{}.pagination", path);
+            if ds.limit.is_none() {
+                errors.push(ValidationError::PaginationRequiresLimit {
+                    path: pagination_path.clone(),
+                });
+            }
+            if *pagination == PaginationMode::Cursor && !supports_cursor(ds) {
+                errors.push(ValidationError::CursorRequiresSort {
+                    path: pagination_path,
+                });
+            }
+        });
+    }
+}
+
+/// Whether the data source can be paged with keyset cursors
+///
+/// A cursor resumes after the sort key values of the last row, so the source
+/// needs a sort and must return rows rather than aggregated groups.
+fn supports_cursor(ds: &DataSource) -> bool {
+    !ds.is_aggregated() && ds.sort.as_ref().is_some_and(|sort| !sort.keys().is_empty())
+}
+
+/// Calls `f` with every component in the layout tree and its path
+fn visit_components(layout: &Layout, path: &str, f: &mut impl FnMut(&Component, &str)) {
+    for (index, child) in layout.children().iter().enumerate() {
+        let child_path = format!("{}.children[{}]", path, index);
+        match child {
+            LayoutChild::Layout(nested) => visit_components(nested, &child_path, f),
+            LayoutChild::Component(component) => f(component, &child_path),
+        }
+    }
+}
+
+/// Calls `f` with every leaf filter in the filter tree and its path
+fn visit_filters(node: &FilterNode, path: &str, f: &mut impl FnMut(&Filter, &str)) {
+    match node {
+        FilterNode::And { and: children } => {
+            for (index, child) in children.iter().enumerate() {
+                visit_filters(child, &format!("{}.and[{}]", path, index), f);
+            }
         }
+        FilterNode::Or { or: children } => {
+            for (index, child) in children.iter().enumerate() {
+                visit_filters(child, &format!("{}.or[{}]", path, index), f);
+            }
+        }
+        FilterNode::Not { not } => visit_filters(not, &format!("{}.not", path), f),
+        FilterNode::Filter(filter) => f(filter, path),
     }
 }
 
-impl Default for SchemaValidator {
-    fn default() -> Self {
-        Self::new()
+fn check_grid_props(props: &Map<String, Value>, errors: &mut Vec<ValidationError>, path: &str) {
+    let columns_path = format!("{}.columns", path);
+    match props.get("columns") {
+        None => errors.push(missing_field("columns", &columns_path)),
+        Some(columns) => match columns.as_u64() {
+            Some(0) => errors.push(ValidationError::InvalidGridColumns {
+                value: 0,
+                path: columns_path,
+            }),
+            Some(n) if n <= u64::from(u32::MAX) => {}
+            _ => errors.push(invalid_type(&columns_path, "positive integer", columns)),
+        },
+    }
+    check_optional_u32(props, "gap", errors, path);
+}
+
+fn check_stack_props(props: &Map<String, Value>, errors: &mut Vec<ValidationError>, path: &str) {
+    let direction_path = format!("{}.direction", path);
+    match props.get("direction") {
+        None => errors.push(missing_field("direction", &direction_path)),
+        Some(direction) => {
+            if !is_variant::<StackDirection>(direction) {
+                errors.push(ValidationError::InvalidType {
+                    path: direction_path,
+                    message: "expected one of: horizontal, vertical".to_string(),
+                });
+            }
+        }
+    }
+    check_optional_u32(props, "spacing", errors, path);
+}
+
+/// Collects dangling data_source references from the components of a raw layout tree
+fn check_data_source_references(
+    layout: &Value,
+    data_source_keys: &[&str],
+    errors: &mut Vec<ValidationError>,
+    path: &str,
+) {
+    let Some(children) = layout.get("children").and_then(Value::as_array) else {
+        return;
+    };
+
+    for (index, child) in children.iter().enumerate() {
+        let child_path = format!("{}.children[{}]", path, index);
+        if is_layout_node(child) {
+            check_data_source_references(child, data_source_keys, errors, &child_path);
+        } else if let Some(ds_ref) = child.get("data_source").and_then(Value::as_str) {
+            if !data_source_keys.contains(&ds_ref) {
+                errors.push(ValidationError::DanglingDataSourceRef {
+                    data_source: ds_ref.to_string(),
+                    path: format!("{}.data_source", child_path),
+                });
+            }
+        }
+    }
+}
+
+/// Whether a raw layout child is a nested layout (as opposed to a component)
+fn is_layout_node(value: &Value) -> bool {
+    matches!(
+        value.get("type").and_then(Value::as_str),
+        Some("grid" | "stack")
+    )
+}
+
+/// Returns the value as an object, recording an InvalidType error otherwise
+fn as_object<'a>(
+    value: &'a Value,
+    errors: &mut Vec<ValidationError>,
+    path: &str,
+) -> Option<&'a Map<String, Value>> {
+    let object = value.as_object();
+    if object.is_none() {
+        errors.push(invalid_type(path, "object", value));
+    }
+    object
+}
+
+fn check_optional_string(
+    object: &Map<String, Value>,
+    field: &str,
+    errors: &mut Vec<ValidationError>,
+    path: &str,
+) {
+    if let Some(value) = object.get(field) {
+        if !value.is_string() {
+            errors.push(invalid_type(
+                &format!("{}.{}", path, field),
+                "string",
+                value,
+            ));
+        }
+    }
+}
+
+fn check_optional_u32(
+    object: &Map<String, Value>,
+    field: &str,
+    errors: &mut Vec<ValidationError>,
+    path: &str,
+) {
+    if let Some(value) = object.get(field) {
+        let valid = matches!(value.as_u64(), Some(n) if n <= u64::from(u32::MAX));
+        if !valid {
+            errors.push(invalid_type(
+                &format!("{}.{}", path, field),
+                "non-negative integer",
+                value,
+            ));
+        }
+    }
+}
+
+/// Whether the value deserializes into the given (string) enum
+fn is_variant<T: DeserializeOwned>(value: &Value) -> bool {
+    value.is_string() && serde_json::from_value::<T>(value.clone()).is_ok()
+}
+
+/// Checks an aggregation type: a type name, or `{"percentile": p}` with p in 0..=100
+fn check_aggregation_type(agg_type: &Value, errors: &mut Vec<ValidationError>, path: &str) {
+    let parsed = match agg_type {
+        Value::String(_) | Value::Object(_) => {
+            serde_json::from_value::<AggregationType>(agg_type.clone()).ok()
+        }
+        _ => None,
+    };
+    match parsed {
+        None => errors.push(ValidationError::InvalidAggregationType {
+            path: path.to_string(),
+        }),
+        Some(agg_type) if !agg_type.is_valid() => errors.push(ValidationError::InvalidPercentile {
+            path: path.to_string(),
+        }),
+        Some(_) => {}
+    }
+}
+
+/// Whether the name is usable as a result column (letters, digits and underscores)
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn missing_field(field: &str, path: &str) -> ValidationError {
+    ValidationError::MissingRequiredField {
+        field: field.to_string(),
+        path: path.to_string(),
+    }
+}
+
+fn invalid_type(path: &str, expected: &str, actual: &Value) -> ValidationError {
+    ValidationError::InvalidType {
+        path: path.to_string(),
+        message: format!("expected {}, got {}", expected, json_type(actual)),
+    }
+}
+
+/// JSON type name used in error messages
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
     }
 }
 
@@ -316,7 +1524,8 @@ mod tests {
 
     #[test]
     fn test_schema_validator_default() {
-        let _validator = SchemaValidator::default();
+        let validator = SchemaValidator::default();
+        assert!(validator.catalog.is_none());
     }
 
     #[test]
@@ -340,6 +1549,9 @@ mod tests {
             ValidationError::InvalidFilterOp {
                 path: "filter.op".to_string(),
             },
+            ValidationError::InvalidRelativeDate {
+                path: "filter.value".to_string(),
+            },
             ValidationError::InvalidAggregationType {
                 path: "agg.type".to_string(),
             },
@@ -372,6 +1584,26 @@ mod tests {
                 field: "type".to_string(),
                 path: "agg".to_string(),
             },
+            ValidationError::InvalidPercentile {
+                path: "data_sources.test.aggregation.type".to_string(),
+            },
+            ValidationError::InvalidTimeUnit {
+                path: "data_sources.test.aggregation.bucket.unit".to_string(),
+            },
+            ValidationError::ConflictingAggregation {
+                path: "data_sources.test".to_string(),
+            },
+            ValidationError::EmptyMetrics {
+                path: "data_sources.test.metrics".to_string(),
+            },
+            ValidationError::InvalidMetricAlias {
+                alias: "total revenue".to_string(),
+                path: "data_sources.test.metrics[0].alias".to_string(),
+            },
+            ValidationError::DuplicateMetricAlias {
+                alias: "total".to_string(),
+                path: "data_sources.test.metrics[1].alias".to_string(),
+            },
             ValidationError::InvalidSortDirection {
                 path: "sort.direction".to_string(),
             },
@@ -379,10 +1611,60 @@ mod tests {
                 field: "field".to_string(),
                 path: "sort".to_string(),
             },
+            ValidationError::InvalidSortNulls {
+                path: "sort[1].nulls".to_string(),
+            },
+            ValidationError::UnknownSortField {
+                field: "amount".to_string(),
+                path: "data_sources.test.sort[0].field".to_string(),
+            },
             ValidationError::InvalidLimit {
                 value: "negative".to_string(),
                 path: "limit".to_string(),
             },
+            ValidationError::InvalidPage {
+                path: "data_sources.test.page".to_string(),
+            },
+            ValidationError::InvalidPaginationMode {
+                path: "layout.children[0].pagination".to_string(),
+            },
+            ValidationError::PaginationRequiresLimit {
+                path: "data_sources.test.limit".to_string(),
+            },
+            ValidationError::CursorRequiresSort {
+                path: "data_sources.test.page".to_string(),
+            },
+            ValidationError::LayoutTooDeep {
+                max_depth: MAX_LAYOUT_DEPTH,
+                path: "layout.children[0]".to_string(),
+            },
+            ValidationError::FilterTooDeep {
+                max_depth: MAX_FILTER_DEPTH,
+                path: "data_sources.a.filters[0].not".to_string(),
+            },
+            ValidationError::EmptyFilterGroup {
+                group: "or".to_string(),
+                path: "data_sources.a.filters[0].or".to_string(),
+            },
+            ValidationError::ChartRequiresAggregation {
+                variant: "pie".to_string(),
+                path: "layout.children[0].data_source".to_string(),
+            },
+            ValidationError::UnknownChartAxisField {
+                axis: "yAxis".to_string(),
+                field: "total".to_string(),
+                data_source: "sales".to_string(),
+                path: "layout.children[0].yAxis".to_string(),
+            },
+            ValidationError::UnknownResource {
+                resource: "secrets".to_string(),
+                path: "data_sources.a.resource".to_string(),
+            },
+            ValidationError::UnknownField {
+                resource: "sales".to_string(),
+                field: "cost".to_string(),
+                path: "data_sources.a.filters[0].field".to_string(),
+            },
         ];
 
         for error in errors {
@@ -445,8 +1727,9 @@ mod tests {
                     data_source: None,
                     variant: ChartVariant::Bar,
                     x_axis: Some("x".to_string()),
-                    y_axis: Some("y".to_string()),
-                }],
+                    y_axis: Some("y".into()),
+                }
+                .into()],
             },
             data_sources: HashMap::new(),
         };
@@ -517,7 +1800,9 @@ mod tests {
                     data_source: None,
                     columns: vec![],
                     sortable: None,
-                }],
+                    pagination: None,
+                }
+                .into()],
             },
             data_sources: HashMap::new(),
         };
@@ -546,7 +1831,8 @@ mod tests {
                     variant: ChartVariant::Bar,
                     x_axis: None,
                     y_axis: None,
-                }],
+                }
+                .into()],
             },
             data_sources: HashMap::new(),
         };
@@ -571,10 +1857,14 @@ mod tests {
                     field: "category".to_string(),
                     op: FilterOperator::In,
                     value: FilterValue::String("electronics".to_string()),
-                }]),
+                }
+                .into()]),
                 aggregation: None,
+                metrics: None,
+                group_by: None,
                 sort: None,
                 limit: None,
+                page: None,
             },
         );
 
@@ -613,10 +1903,14 @@ mod tests {
                         FilterValueScalar::Number(1.0),
                         FilterValueScalar::Number(2.0),
                     ]),
-                }]),
+                }
+                .into()]),
                 aggregation: None,
+                metrics: None,
+                group_by: None,
                 sort: None,
                 limit: None,
+                page: None,
             },
         );
 
@@ -0,0 +1,95 @@
+//! Aggregation Type Tests
+//!
+//! Tests the count_distinct, median and percentile aggregation types: serde
+//! wire format and percentile range validation in the raw JSON and typed
+//! validators
+
+use liquid_protocol::*;
+use serde_json::{json, Value};
+
+/// Builds a raw schema with a table bound to an `expenses` source using the given metric type
+fn schema_json(agg_type: Value) -> Value {
+    json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [{ "type": "table", "data_source": "expenses", "columns": ["category"] }]
+        },
+        "data_sources": {
+            "expenses": {
+                "resource": "expenses",
+                "metrics": [{ "alias": "value", "type": agg_type, "field": "amount" }],
+                "group_by": ["category"]
+            }
+        }
+    })
+}
+
+#[test]
+fn test_aggregation_type_wire_format() {
+    let cases = [
+        (json!("count_distinct"), AggregationType::CountDistinct),
+        (json!("median"), AggregationType::Median),
+        (
+            json!({ "percentile": 90.5 }),
+            AggregationType::Percentile(90.5),
+        ),
+    ];
+
+    for (wire, expected) in cases {
+        let agg_type: AggregationType = serde_json::from_value(wire.clone()).unwrap();
+        assert_eq!(agg_type, expected);
+        assert_eq!(serde_json::to_value(&agg_type).unwrap(), wire);
+    }
+}
+
+#[test]
+fn test_percentile_schema_validates() {
+    let json = schema_json(json!({ "percentile": 90 }));
+
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+
+    assert!(SchemaValidator::new().validate_json(&json).valid);
+    assert!(SchemaValidator::new().validate(&schema).valid);
+    assert_eq!(
+        schema.data_sources["expenses"].output_metrics()[0]
+            .agg_type
+            .percentile_fraction(),
+        Some(0.9)
+    );
+}
+
+#[test]
+fn test_out_of_range_percentile_is_rejected() {
+    let expected = vec![ValidationError::InvalidPercentile {
+        path: "data_sources.expenses.metrics[0].type".to_string(),
+    }];
+
+    let json = schema_json(json!({ "percentile": 120 }));
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+
+    assert_eq!(SchemaValidator::new().validate_json(&json).errors, expected);
+    assert_eq!(SchemaValidator::new().validate(&schema).errors, expected);
+}
+
+#[test]
+fn test_malformed_percentile_is_an_invalid_type() {
+    let json = schema_json(json!({ "percentile": "high" }));
+
+    assert_eq!(
+        SchemaValidator::new().validate_json(&json).errors,
+        vec![ValidationError::InvalidAggregationType {
+            path: "data_sources.expenses.metrics[0].type".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_aggregation_type_as_str() {
+    assert_eq!(AggregationType::CountDistinct.as_str(), "count_distinct");
+    assert_eq!(AggregationType::Median.as_str(), "median");
+    assert_eq!(AggregationType::Percentile(50.0).as_str(), "percentile");
+    assert_eq!(AggregationType::Median.percentile_fraction(), Some(0.5));
+    assert_eq!(AggregationType::Sum.percentile_fraction(), None);
+}
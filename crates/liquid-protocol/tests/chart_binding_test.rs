@@ -0,0 +1,258 @@
+//! Chart Binding Validation Tests
+//!
+//! Tests chart axes against the shape of the referenced DataSource and
+//! resource/field references against a FieldCatalog
+
+use liquid_protocol::*;
+use serde_json::{json, Value};
+
+/// Catalog exposing `sales(month, region, amount)`
+struct SalesCatalog;
+
+impl FieldCatalog for SalesCatalog {
+    fn has_resource(&self, resource: &str) -> bool {
+        resource == "sales"
+    }
+
+    fn has_field(&self, resource: &str, field: &str) -> bool {
+        resource == "sales" && ["month", "region", "amount"].contains(&field)
+    }
+}
+
+/// Builds a schema with one chart bound to the `sales` data source
+fn chart_schema(chart: Value, data_source: Value) -> LiquidViewSchema {
+    let mut chart = chart;
+    chart["type"] = json!("chart");
+    chart["data_source"] = json!("sales");
+    serde_json::from_value(json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [chart]
+        },
+        "data_sources": { "sales": data_source }
+    }))
+    .unwrap()
+}
+
+fn monthly_total() -> Value {
+    json!({
+        "resource": "sales",
+        "aggregation": { "type": "sum", "field": "amount", "by": "month" }
+    })
+}
+
+fn validate(schema: &LiquidViewSchema) -> Vec<ValidationError> {
+    SchemaValidator::new().validate(schema).errors
+}
+
+fn validate_with_catalog(schema: &LiquidViewSchema) -> Vec<ValidationError> {
+    SchemaValidator::with_field_catalog(SalesCatalog)
+        .validate(schema)
+        .errors
+}
+
+#[test]
+fn test_aggregated_chart_axes_are_accepted() {
+    let schema = chart_schema(
+        json!({ "variant": "bar", "xAxis": "month", "yAxis": "amount_sum" }),
+        monthly_total(),
+    );
+
+    assert_eq!(validate(&schema), vec![]);
+}
+
+#[test]
+fn test_y_axis_rejects_aggregated_source_field() {
+    // The query only produces `amount_sum`, not the raw `amount` column
+    let schema = chart_schema(
+        json!({ "variant": "line", "xAxis": "month", "yAxis": "amount" }),
+        monthly_total(),
+    );
+
+    assert_eq!(
+        validate(&schema),
+        vec![ValidationError::UnknownChartAxisField {
+            axis: "yAxis".to_string(),
+            field: "amount".to_string(),
+            data_source: "sales".to_string(),
+            path: "layout.children[0].yAxis".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_y_axis_not_produced_by_aggregation() {
+    let schema = chart_schema(
+        json!({ "variant": "bar", "xAxis": "month", "yAxis": "profit" }),
+        monthly_total(),
+    );
+
+    assert_eq!(
+        validate(&schema),
+        vec![ValidationError::UnknownChartAxisField {
+            axis: "yAxis".to_string(),
+            field: "profit".to_string(),
+            data_source: "sales".to_string(),
+            path: "layout.children[0].yAxis".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_x_axis_must_be_group_by_field() {
+    let schema = chart_schema(
+        json!({ "variant": "bar", "xAxis": "region", "yAxis": "amount_sum" }),
+        monthly_total(),
+    );
+
+    assert_eq!(
+        validate(&schema),
+        vec![ValidationError::UnknownChartAxisField {
+            axis: "xAxis".to_string(),
+            field: "region".to_string(),
+            data_source: "sales".to_string(),
+            path: "layout.children[0].xAxis".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_pie_chart_requires_aggregation() {
+    let schema = chart_schema(json!({ "variant": "pie" }), json!({ "resource": "sales" }));
+
+    assert_eq!(
+        validate(&schema),
+        vec![ValidationError::ChartRequiresAggregation {
+            variant: "pie".to_string(),
+            path: "layout.children[0].data_source".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_unaggregated_axes_without_catalog_are_not_checked() {
+    let schema = chart_schema(
+        json!({ "variant": "line", "xAxis": "anything", "yAxis": "goes" }),
+        json!({ "resource": "sales" }),
+    );
+
+    assert_eq!(validate(&schema), vec![]);
+}
+
+#[test]
+fn test_unaggregated_axes_checked_against_catalog() {
+    let schema = chart_schema(
+        json!({ "variant": "line", "xAxis": "month", "yAxis": "revenue" }),
+        json!({ "resource": "sales" }),
+    );
+
+    assert_eq!(
+        validate_with_catalog(&schema),
+        vec![ValidationError::UnknownChartAxisField {
+            axis: "yAxis".to_string(),
+            field: "revenue".to_string(),
+            data_source: "sales".to_string(),
+            path: "layout.children[0].yAxis".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_catalog_rejects_unknown_resource() {
+    let schema = chart_schema(
+        json!({ "variant": "bar" }),
+        json!({ "resource": "payroll" }),
+    );
+
+    assert_eq!(
+        validate_with_catalog(&schema),
+        vec![ValidationError::UnknownResource {
+            resource: "payroll".to_string(),
+            path: "data_sources.sales.resource".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_catalog_rejects_unknown_data_source_fields() {
+    let schema = chart_schema(
+        json!({ "variant": "bar", "xAxis": "quarter", "yAxis": "cost_avg" }),
+        json!({
+            "resource": "sales",
+            "filters": [{ "field": "secret", "op": "eq", "value": 1 }],
+            "aggregation": { "type": "avg", "field": "cost", "by": "quarter" },
+            "sort": { "field": "cost_avg", "direction": "desc" }
+        }),
+    );
+
+    assert_eq!(
+        validate_with_catalog(&schema),
+        vec![
+            ValidationError::UnknownField {
+                resource: "sales".to_string(),
+                field: "secret".to_string(),
+                path: "data_sources.sales.filters[0].field".to_string(),
+            },
+            ValidationError::UnknownField {
+                resource: "sales".to_string(),
+                field: "cost".to_string(),
+                path: "data_sources.sales.aggregation.field".to_string(),
+            },
+            ValidationError::UnknownField {
+                resource: "sales".to_string(),
+                field: "quarter".to_string(),
+                path: "data_sources.sales.aggregation.by".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_nested_chart_binding_path() {
+    let schema: LiquidViewSchema = serde_json::from_value(json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 2 },
+            "children": [{
+                "type": "stack",
+                "props": { "direction": "vertical" },
+                "children": [
+                    { "type": "chart", "variant": "bar", "data_source": "sales", "yAxis": "total" }
+                ]
+            }]
+        },
+        "data_sources": { "sales": monthly_total() }
+    }))
+    .unwrap();
+
+    let errors = validate(&schema);
+
+    assert!(matches!(
+        &errors[..],
+        [ValidationError::UnknownChartAxisField { path, .. }]
+            if path == "layout.children[0].children[0].yAxis"
+    ));
+}
+
+#[test]
+fn test_validate_json_reports_chart_binding_errors() {
+    let document = json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [{ "type": "chart", "variant": "pie", "data_source": "sales" }]
+        },
+        "data_sources": { "sales": { "resource": "sales" } }
+    });
+
+    let errors = SchemaValidator::new().validate_json(&document).errors;
+
+    assert!(matches!(
+        &errors[..],
+        [ValidationError::ChartRequiresAggregation { .. }]
+    ));
+}
@@ -0,0 +1,221 @@
+//! Filter Group Tests
+//!
+//! Tests `and`/`or`/`not` filter trees: serde roundtrip, recursive
+//! validation paths, the maximum nesting depth and catalog field checks
+
+use liquid_protocol::validator::MAX_FILTER_DEPTH;
+use liquid_protocol::*;
+use serde_json::{json, Value};
+
+/// Catalog exposing `expenses(category, amount, refunded)`
+struct ExpensesCatalog;
+
+impl FieldCatalog for ExpensesCatalog {
+    fn has_resource(&self, resource: &str) -> bool {
+        resource == "expenses"
+    }
+
+    fn has_field(&self, resource: &str, field: &str) -> bool {
+        resource == "expenses" && ["category", "amount", "refunded"].contains(&field)
+    }
+}
+
+/// Builds a raw schema with a table bound to an `expenses` source using the given filters
+fn schema_json(filters: Value) -> Value {
+    json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [{ "type": "table", "data_source": "expenses", "columns": ["amount"] }]
+        },
+        "data_sources": {
+            "expenses": { "resource": "expenses", "filters": filters }
+        }
+    })
+}
+
+/// "food OR transport, excluding refunds"
+fn food_or_transport() -> Value {
+    json!([
+        {
+            "or": [
+                { "field": "category", "op": "eq", "value": "food" },
+                { "field": "category", "op": "eq", "value": "transport" }
+            ]
+        },
+        { "not": { "field": "refunded", "op": "eq", "value": true } }
+    ])
+}
+
+/// Wraps a leaf filter in `levels` `not` groups
+fn nested_filter(levels: usize) -> Value {
+    let mut node = json!({ "field": "amount", "op": "gt", "value": 0 });
+    for _ in 0..levels {
+        node = json!({ "not": node });
+    }
+    node
+}
+
+fn leaf(field: &str) -> FilterNode {
+    Filter {
+        field: field.to_string(),
+        op: FilterOperator::Eq,
+        value: FilterValue::String("x".to_string()),
+    }
+    .into()
+}
+
+#[test]
+fn test_filter_groups_roundtrip() {
+    let json = schema_json(food_or_transport());
+
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+    let filters = schema.data_sources["expenses"].filters.as_ref().unwrap();
+
+    assert!(matches!(&filters[0], FilterNode::Or { or } if or.len() == 2));
+    assert!(matches!(&filters[1], FilterNode::Not { .. }));
+    assert_eq!(serde_json::to_value(&schema).unwrap(), json);
+}
+
+#[test]
+fn test_flat_filters_are_still_accepted() {
+    let json = schema_json(json!([{ "field": "amount", "op": "gt", "value": 100 }]));
+
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+
+    assert!(matches!(
+        &schema.data_sources["expenses"].filters.as_ref().unwrap()[0],
+        FilterNode::Filter(filter) if filter.field == "amount"
+    ));
+    assert!(SchemaValidator::new().validate_json(&json).valid);
+}
+
+#[test]
+fn test_valid_filter_groups() {
+    let result = SchemaValidator::with_field_catalog(ExpensesCatalog)
+        .validate_json(&schema_json(food_or_transport()));
+
+    assert_eq!(result, ValidationResult::ok());
+}
+
+#[test]
+fn test_filter_depth_limit() {
+    let validator = SchemaValidator::new();
+
+    let at_limit = schema_json(json!([nested_filter(MAX_FILTER_DEPTH - 1)]));
+    assert!(validator.validate_json(&at_limit).valid);
+
+    let too_deep = schema_json(json!([nested_filter(MAX_FILTER_DEPTH)]));
+    let result = validator.validate_json(&too_deep);
+    assert_eq!(
+        result.errors,
+        vec![ValidationError::FilterTooDeep {
+            max_depth: MAX_FILTER_DEPTH,
+            path: "data_sources.expenses.filters[0].not.not.not.not".to_string(),
+        }]
+    );
+
+    // The typed validator enforces the same limit
+    let schema: LiquidViewSchema = serde_json::from_value(too_deep).unwrap();
+    assert_eq!(validator.validate(&schema).errors, result.errors);
+}
+
+#[test]
+fn test_empty_filter_group() {
+    let result = SchemaValidator::new().validate_json(&schema_json(json!([{ "or": [] }])));
+
+    assert_eq!(
+        result.errors,
+        vec![ValidationError::EmptyFilterGroup {
+            group: "or".to_string(),
+            path: "data_sources.expenses.filters[0].or".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_filter_group_with_several_keys() {
+    let filters = json!([{ "and": [], "field": "amount" }]);
+
+    let result = SchemaValidator::new().validate_json(&schema_json(filters));
+
+    assert!(matches!(
+        &result.errors[..],
+        [ValidationError::InvalidType { path, .. }] if path == "data_sources.expenses.filters[0]"
+    ));
+}
+
+#[test]
+fn test_errors_inside_groups_have_paths() {
+    let filters = json!([{
+        "and": [
+            { "field": "amount", "op": "like", "value": 1 },
+            { "or": "category" }
+        ]
+    }]);
+
+    let result = SchemaValidator::new().validate_json(&schema_json(filters));
+
+    assert_eq!(result.errors.len(), 2);
+    assert_eq!(
+        result.errors[0],
+        ValidationError::InvalidFilterOp {
+            path: "data_sources.expenses.filters[0].and[0].op".to_string(),
+        }
+    );
+    assert!(matches!(
+        &result.errors[1],
+        ValidationError::InvalidType { path, .. } if path == "data_sources.expenses.filters[0].and[1].or"
+    ));
+}
+
+#[test]
+fn test_catalog_checks_fields_inside_groups() {
+    let filters = json!([{
+        "or": [
+            { "field": "category", "op": "eq", "value": "food" },
+            { "not": { "field": "password", "op": "eq", "value": "x" } }
+        ]
+    }]);
+
+    let result =
+        SchemaValidator::with_field_catalog(ExpensesCatalog).validate_json(&schema_json(filters));
+
+    assert_eq!(
+        result.errors,
+        vec![ValidationError::UnknownField {
+            resource: "expenses".to_string(),
+            field: "password".to_string(),
+            path: "data_sources.expenses.filters[0].or[1].not.field".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_filter_node_helpers() {
+    let node = FilterNode::And {
+        and: vec![
+            leaf("a"),
+            FilterNode::Or {
+                or: vec![leaf("b"), leaf("c")],
+            },
+        ],
+    };
+
+    assert_eq!(node.depth(), 3);
+    let fields: Vec<&str> = node.filters().iter().map(|f| f.field.as_str()).collect();
+    assert_eq!(fields, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_json_schema_describes_filter_node() {
+    let schema = liquid_view_json_schema();
+
+    assert_eq!(
+        schema["$defs"]["DataSource"]["properties"]["filters"]["type"],
+        json!(["array", "null"])
+    );
+    let variants = schema["$defs"]["FilterNode"]["anyOf"].as_array().unwrap();
+    assert_eq!(variants.len(), 4);
+}
@@ -0,0 +1,130 @@
+//! Filter Operator Tests
+//!
+//! Tests the value shape each filter operator requires (`in`/`not_in` arrays,
+//! `between` ranges, valueless null checks) in both the raw JSON and the
+//! typed validators
+
+use liquid_protocol::*;
+use serde_json::{json, Value};
+
+/// Builds a raw schema with a table bound to an `expenses` source using the given filters
+fn schema_json(filters: Value) -> Value {
+    json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [{ "type": "table", "data_source": "expenses", "columns": ["amount"] }]
+        },
+        "data_sources": {
+            "expenses": { "resource": "expenses", "filters": filters }
+        }
+    })
+}
+
+/// Validates the filters with the raw JSON validator and, when the document
+/// deserializes, checks the typed validator reports the same errors
+fn validate(filters: Value) -> Vec<ValidationError> {
+    let json = schema_json(filters);
+    let validator = SchemaValidator::new();
+    let errors = validator.validate_json(&json).errors;
+
+    if let Ok(schema) = serde_json::from_value::<LiquidViewSchema>(json) {
+        assert_eq!(validator.validate(&schema).errors, errors);
+    }
+    errors
+}
+
+fn value_type_error(index: usize) -> ValidationError {
+    ValidationError::InvalidFilterValueType {
+        path: format!("data_sources.expenses.filters[{}].value", index),
+    }
+}
+
+#[test]
+fn test_extended_operators_are_valid() {
+    let errors = validate(json!([
+        { "field": "amount", "op": "between", "value": [10, 20] },
+        { "field": "category", "op": "not_in", "value": ["food", "travel"] },
+        { "field": "memo", "op": "is_null" },
+        { "field": "memo", "op": "is_not_null", "value": null },
+        { "field": "memo", "op": "starts_with", "value": "Lun" },
+        { "field": "memo", "op": "ends_with", "value": "team" },
+        { "field": "memo", "op": "icontains", "value": "lunch" }
+    ]));
+
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn test_between_requires_two_bounds() {
+    let errors = validate(json!([
+        { "field": "amount", "op": "between", "value": [10] },
+        { "field": "amount", "op": "between", "value": [10, 20, 30] },
+        { "field": "amount", "op": "between", "value": 10 }
+    ]));
+
+    assert_eq!(
+        errors,
+        vec![
+            value_type_error(0),
+            value_type_error(1),
+            value_type_error(2)
+        ]
+    );
+}
+
+#[test]
+fn test_not_in_requires_array() {
+    let errors = validate(json!([{ "field": "category", "op": "not_in", "value": "food" }]));
+
+    assert_eq!(errors, vec![value_type_error(0)]);
+}
+
+#[test]
+fn test_null_checks_take_no_value() {
+    let errors = validate(json!([
+        { "field": "memo", "op": "is_null", "value": "x" },
+        { "field": "memo", "op": "is_not_null", "value": ["x"] }
+    ]));
+
+    assert_eq!(errors, vec![value_type_error(0), value_type_error(1)]);
+}
+
+#[test]
+fn test_text_operators_require_scalar() {
+    let errors = validate(json!([
+        { "field": "memo", "op": "starts_with", "value": ["a"] },
+        { "field": "memo", "op": "icontains", "value": ["a"] }
+    ]));
+
+    assert_eq!(errors, vec![value_type_error(0), value_type_error(1)]);
+}
+
+#[test]
+fn test_value_is_still_required_for_other_operators() {
+    let errors = validate(json!([{ "field": "memo", "op": "ends_with" }]));
+
+    assert_eq!(
+        errors,
+        vec![ValidationError::MissingFilterField {
+            field: "value".to_string(),
+            path: "data_sources.expenses.filters[0].value".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_operator_value_shapes() {
+    assert_eq!(FilterOperator::Eq.value_shape(), FilterValueShape::Scalar);
+    assert_eq!(
+        FilterOperator::IContains.value_shape(),
+        FilterValueShape::Scalar
+    );
+    assert_eq!(FilterOperator::NotIn.value_shape(), FilterValueShape::Array);
+    assert_eq!(
+        FilterOperator::Between.value_shape(),
+        FilterValueShape::Range
+    );
+    assert_eq!(FilterOperator::IsNull.value_shape(), FilterValueShape::None);
+}
@@ -0,0 +1,234 @@
+//! JSON Schema Export Tests
+//!
+//! Checks the generated draft 2020-12 document against the serde wire format
+//! and the TypeScript specification in `packages/protocol`
+
+use liquid_protocol::json_schema::JSON_SCHEMA_DIALECT;
+use liquid_protocol::liquid_view_json_schema;
+use serde_json::Value;
+
+/// TypeScript type definitions of Liquid Protocol v1.0
+const TS_TYPES: &str = include_str!("../../../packages/protocol/src/types/index.ts");
+
+/// Returns the `$defs` entry for the given type name
+fn definition(schema: &Value, name: &str) -> Value {
+    schema["$defs"][name].clone()
+}
+
+/// Collects string constants from a string enum definition
+/// (`enum`, or `oneOf` of `enum` / `const`; object variants are skipped)
+fn string_constants(def: &Value) -> Vec<String> {
+    if let Some(values) = def["enum"].as_array() {
+        return values
+            .iter()
+            .map(|v| v.as_str().unwrap().to_string())
+            .collect();
+    }
+    def["oneOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|v| match (v["enum"].as_array(), v["const"].as_str()) {
+            (Some(values), _) => values
+                .iter()
+                .map(|v| v.as_str().unwrap().to_string())
+                .collect(),
+            (None, Some(value)) => vec![value.to_string()],
+            (None, None) => vec![],
+        })
+        .collect()
+}
+
+/// Extracts the string literals of `export type <name> = "a" | "b";` from the TS spec
+fn ts_string_union(name: &str) -> Vec<String> {
+    let start = TS_TYPES
+        .find(&format!("export type {} =", name))
+        .unwrap_or_else(|| panic!("TS type {} not found", name));
+    let body = &TS_TYPES[start..];
+    let body = &body[..body.find(';').unwrap()];
+    body.split('"')
+        .skip(1)
+        .step_by(2)
+        .map(String::from)
+        .collect()
+}
+
+/// Returns the `oneOf` variant whose `type` property is the given constant
+fn tagged_variant(def: &Value, tag: &str) -> Value {
+    def["oneOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|v| v["properties"]["type"]["const"] == tag)
+        .unwrap_or_else(|| panic!("variant {} not found", tag))
+        .clone()
+}
+
+fn required(def: &Value) -> Vec<&str> {
+    def["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v.as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_root_schema_shape() {
+    let schema = liquid_view_json_schema();
+
+    assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["version"]["const"], "1.0");
+    assert_eq!(schema["properties"]["layout"]["$ref"], "#/$defs/Layout");
+    assert_eq!(
+        schema["properties"]["data_sources"]["additionalProperties"]["$ref"],
+        "#/$defs/DataSource"
+    );
+    assert_eq!(required(&schema), vec!["version", "layout"]);
+}
+
+#[test]
+fn test_layout_is_tagged_by_type() {
+    let schema = liquid_view_json_schema();
+    let layout = definition(&schema, "Layout");
+
+    let grid = tagged_variant(&layout, "grid");
+    assert_eq!(
+        grid["properties"]["props"]["$ref"],
+        "#/$defs/GridLayoutProps"
+    );
+    assert_eq!(
+        grid["properties"]["children"]["items"]["$ref"],
+        "#/$defs/LayoutChild"
+    );
+    assert_eq!(required(&grid), vec!["type", "props", "children"]);
+
+    let stack = tagged_variant(&layout, "stack");
+    assert_eq!(
+        stack["properties"]["props"]["$ref"],
+        "#/$defs/StackLayoutProps"
+    );
+}
+
+#[test]
+fn test_component_uses_axis_renames() {
+    let schema = liquid_view_json_schema();
+    let component = definition(&schema, "Component");
+
+    let chart = tagged_variant(&component, "chart");
+    let properties = chart["properties"].as_object().unwrap();
+    assert!(properties.contains_key("xAxis"));
+    assert!(properties.contains_key("yAxis"));
+    assert!(!properties.contains_key("x_axis"));
+    assert!(!properties.contains_key("y_axis"));
+
+    let table = tagged_variant(&component, "table");
+    assert_eq!(required(&table), vec!["type", "columns"]);
+}
+
+#[test]
+fn test_grid_columns_minimum() {
+    let schema = liquid_view_json_schema();
+
+    assert_eq!(
+        definition(&schema, "GridLayoutProps")["properties"]["columns"]["minimum"],
+        1
+    );
+}
+
+#[test]
+fn test_filter_value_accepts_scalars_and_arrays() {
+    let schema = liquid_view_json_schema();
+    let types: Vec<Value> = definition(&schema, "FilterValue")["anyOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| match v.get("$ref") {
+            Some(reference) => reference.clone(),
+            None => v["type"].clone(),
+        })
+        .collect();
+
+    assert_eq!(
+        types,
+        vec![
+            "string",
+            "number",
+            "boolean",
+            "array",
+            "#/$defs/RelativeDate",
+            "null"
+        ]
+    );
+}
+
+#[test]
+fn test_string_enums_match_typescript_spec() {
+    let schema = liquid_view_json_schema();
+
+    for name in [
+        "ChartVariant",
+        "FilterOperator",
+        "AggregationType",
+        "TimeUnit",
+        "NullsOrder",
+        "PaginationMode",
+    ] {
+        assert_eq!(
+            string_constants(&definition(&schema, name)),
+            ts_string_union(name),
+            "{} differs from the TypeScript spec",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_percentile_aggregation_is_an_object_variant() {
+    let schema = liquid_view_json_schema();
+    let percentile = definition(&schema, "AggregationType")["oneOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|v| v["type"] == "object")
+        .unwrap()
+        .clone();
+
+    assert_eq!(required(&percentile), vec!["percentile"]);
+    assert_eq!(percentile["properties"]["percentile"]["minimum"], 0);
+    assert_eq!(percentile["properties"]["percentile"]["maximum"], 100);
+    assert!(TS_TYPES.contains("{ percentile: number }"));
+}
+
+#[test]
+fn test_relative_date_kinds_match_typescript_spec() {
+    let schema = liquid_view_json_schema();
+    let kinds: Vec<String> = definition(&schema, "RelativeDate")["oneOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| {
+            v["properties"]["relative"]["const"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+
+    assert_eq!(kinds, ts_string_union("RelativeDateKind"));
+}
+
+#[test]
+fn test_every_definition_is_referenced_from_root() {
+    let schema = liquid_view_json_schema();
+    let text = schema.to_string();
+
+    for name in schema["$defs"].as_object().unwrap().keys() {
+        assert!(
+            text.contains(&format!("\"#/$defs/{}\"", name)),
+            "{} is never referenced",
+            name
+        );
+    }
+}
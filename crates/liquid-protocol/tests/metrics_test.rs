@@ -0,0 +1,249 @@
+//! Metrics Tests
+//!
+//! Tests named metrics with multi-field group_by: serde roundtrip, alias
+//! validation and chart axes bound to aliases
+
+use liquid_protocol::*;
+use serde_json::{json, Value};
+
+/// Builds a raw schema with one chart bound to an `expenses` source
+fn schema_json(chart: Value, data_source: Value) -> Value {
+    let mut chart = chart;
+    chart["type"] = json!("chart");
+    chart["data_source"] = json!("expenses");
+    json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [chart]
+        },
+        "data_sources": { "expenses": data_source }
+    })
+}
+
+fn totals_by_category(metrics: Value) -> Value {
+    json!({
+        "resource": "expenses",
+        "metrics": metrics,
+        "group_by": ["category", "month"]
+    })
+}
+
+fn bar_chart() -> Value {
+    json!({ "variant": "bar", "xAxis": "category", "yAxis": ["total", "count"] })
+}
+
+fn validate(json: &Value) -> Vec<ValidationError> {
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+    SchemaValidator::new().validate(&schema).errors
+}
+
+#[test]
+fn test_metrics_roundtrip_and_validate() {
+    let json = schema_json(
+        bar_chart(),
+        totals_by_category(json!([
+            { "alias": "total", "type": "sum", "field": "amount" },
+            { "alias": "count", "type": "count", "field": "id" }
+        ])),
+    );
+
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+    let ds = &schema.data_sources["expenses"];
+
+    assert!(ds.is_aggregated());
+    assert_eq!(ds.group_by_fields(), vec!["category", "month"]);
+    assert_eq!(
+        ds.output_metrics()[1],
+        Metric {
+            alias: "count".to_string(),
+            agg_type: AggregationType::Count,
+            field: "id".to_string(),
+        }
+    );
+    assert_eq!(serde_json::to_value(&schema).unwrap(), json);
+    assert!(SchemaValidator::new().validate_json(&json).valid);
+    assert!(SchemaValidator::new().validate(&schema).valid);
+}
+
+#[test]
+fn test_aggregation_shorthand_expands_to_metric() {
+    let ds: DataSource = serde_json::from_value(json!({
+        "resource": "expenses",
+        "aggregation": { "type": "avg", "field": "amount", "by": "month" }
+    }))
+    .unwrap();
+
+    assert_eq!(ds.output_metrics()[0].alias, "amount_avg");
+    assert_eq!(ds.group_by_fields(), vec!["month"]);
+}
+
+#[test]
+fn test_duplicate_and_invalid_aliases_are_rejected() {
+    let json = schema_json(
+        json!({ "variant": "bar", "xAxis": "category", "yAxis": "total" }),
+        totals_by_category(json!([
+            { "alias": "total", "type": "sum", "field": "amount" },
+            { "alias": "total", "type": "max", "field": "amount" },
+            { "alias": "month", "type": "min", "field": "amount" },
+            { "alias": "avg amount", "type": "avg", "field": "amount" }
+        ])),
+    );
+
+    assert_eq!(
+        validate(&json),
+        vec![
+            ValidationError::DuplicateMetricAlias {
+                alias: "total".to_string(),
+                path: "data_sources.expenses.metrics[1].alias".to_string(),
+            },
+            ValidationError::DuplicateMetricAlias {
+                alias: "month".to_string(),
+                path: "data_sources.expenses.metrics[2].alias".to_string(),
+            },
+            ValidationError::InvalidMetricAlias {
+                alias: "avg amount".to_string(),
+                path: "data_sources.expenses.metrics[3].alias".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_chart_axes_must_reference_group_by_and_aliases() {
+    let json = schema_json(
+        json!({ "variant": "bar", "xAxis": "amount", "yAxis": ["total", "amount"] }),
+        totals_by_category(json!([{ "alias": "total", "type": "sum", "field": "amount" }])),
+    );
+
+    assert_eq!(
+        validate(&json),
+        vec![
+            ValidationError::UnknownChartAxisField {
+                axis: "xAxis".to_string(),
+                field: "amount".to_string(),
+                data_source: "expenses".to_string(),
+                path: "layout.children[0].xAxis".to_string(),
+            },
+            ValidationError::UnknownChartAxisField {
+                axis: "yAxis".to_string(),
+                field: "amount".to_string(),
+                data_source: "expenses".to_string(),
+                path: "layout.children[0].yAxis[1]".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_metrics_shape_errors() {
+    let cases = [
+        (
+            json!({
+                "resource": "expenses",
+                "aggregation": { "type": "sum", "field": "amount" },
+                "metrics": [{ "alias": "total", "type": "sum", "field": "amount" }]
+            }),
+            ValidationError::ConflictingAggregation {
+                path: "data_sources.expenses".to_string(),
+            },
+        ),
+        (
+            json!({ "resource": "expenses", "metrics": [] }),
+            ValidationError::EmptyMetrics {
+                path: "data_sources.expenses.metrics".to_string(),
+            },
+        ),
+        (
+            json!({ "resource": "expenses", "group_by": ["category"] }),
+            ValidationError::MissingRequiredField {
+                field: "metrics".to_string(),
+                path: "data_sources.expenses.metrics".to_string(),
+            },
+        ),
+    ];
+
+    for (data_source, expected) in cases {
+        let json = schema_json(json!({ "variant": "bar" }), data_source);
+        assert_eq!(validate(&json), vec![expected]);
+    }
+}
+
+#[test]
+fn test_raw_metrics_structure_is_checked() {
+    let json = schema_json(
+        json!({ "variant": "bar", "yAxis": ["total", 1] }),
+        json!({
+            "resource": "expenses",
+            "metrics": [{ "alias": "total", "type": "mode" }],
+            "group_by": "category"
+        }),
+    );
+
+    let result = SchemaValidator::new().validate_json(&json);
+    let paths: Vec<String> = result
+        .errors
+        .iter()
+        .map(|error| match error {
+            ValidationError::MissingAggregationField { path, .. }
+            | ValidationError::InvalidAggregationType { path }
+            | ValidationError::InvalidType { path, .. } => path.clone(),
+            other => panic!("unexpected error: {:?}", other),
+        })
+        .collect();
+
+    assert_eq!(
+        paths,
+        vec![
+            "layout.children[0].yAxis[1]",
+            "data_sources.expenses.metrics[0].field",
+            "data_sources.expenses.metrics[0].type",
+            "data_sources.expenses.group_by",
+        ]
+    );
+}
+
+#[test]
+fn test_metric_fields_are_checked_against_catalog() {
+    struct ExpensesCatalog;
+
+    impl FieldCatalog for ExpensesCatalog {
+        fn has_resource(&self, resource: &str) -> bool {
+            resource == "expenses"
+        }
+
+        fn has_field(&self, _resource: &str, field: &str) -> bool {
+            ["id", "amount", "category", "month"].contains(&field)
+        }
+    }
+
+    let json = schema_json(
+        json!({ "variant": "bar", "xAxis": "region" }),
+        json!({
+            "resource": "expenses",
+            "metrics": [{ "alias": "total", "type": "sum", "field": "price" }],
+            "group_by": ["region"],
+            "sort": { "field": "total", "direction": "desc" }
+        }),
+    );
+    let schema: LiquidViewSchema = serde_json::from_value(json).unwrap();
+
+    let paths: Vec<String> = SchemaValidator::with_field_catalog(ExpensesCatalog)
+        .validate(&schema)
+        .errors
+        .into_iter()
+        .filter_map(|error| match error {
+            ValidationError::UnknownField { path, .. } => Some(path),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(
+        paths,
+        vec![
+            "data_sources.expenses.metrics[0].field",
+            "data_sources.expenses.group_by[0]",
+        ]
+    );
+}
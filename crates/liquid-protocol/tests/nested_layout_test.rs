@@ -0,0 +1,235 @@
+//! Nested Layout Tests
+//!
+//! Tests layouts containing sub-layouts: serde roundtrip, recursive
+//! validation paths and the maximum nesting depth
+
+use liquid_protocol::validator::MAX_LAYOUT_DEPTH;
+use liquid_protocol::*;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// Creates a table component bound to the given data source
+fn table(data_source: &str) -> Component {
+    Component::Table {
+        title: None,
+        data_source: Some(data_source.to_string()),
+        columns: vec!["amount".to_string()],
+        sortable: None,
+        pagination: None,
+    }
+}
+
+/// Creates a vertical stack with the given children
+fn vertical_stack(children: Vec<LayoutChild>) -> Layout {
+    Layout::Stack {
+        props: StackLayoutProps {
+            direction: StackDirection::Vertical,
+            spacing: None,
+        },
+        children,
+    }
+}
+
+/// Creates a schema with the given root layout and an `expenses` data source
+fn schema_with_layout(layout: Layout) -> LiquidViewSchema {
+    let mut data_sources = HashMap::new();
+    data_sources.insert(
+        "expenses".to_string(),
+        DataSource {
+            resource: "expenses".to_string(),
+            filters: None,
+            aggregation: None,
+            metrics: None,
+            group_by: None,
+            sort: None,
+            limit: None,
+            page: None,
+        },
+    );
+    LiquidViewSchema {
+        version: "1.0".to_string(),
+        layout,
+        data_sources,
+    }
+}
+
+/// Wraps a layout in `levels` additional stacks and returns the raw JSON of the result
+fn nested_json(levels: usize) -> Value {
+    let mut layout = json!({
+        "type": "stack",
+        "props": { "direction": "vertical" },
+        "children": [{ "type": "table", "columns": ["amount"] }]
+    });
+    for _ in 0..levels {
+        layout = json!({
+            "type": "stack",
+            "props": { "direction": "vertical" },
+            "children": [layout]
+        });
+    }
+    json!({ "version": "1.0", "layout": layout })
+}
+
+#[test]
+fn test_grid_with_stack_of_tables_roundtrip() {
+    let schema = schema_with_layout(Layout::Grid {
+        props: GridLayoutProps {
+            columns: 2,
+            gap: None,
+        },
+        children: vec![
+            table("expenses").into(),
+            vertical_stack(vec![table("expenses").into(), table("expenses").into()]).into(),
+        ],
+    });
+
+    let json = serde_json::to_value(&schema).unwrap();
+    assert_eq!(json["layout"]["children"][1]["type"], "stack");
+    assert_eq!(
+        json["layout"]["children"][1]["children"][0]["type"],
+        "table"
+    );
+
+    let roundtrip: LiquidViewSchema = serde_json::from_value(json).unwrap();
+    assert_eq!(roundtrip, schema);
+    assert!(SchemaValidator::new().validate(&schema).valid);
+}
+
+#[test]
+fn test_nested_errors_report_recursive_paths() {
+    let schema = schema_with_layout(Layout::Grid {
+        props: GridLayoutProps {
+            columns: 2,
+            gap: None,
+        },
+        children: vec![
+            table("expenses").into(),
+            vertical_stack(vec![
+                Component::Table {
+                    title: None,
+                    data_source: None,
+                    columns: vec![],
+                    sortable: None,
+                    pagination: None,
+                }
+                .into(),
+                table("missing").into(),
+            ])
+            .into(),
+        ],
+    });
+
+    let result = SchemaValidator::new().validate(&schema);
+
+    assert_eq!(
+        result.errors,
+        vec![
+            ValidationError::EmptyTableColumns {
+                path: "layout.children[1].children[0].columns".to_string(),
+            },
+            ValidationError::DanglingDataSourceRef {
+                data_source: "missing".to_string(),
+                path: "layout.children[1].children[1].data_source".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_nested_grid_columns_path() {
+    let schema = schema_with_layout(vertical_stack(vec![Layout::Grid {
+        props: GridLayoutProps {
+            columns: 0,
+            gap: None,
+        },
+        children: vec![],
+    }
+    .into()]));
+
+    let result = SchemaValidator::new().validate(&schema);
+
+    assert_eq!(
+        result.errors,
+        vec![ValidationError::InvalidGridColumns {
+            value: 0,
+            path: "layout.children[0].props.columns".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_maximum_depth_is_allowed() {
+    let document = nested_json(MAX_LAYOUT_DEPTH - 1);
+
+    let result = SchemaValidator::new().validate_json(&document);
+
+    assert!(result.valid, "{:?}", result.errors);
+}
+
+#[test]
+fn test_layout_too_deep() {
+    let document = nested_json(MAX_LAYOUT_DEPTH);
+    let expected_path = format!("layout{}", ".children[0]".repeat(MAX_LAYOUT_DEPTH));
+    let expected = vec![ValidationError::LayoutTooDeep {
+        max_depth: MAX_LAYOUT_DEPTH,
+        path: expected_path,
+    }];
+
+    assert_eq!(
+        SchemaValidator::new().validate_json(&document).errors,
+        expected
+    );
+
+    let schema: LiquidViewSchema = serde_json::from_value(document).unwrap();
+    assert_eq!(SchemaValidator::new().validate(&schema).errors, expected);
+}
+
+#[test]
+fn test_validate_json_nested_paths() {
+    let document = json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 2 },
+            "children": [
+                {
+                    "type": "stack",
+                    "props": { "direction": "vertical" },
+                    "children": [
+                        { "type": "chart", "variant": "radar" },
+                        { "type": "table", "columns": ["a"], "data_source": "nope" }
+                    ]
+                }
+            ]
+        }
+    });
+
+    let errors = SchemaValidator::new().validate_json(&document).errors;
+
+    assert_eq!(
+        errors,
+        vec![
+            ValidationError::InvalidChartVariant {
+                path: "layout.children[0].children[0].variant".to_string(),
+            },
+            ValidationError::DanglingDataSourceRef {
+                data_source: "nope".to_string(),
+                path: "layout.children[0].children[1].data_source".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_json_schema_allows_nested_layouts() {
+    let schema = liquid_view_json_schema();
+    let child = &schema["$defs"]["LayoutChild"];
+    let refs: Vec<&str> = child["anyOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["$ref"].as_str().unwrap())
+        .collect();
+
+    assert_eq!(refs, vec!["#/$defs/Layout", "#/$defs/Component"]);
+}
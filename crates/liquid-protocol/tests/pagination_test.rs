@@ -0,0 +1,159 @@
+//! Pagination Tests
+//!
+//! Tests `DataSource.page` (offset or cursor) and `Table.pagination`: serde
+//! roundtrip, raw structural checks and the limit / sort requirements
+
+use liquid_protocol::*;
+use serde_json::{json, Value};
+
+/// Builds a raw schema with a paged table bound to an `expenses` source
+fn schema_json(pagination: Value, data_source: Value) -> Value {
+    json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [{
+                "type": "table",
+                "data_source": "expenses",
+                "columns": ["id", "amount"],
+                "pagination": pagination
+            }]
+        },
+        "data_sources": { "expenses": data_source }
+    })
+}
+
+fn validate(json: &Value) -> Vec<ValidationError> {
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+    SchemaValidator::new().validate(&schema).errors
+}
+
+#[test]
+fn test_cursor_page_roundtrip_and_validate() {
+    let json = schema_json(
+        json!("cursor"),
+        json!({
+            "resource": "expenses",
+            "sort": [
+                { "field": "amount", "direction": "desc" },
+                { "field": "id", "direction": "asc" }
+            ],
+            "limit": 50,
+            "page": { "after": "eyJpZCI6NDJ9.c2lnbmF0dXJl" }
+        }),
+    );
+
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+
+    assert_eq!(
+        schema.data_sources["expenses"].page,
+        Some(Page::After("eyJpZCI6NDJ9.c2lnbmF0dXJl".to_string()))
+    );
+    assert_eq!(serde_json::to_value(&schema).unwrap(), json);
+    assert!(SchemaValidator::new().validate_json(&json).valid);
+    assert!(SchemaValidator::new().validate(&schema).valid);
+}
+
+#[test]
+fn test_offset_page_roundtrip() {
+    let page: Page = serde_json::from_value(json!({ "offset": 100 })).unwrap();
+
+    assert_eq!(page, Page::Offset(100));
+    assert_eq!(
+        serde_json::to_value(&page).unwrap(),
+        json!({ "offset": 100 })
+    );
+    assert_eq!(PaginationMode::Offset.as_str(), "offset");
+}
+
+#[test]
+fn test_raw_pagination_is_checked() {
+    let json = schema_json(
+        json!("infinite"),
+        json!({ "resource": "expenses", "limit": 10, "page": { "offset": -1 } }),
+    );
+
+    assert_eq!(
+        SchemaValidator::new().validate_json(&json).errors,
+        vec![
+            ValidationError::InvalidPaginationMode {
+                path: "layout.children[0].pagination".to_string(),
+            },
+            ValidationError::InvalidPage {
+                path: "data_sources.expenses.page".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_pagination_requires_limit() {
+    let json = schema_json(
+        json!("offset"),
+        json!({ "resource": "expenses", "page": { "offset": 10 } }),
+    );
+
+    assert_eq!(
+        validate(&json),
+        vec![
+            ValidationError::PaginationRequiresLimit {
+                path: "data_sources.expenses.limit".to_string(),
+            },
+            ValidationError::PaginationRequiresLimit {
+                path: "layout.children[0].pagination".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_cursor_requires_sort_on_non_aggregated_source() {
+    let unsorted = schema_json(
+        json!("cursor"),
+        json!({ "resource": "expenses", "limit": 10, "page": { "after": "token" } }),
+    );
+    let aggregated = schema_json(
+        json!("cursor"),
+        json!({
+            "resource": "expenses",
+            "metrics": [{ "alias": "total", "type": "sum", "field": "amount" }],
+            "group_by": ["category"],
+            "sort": { "field": "total", "direction": "desc" },
+            "limit": 10
+        }),
+    );
+
+    assert_eq!(
+        validate(&unsorted),
+        vec![
+            ValidationError::CursorRequiresSort {
+                path: "data_sources.expenses.page".to_string(),
+            },
+            ValidationError::CursorRequiresSort {
+                path: "layout.children[0].pagination".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        validate(&aggregated),
+        vec![ValidationError::CursorRequiresSort {
+            path: "layout.children[0].pagination".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_zero_limit_is_rejected_by_both_entry_points() {
+    let json = schema_json(
+        json!("offset"),
+        json!({ "resource": "expenses", "limit": 0, "page": { "offset": 10 } }),
+    );
+    let expected = vec![ValidationError::InvalidLimit {
+        value: "0".to_string(),
+        path: "data_sources.expenses.limit".to_string(),
+    }];
+
+    assert_eq!(SchemaValidator::new().validate_json(&json).errors, expected);
+    assert_eq!(validate(&json), expected);
+}
@@ -0,0 +1,110 @@
+//! Relative Date Tests
+//!
+//! Tests the `{"relative": ...}` filter value form: serde roundtrip and
+//! validation in the raw JSON and typed validators
+
+use liquid_protocol::*;
+use serde_json::{json, Value};
+
+/// Builds a raw schema with a table bound to an `expenses` source using the given filters
+fn schema_json(filters: Value) -> Value {
+    json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [{ "type": "table", "data_source": "expenses", "columns": ["amount"] }]
+        },
+        "data_sources": {
+            "expenses": { "resource": "expenses", "filters": filters }
+        }
+    })
+}
+
+#[test]
+fn test_relative_filters_roundtrip_and_validate() {
+    let json = schema_json(json!([
+        { "field": "date", "op": "gte", "value": { "relative": "last_n_days", "n": 30 } },
+        { "field": "date", "op": "lt", "value": { "relative": "start_of_month" } }
+    ]));
+
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+    let filters = schema.data_sources["expenses"].filters.as_ref().unwrap();
+
+    assert!(matches!(
+        &filters[0],
+        FilterNode::Filter(Filter {
+            value: FilterValue::Relative(RelativeDate::LastNDays { n: 30 }),
+            ..
+        })
+    ));
+    assert_eq!(serde_json::to_value(&schema).unwrap(), json);
+    assert!(SchemaValidator::new().validate_json(&json).valid);
+    assert!(SchemaValidator::new().validate(&schema).valid);
+}
+
+#[test]
+fn test_unknown_relative_date_is_rejected() {
+    let cases = [
+        json!({ "relative": "next_tuesday" }),
+        json!({ "relative": "last_n_days" }),
+        json!({ "relative": "last_n_days", "n": -1 }),
+    ];
+
+    for value in cases {
+        let json = schema_json(json!([{ "field": "date", "op": "gte", "value": value }]));
+
+        let result = SchemaValidator::new().validate_json(&json);
+
+        assert_eq!(
+            result.errors,
+            vec![ValidationError::InvalidRelativeDate {
+                path: "data_sources.expenses.filters[0].value".to_string(),
+            }],
+            "{}",
+            value
+        );
+    }
+}
+
+#[test]
+fn test_relative_date_requires_scalar_operator() {
+    let json = schema_json(json!([
+        { "field": "date", "op": "in", "value": { "relative": "today" } }
+    ]));
+
+    let result = SchemaValidator::new().validate_json(&json);
+
+    assert_eq!(
+        result.errors,
+        vec![ValidationError::InvalidFilterValueType {
+            path: "data_sources.expenses.filters[0].value".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_other_objects_are_still_invalid_types() {
+    let json = schema_json(json!([
+        { "field": "date", "op": "eq", "value": { "days": 30 } }
+    ]));
+
+    let result = SchemaValidator::new().validate_json(&json);
+
+    assert!(matches!(
+        &result.errors[..],
+        [ValidationError::InvalidType { path, .. }] if path == "data_sources.expenses.filters[0].value"
+    ));
+}
+
+#[test]
+fn test_relative_date_as_str_matches_serde() {
+    for relative in [
+        RelativeDate::Today,
+        RelativeDate::StartOfQuarter,
+        RelativeDate::LastNMonths { n: 2 },
+    ] {
+        let json = serde_json::to_value(relative).unwrap();
+        assert_eq!(json["relative"], relative.as_str());
+    }
+}
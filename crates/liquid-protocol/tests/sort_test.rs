@@ -0,0 +1,156 @@
+//! Sort Tests
+//!
+//! Tests multi-key sort on `DataSource.sort`: serde roundtrip of single and
+//! list forms, nulls ordering and checks that sort keys exist in the result
+
+use liquid_protocol::*;
+use serde_json::{json, Value};
+
+/// Builds a raw schema with a table bound to an `expenses` source
+fn schema_json(data_source: Value) -> Value {
+    json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [{ "type": "table", "data_source": "expenses", "columns": ["category"] }]
+        },
+        "data_sources": { "expenses": data_source }
+    })
+}
+
+fn validate(json: &Value) -> Vec<ValidationError> {
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+    SchemaValidator::new().validate(&schema).errors
+}
+
+#[test]
+fn test_sort_list_roundtrip_and_validate() {
+    let json = schema_json(json!({
+        "resource": "expenses",
+        "sort": [
+            { "field": "category", "direction": "asc" },
+            { "field": "amount", "direction": "desc", "nulls": "last" }
+        ]
+    }));
+
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+    let sort = schema.data_sources["expenses"].sort.as_ref().unwrap();
+    let keys = sort.keys();
+
+    assert_eq!(keys.len(), 2);
+    assert_eq!(
+        keys[1],
+        (
+            "[1]".to_string(),
+            &Sort {
+                field: "amount".to_string(),
+                direction: SortDirection::Desc,
+                nulls: Some(NullsOrder::Last),
+            }
+        )
+    );
+    assert_eq!(serde_json::to_value(&schema).unwrap(), json);
+    assert!(SchemaValidator::new().validate_json(&json).valid);
+    assert!(SchemaValidator::new().validate(&schema).valid);
+}
+
+#[test]
+fn test_single_sort_is_still_accepted() {
+    let sort: SortKeys =
+        serde_json::from_value(json!({ "field": "amount", "direction": "desc" })).unwrap();
+
+    assert_eq!(
+        sort,
+        SortKeys::Single(Sort {
+            field: "amount".to_string(),
+            direction: SortDirection::Desc,
+            nulls: None,
+        })
+    );
+    assert_eq!(sort.keys()[0].0, "");
+}
+
+#[test]
+fn test_raw_sort_keys_are_checked() {
+    let json = schema_json(json!({
+        "resource": "expenses",
+        "sort": [
+            { "field": "category", "direction": "asc", "nulls": "middle" },
+            { "direction": "down" }
+        ]
+    }));
+
+    assert_eq!(
+        SchemaValidator::new().validate_json(&json).errors,
+        vec![
+            ValidationError::InvalidSortNulls {
+                path: "data_sources.expenses.sort[0].nulls".to_string(),
+            },
+            ValidationError::MissingSortField {
+                field: "field".to_string(),
+                path: "data_sources.expenses.sort[1].field".to_string(),
+            },
+            ValidationError::InvalidSortDirection {
+                path: "data_sources.expenses.sort[1].direction".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_aggregated_sort_must_use_result_columns() {
+    let json = schema_json(json!({
+        "resource": "expenses",
+        "metrics": [{ "alias": "total", "type": "sum", "field": "amount" }],
+        "group_by": ["category"],
+        "sort": [
+            { "field": "total", "direction": "desc" },
+            { "field": "category", "direction": "asc" },
+            { "field": "amount", "direction": "desc" }
+        ]
+    }));
+
+    assert_eq!(
+        validate(&json),
+        vec![ValidationError::UnknownSortField {
+            field: "amount".to_string(),
+            path: "data_sources.expenses.sort[2].field".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_sort_keys_are_checked_against_catalog() {
+    struct ExpensesCatalog;
+
+    impl FieldCatalog for ExpensesCatalog {
+        fn has_resource(&self, resource: &str) -> bool {
+            resource == "expenses"
+        }
+
+        fn has_field(&self, _resource: &str, field: &str) -> bool {
+            ["amount", "category"].contains(&field)
+        }
+    }
+
+    let json = schema_json(json!({
+        "resource": "expenses",
+        "sort": [
+            { "field": "category", "direction": "asc" },
+            { "field": "created_by", "direction": "asc" }
+        ]
+    }));
+    let schema: LiquidViewSchema = serde_json::from_value(json).unwrap();
+
+    assert_eq!(
+        SchemaValidator::with_field_catalog(ExpensesCatalog)
+            .validate(&schema)
+            .errors,
+        vec![ValidationError::UnknownField {
+            resource: "expenses".to_string(),
+            field: "created_by".to_string(),
+            path: "data_sources.expenses.sort[1].field".to_string(),
+        }]
+    );
+}
@@ -0,0 +1,118 @@
+//! Time Bucket Tests
+//!
+//! Tests date-truncation grouping on `Aggregation.bucket`: serde roundtrip
+//! and validation in the raw JSON and typed validators
+
+use liquid_protocol::*;
+use serde_json::{json, Value};
+
+/// Builds a raw schema with a table bound to an `expenses` source using the given aggregation
+fn schema_json(aggregation: Value) -> Value {
+    json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [{ "type": "table", "data_source": "expenses", "columns": ["date"] }]
+        },
+        "data_sources": {
+            "expenses": { "resource": "expenses", "aggregation": aggregation }
+        }
+    })
+}
+
+#[test]
+fn test_bucket_roundtrip_and_validate() {
+    let json = schema_json(json!({
+        "type": "sum",
+        "field": "amount",
+        "by": "date",
+        "bucket": { "unit": "month", "timezone": "Asia/Tokyo", "zero_fill": true }
+    }));
+
+    let schema: LiquidViewSchema = serde_json::from_value(json.clone()).unwrap();
+    let aggregation = schema.data_sources["expenses"]
+        .aggregation
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(
+        aggregation.bucket,
+        Some(TimeBucket {
+            unit: TimeUnit::Month,
+            timezone: Some("Asia/Tokyo".to_string()),
+            zero_fill: true,
+        })
+    );
+    assert_eq!(serde_json::to_value(&schema).unwrap(), json);
+    assert!(SchemaValidator::new().validate_json(&json).valid);
+}
+
+#[test]
+fn test_bucket_defaults_are_omitted() {
+    let bucket: TimeBucket = serde_json::from_value(json!({ "unit": "week" })).unwrap();
+
+    assert_eq!(bucket.timezone, None);
+    assert!(!bucket.zero_fill);
+    assert_eq!(
+        serde_json::to_value(&bucket).unwrap(),
+        json!({ "unit": "week" })
+    );
+}
+
+#[test]
+fn test_invalid_bucket_is_rejected() {
+    let json = schema_json(json!({
+        "type": "sum",
+        "field": "amount",
+        "by": "date",
+        "bucket": { "unit": "fortnight", "zero_fill": "yes" }
+    }));
+
+    let result = SchemaValidator::new().validate_json(&json);
+
+    assert_eq!(
+        result.errors,
+        vec![
+            ValidationError::InvalidTimeUnit {
+                path: "data_sources.expenses.aggregation.bucket.unit".to_string(),
+            },
+            ValidationError::InvalidType {
+                path: "data_sources.expenses.aggregation.bucket.zero_fill".to_string(),
+                message: "expected boolean, got string".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_bucket_requires_group_by_field() {
+    let json = schema_json(json!({
+        "type": "count",
+        "field": "id",
+        "bucket": { "unit": "day" }
+    }));
+
+    let result = SchemaValidator::new().validate_json(&json);
+
+    assert_eq!(
+        result.errors,
+        vec![ValidationError::MissingAggregationField {
+            field: "by".to_string(),
+            path: "data_sources.expenses.aggregation.by".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_time_unit_as_str_matches_serde() {
+    for unit in [
+        TimeUnit::Day,
+        TimeUnit::Week,
+        TimeUnit::Month,
+        TimeUnit::Quarter,
+        TimeUnit::Year,
+    ] {
+        assert_eq!(serde_json::to_value(unit).unwrap(), unit.as_str());
+    }
+}
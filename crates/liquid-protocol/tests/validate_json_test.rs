@@ -0,0 +1,272 @@
+//! Raw JSON Validation Tests
+//!
+//! Tests `SchemaValidator::validate_json` error collection and JSON paths
+
+use liquid_protocol::{SchemaValidator, ValidationError};
+use serde_json::{json, Value};
+
+/// Returns a valid schema document that individual tests break
+fn valid_document() -> Value {
+    json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 2 },
+            "children": [
+                { "type": "chart", "variant": "bar", "data_source": "sales", "xAxis": "month" },
+                { "type": "table", "columns": ["month", "amount"] }
+            ]
+        },
+        "data_sources": {
+            "sales": {
+                "resource": "sales",
+                "filters": [{ "field": "year", "op": "eq", "value": 2024 }],
+                "aggregation": { "type": "sum", "field": "amount", "by": "month" },
+                "sort": { "field": "month", "direction": "asc" },
+                "limit": 12
+            }
+        }
+    })
+}
+
+fn validate(document: &Value) -> Vec<ValidationError> {
+    SchemaValidator::new().validate_json(document).errors
+}
+
+#[test]
+fn test_valid_document() {
+    let result = SchemaValidator::new().validate_json(&valid_document());
+
+    assert!(result.valid, "{:?}", result.errors);
+}
+
+#[test]
+fn test_non_object_root() {
+    let errors = validate(&json!(["not", "a", "schema"]));
+
+    assert!(matches!(
+        &errors[..],
+        [ValidationError::InvalidType { path, .. }] if path == "root"
+    ));
+}
+
+#[test]
+fn test_missing_required_root_fields() {
+    let errors = validate(&json!({}));
+
+    assert_eq!(
+        errors,
+        vec![
+            ValidationError::MissingRequiredField {
+                field: "version".to_string(),
+                path: "version".to_string(),
+            },
+            ValidationError::MissingRequiredField {
+                field: "layout".to_string(),
+                path: "layout".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_unsupported_version() {
+    let mut document = valid_document();
+    document["version"] = json!("2.0");
+
+    assert_eq!(
+        validate(&document),
+        vec![ValidationError::UnsupportedVersion("2.0".to_string())]
+    );
+}
+
+#[test]
+fn test_invalid_layout_type() {
+    let mut document = valid_document();
+    document["layout"]["type"] = json!("masonry");
+
+    assert_eq!(
+        validate(&document),
+        vec![ValidationError::InvalidLayoutType {
+            path: "layout.type".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_invalid_grid_columns() {
+    let mut document = valid_document();
+    document["layout"]["props"]["columns"] = json!(0);
+
+    assert_eq!(
+        validate(&document),
+        vec![ValidationError::InvalidGridColumns {
+            value: 0,
+            path: "layout.props.columns".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_invalid_component_type_and_variant() {
+    let mut document = valid_document();
+    document["layout"]["children"][0]["variant"] = json!("donut");
+    document["layout"]["children"][1]["type"] = json!("card");
+
+    assert_eq!(
+        validate(&document),
+        vec![
+            ValidationError::InvalidChartVariant {
+                path: "layout.children[0].variant".to_string(),
+            },
+            ValidationError::InvalidComponentType {
+                path: "layout.children[1].type".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_filter_op_and_missing_value() {
+    let mut document = valid_document();
+    document["data_sources"]["sales"]["filters"] = json!([
+        { "field": "year", "op": "like", "value": "2024" },
+        { "field": "region", "op": "eq" }
+    ]);
+
+    assert_eq!(
+        validate(&document),
+        vec![
+            ValidationError::InvalidFilterOp {
+                path: "data_sources.sales.filters[0].op".to_string(),
+            },
+            ValidationError::MissingFilterField {
+                field: "value".to_string(),
+                path: "data_sources.sales.filters[1].value".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_filter_value_type_mismatch() {
+    let mut document = valid_document();
+    document["data_sources"]["sales"]["filters"] = json!([
+        { "field": "year", "op": "in", "value": 2024 },
+        { "field": "region", "op": "eq", "value": ["east"] },
+        { "field": "region", "op": "eq", "value": { "nested": true } }
+    ]);
+
+    let errors = validate(&document);
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(
+        errors[0],
+        ValidationError::InvalidFilterValueType {
+            path: "data_sources.sales.filters[0].value".to_string(),
+        }
+    );
+    assert_eq!(
+        errors[1],
+        ValidationError::InvalidFilterValueType {
+            path: "data_sources.sales.filters[1].value".to_string(),
+        }
+    );
+    assert!(matches!(
+        &errors[2],
+        ValidationError::InvalidType { path, .. } if path == "data_sources.sales.filters[2].value"
+    ));
+}
+
+#[test]
+fn test_invalid_aggregation_sort_and_limit() {
+    let mut document = valid_document();
+    document["data_sources"]["sales"]["aggregation"] = json!({ "type": "mode" });
+    document["data_sources"]["sales"]["sort"] = json!({ "field": "month", "direction": "up" });
+    document["data_sources"]["sales"]["limit"] = json!(-5);
+
+    assert_eq!(
+        validate(&document),
+        vec![
+            ValidationError::MissingAggregationField {
+                field: "field".to_string(),
+                path: "data_sources.sales.aggregation.field".to_string(),
+            },
+            ValidationError::InvalidAggregationType {
+                path: "data_sources.sales.aggregation.type".to_string(),
+            },
+            ValidationError::InvalidSortDirection {
+                path: "data_sources.sales.sort.direction".to_string(),
+            },
+            ValidationError::InvalidLimit {
+                value: "-5".to_string(),
+                path: "data_sources.sales.limit".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_missing_resource_and_sort_field() {
+    let mut document = valid_document();
+    document["data_sources"]["sales"]["resource"] = json!("");
+    document["data_sources"]["sales"]["sort"] = json!({ "direction": "desc" });
+
+    assert_eq!(
+        validate(&document),
+        vec![
+            ValidationError::MissingResource {
+                path: "data_sources.sales.resource".to_string(),
+            },
+            ValidationError::MissingSortField {
+                field: "field".to_string(),
+                path: "data_sources.sales.sort.field".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_dangling_reference_in_raw_document() {
+    let mut document = valid_document();
+    document["layout"]["children"][1]["data_source"] = json!("missing");
+
+    assert_eq!(
+        validate(&document),
+        vec![ValidationError::DanglingDataSourceRef {
+            data_source: "missing".to_string(),
+            path: "layout.children[1].data_source".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_collects_errors_across_the_document() {
+    let document = json!({
+        "version": 1,
+        "layout": { "type": "stack", "props": { "direction": "diagonal" }, "children": "none" },
+        "data_sources": { "a": { "limit": 0 } }
+    });
+
+    let errors = validate(&document);
+    let paths: Vec<String> = errors
+        .iter()
+        .map(|error| match error {
+            ValidationError::InvalidType { path, .. }
+            | ValidationError::MissingResource { path }
+            | ValidationError::InvalidLimit { path, .. } => path.clone(),
+            other => panic!("unexpected error: {:?}", other),
+        })
+        .collect();
+
+    assert_eq!(
+        paths,
+        vec![
+            "version",
+            "layout.props.direction",
+            "layout.children",
+            "data_sources.a.resource",
+            "data_sources.a.limit",
+        ]
+    );
+}
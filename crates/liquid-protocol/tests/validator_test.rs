@@ -43,17 +43,26 @@ fn test_roundtrip_complex_schema() {
                 field: "status".to_string(),
                 op: FilterOperator::Eq,
                 value: FilterValue::String("completed".to_string()),
-            }]),
+            }
+            .into()]),
             aggregation: Some(Aggregation {
                 agg_type: AggregationType::Sum,
                 field: "amount".to_string(),
                 by: Some("month".to_string()),
+                bucket: None,
             }),
-            sort: Some(Sort {
-                field: "month".to_string(),
-                direction: SortDirection::Asc,
-            }),
+            metrics: None,
+            group_by: None,
+            sort: Some(
+                Sort {
+                    field: "month".to_string(),
+                    direction: SortDirection::Asc,
+                    nulls: None,
+                }
+                .into(),
+            ),
             limit: Some(12),
+            page: None,
         },
     );
 
@@ -70,14 +79,17 @@ fn test_roundtrip_complex_schema() {
                     data_source: Some("sales_data".to_string()),
                     variant: ChartVariant::Bar,
                     x_axis: Some("month".to_string()),
-                    y_axis: Some("amount".to_string()),
-                },
+                    y_axis: Some("amount".into()),
+                }
+                .into(),
                 Component::Table {
                     title: Some("Sales Table".to_string()),
                     data_source: Some("sales_data".to_string()),
                     columns: vec!["month".to_string(), "amount".to_string()],
                     sortable: Some(true),
-                },
+                    pagination: None,
+                }
+                .into(),
             ],
         },
         data_sources,
@@ -235,7 +247,7 @@ fn test_component_with_data_source() {
         data_source: Some("sales_data".to_string()),
         variant: ChartVariant::Line,
         x_axis: Some("date".to_string()),
-        y_axis: Some("revenue".to_string()),
+        y_axis: Some("revenue".into()),
     };
 
     let json = serde_json::to_value(&component).expect("Failed to serialize");
@@ -255,17 +267,26 @@ fn test_data_source_complete() {
             field: "year".to_string(),
             op: FilterOperator::Eq,
             value: FilterValue::Number(2024.0),
-        }]),
+        }
+        .into()]),
         aggregation: Some(Aggregation {
             agg_type: AggregationType::Sum,
             field: "amount".to_string(),
             by: Some("month".to_string()),
+            bucket: None,
         }),
-        sort: Some(Sort {
-            field: "month".to_string(),
-            direction: SortDirection::Asc,
-        }),
+        metrics: None,
+        group_by: None,
+        sort: Some(
+            Sort {
+                field: "month".to_string(),
+                direction: SortDirection::Asc,
+                nulls: None,
+            }
+            .into(),
+        ),
         limit: Some(10),
+        page: None,
     };
 
     // Roundtrip test
@@ -289,10 +310,14 @@ fn test_validator_valid_schema() {
                     FilterValueScalar::String("electronics".to_string()),
                     FilterValueScalar::String("books".to_string()),
                 ]),
-            }]),
+            }
+            .into()]),
             aggregation: None,
+            metrics: None,
+            group_by: None,
             sort: None,
             limit: None,
+            page: None,
         },
     );
 
@@ -309,14 +334,19 @@ fn test_validator_valid_schema() {
                 variant: ChartVariant::Bar,
                 x_axis: None,
                 y_axis: None,
-            }],
+            }
+            .into()],
         },
         data_sources,
     };
 
     let validator = SchemaValidator::new();
     let result = validator.validate(&schema);
-    assert!(result.valid, "Expected valid schema, got errors: {:?}", result.errors);
+    assert!(
+        result.valid,
+        "Expected valid schema, got errors: {:?}",
+        result.errors
+    );
     assert_eq!(result.errors.len(), 0);
 }
 
@@ -359,7 +389,9 @@ fn test_validator_dangling_reference() {
                 data_source: Some("missing_data".to_string()),
                 columns: vec!["col1".to_string()],
                 sortable: None,
-            }],
+                pagination: None,
+            }
+            .into()],
         },
         data_sources: HashMap::new(),
     };
@@ -0,0 +1,205 @@
+// FR-13: Policy Decision Audit Log
+
+use crate::converter::{ConversionError, QueryCondition};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// RLS適用1回分の監査イベント
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditEvent {
+    /// 記録日時 (UTC)
+    pub timestamp: DateTime<Utc>,
+    pub user_id: u64,
+    pub resource: String,
+    /// 評価したポリシー名 (評価順)
+    pub policies: Vec<String>,
+    pub decision: AuditDecision,
+    /// 拒否時のエラーコード
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    /// 拒否理由
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// 注入した条件
+    pub injected_conditions: Vec<QueryCondition>,
+    /// 保護フィールドのため除去したユーザー条件
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stripped_conditions: Vec<QueryCondition>,
+}
+
+impl AuditEvent {
+    /// 評価結果から生成
+    pub(crate) fn new(
+        user_id: u64,
+        resource: &str,
+        policies: Vec<String>,
+        result: &Result<Vec<QueryCondition>, ConversionError>,
+    ) -> Self {
+        let (decision, error_code, reason, injected_conditions) = match result {
+            Ok(conditions) => (AuditDecision::Allow, None, None, conditions.clone()),
+            Err(err) => (
+                AuditDecision::Deny,
+                Some(err.code().to_string()),
+                Some(err.message().to_string()),
+                Vec::new(),
+            ),
+        };
+        Self {
+            timestamp: Utc::now(),
+            user_id,
+            resource: resource.to_string(),
+            policies,
+            decision,
+            error_code,
+            reason,
+            injected_conditions,
+            stripped_conditions: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_stripped_conditions(mut self, conditions: Vec<QueryCondition>) -> Self {
+        self.stripped_conditions = conditions;
+        self
+    }
+}
+
+/// アクセス判定
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditDecision {
+    Allow,
+    Deny,
+}
+
+/// 監査イベントの出力先
+///
+/// 記録に失敗した場合、`SecurityEnforcer::enforce` はクエリを拒否する。
+pub trait AuditSink: Send + Sync {
+    fn record(&self, event: &AuditEvent) -> Result<(), ConversionError>;
+}
+
+/// メモリ上に保持するSink (テスト・デバッグ用)
+#[derive(Debug, Default)]
+pub struct InMemoryAuditSink {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+impl InMemoryAuditSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 記録済みイベントのコピー
+    pub fn events(&self) -> Vec<AuditEvent> {
+        self.lock().clone()
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<AuditEvent>> {
+        // 記録中にpanicしてもイベント自体は有効
+        self.events
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl AuditSink for InMemoryAuditSink {
+    fn record(&self, event: &AuditEvent) -> Result<(), ConversionError> {
+        self.lock().push(event.clone());
+        Ok(())
+    }
+}
+
+/// JSON Lines形式でファイルに追記するSink
+#[derive(Debug)]
+pub struct JsonLinesAuditSink {
+    file: Mutex<File>,
+}
+
+impl JsonLinesAuditSink {
+    /// ファイルを追記モードで開く (存在しない場合は作成)
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ConversionError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(audit_error)?;
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl AuditSink for JsonLinesAuditSink {
+    fn record(&self, event: &AuditEvent) -> Result<(), ConversionError> {
+        let mut line = serde_json::to_string(event).map_err(audit_error)?;
+        line.push('\n');
+
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| ConversionError::new("AUDIT_ERROR", "Audit log file lock poisoned"))?;
+        // 1行を1回で書き込み、行が混ざらないようにする
+        file.write_all(line.as_bytes()).map_err(audit_error)?;
+        file.flush().map_err(audit_error)
+    }
+}
+
+fn audit_error(err: impl std::fmt::Display) -> ConversionError {
+    ConversionError::new("AUDIT_ERROR", format!("Failed to write audit log: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_audit_event_serialization() {
+        let event = AuditEvent::new(
+            7,
+            "expenses",
+            vec!["owner".to_string()],
+            &Ok(vec![QueryCondition::Eq {
+                field: "owner_id".to_string(),
+                value: 7i64.into(),
+            }]),
+        );
+
+        let mut value = serde_json::to_value(&event).unwrap();
+        value.as_object_mut().unwrap().remove("timestamp");
+
+        assert_eq!(
+            value,
+            json!({
+                "user_id": 7,
+                "resource": "expenses",
+                "policies": ["owner"],
+                "decision": "allow",
+                "injected_conditions": [{ "op": "eq", "field": "owner_id", "value": 7 }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_audit_event_denied() {
+        let event = AuditEvent::new(
+            1,
+            "payroll",
+            vec![],
+            &Err(ConversionError::new("ACCESS_DENIED", "no")),
+        );
+
+        assert_eq!(event.decision, AuditDecision::Deny);
+        assert_eq!(event.error_code.as_deref(), Some("ACCESS_DENIED"));
+        assert_eq!(event.reason.as_deref(), Some("no"));
+        assert!(event.injected_conditions.is_empty());
+    }
+}
@@ -0,0 +1,143 @@
+// FR-06: Time Bucket Zero Fill
+
+use crate::converter::ConvertedQuery;
+use chrono::{Datelike, Days, Months, NaiveDate};
+use liquid_protocol::{AggregationType, SortDirection, TimeUnit};
+use serde_json::Value;
+
+/// 日付を含む期間の開始日 (週は月曜始まり)
+pub fn bucket_start(date: NaiveDate, unit: TimeUnit) -> NaiveDate {
+    match unit {
+        TimeUnit::Day => date,
+        TimeUnit::Week => date - Days::new(u64::from(date.weekday().num_days_from_monday())),
+        TimeUnit::Month => first_day(date.year(), date.month()),
+        TimeUnit::Quarter => first_day(date.year(), date.month0() / 3 * 3 + 1),
+        TimeUnit::Year => first_day(date.year(), 1),
+    }
+}
+
+/// 次の期間の開始日
+fn next_bucket(start: NaiveDate, unit: TimeUnit) -> Option<NaiveDate> {
+    match unit {
+        TimeUnit::Day => start.checked_add_days(Days::new(1)),
+        TimeUnit::Week => start.checked_add_days(Days::new(7)),
+        TimeUnit::Month => start.checked_add_months(Months::new(1)),
+        TimeUnit::Quarter => start.checked_add_months(Months::new(3)),
+        TimeUnit::Year => start.checked_add_months(Months::new(12)),
+    }
+}
+
+/// 集計結果に空の期間を補完
+///
+/// `zero_fill`を指定した期間が唯一のグループ化フィールドの場合のみ対象。
+/// 最初と最後の期間の間で欠けている期間に、合計・件数は0、それ以外の集計はnullの行を追加し、
+/// 期間順 (期間フィールドで降順ソートしている場合は降順) に並べ替える。
+///
+/// 補完した行の位置はクエリのソート順と一致する必要があるため、期間以外のフィールドで
+/// ソートする場合と件数を制限する場合 (ページングを含む) は補完しない。
+pub fn fill_empty_buckets(query: &ConvertedQuery, rows: &mut Vec<Value>) {
+    let Some(aggregation) = query.aggregation() else {
+        return;
+    };
+    let Some(bucket) = aggregation.bucket().filter(|bucket| bucket.zero_fill()) else {
+        return;
+    };
+    if aggregation.group_by() != [bucket.field()] || query.limit().is_some() {
+        return;
+    }
+    if query
+        .sort()
+        .first()
+        .is_some_and(|sort| sort.field() != bucket.field())
+    {
+        return;
+    }
+
+    let Some(mut keyed) = rows
+        .iter()
+        .map(|row| Some((bucket_key(row, bucket.field())?, row.clone())))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+    keyed.sort_by_key(|(key, _)| *key);
+    let (Some(first), Some(last)) = (keyed.first(), keyed.last()) else {
+        return;
+    };
+
+    let mut filled = Vec::with_capacity(keyed.len());
+    let mut existing = keyed.iter().peekable();
+    let mut current = Some(bucket_start(first.0, bucket.unit()));
+    while let Some(start) = current.filter(|start| *start <= last.0) {
+        match existing.next_if(|(key, _)| *key == start) {
+            Some((_, row)) => filled.push(row.clone()),
+            None => {
+                let mut row = serde_json::Map::new();
+                row.insert(
+                    bucket.field().to_string(),
+                    Value::String(start.format("%Y-%m-%d").to_string()),
+                );
+                for metric in aggregation.metrics() {
+                    row.insert(metric.alias().to_string(), empty_value(metric.agg_type()));
+                }
+                filled.push(Value::Object(row));
+            }
+        }
+        current = next_bucket(start, bucket.unit());
+    }
+
+    let descending = query.sort().first().is_some_and(|sort| {
+        sort.field() == bucket.field() && *sort.direction() == SortDirection::Desc
+    });
+    if descending {
+        filled.reverse();
+    }
+    *rows = filled;
+}
+
+/// 行の期間キー (`YYYY-MM-DD`)
+fn bucket_key(row: &Value, field: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(row.get(field)?.as_str()?, "%Y-%m-%d").ok()
+}
+
+/// 空の期間の集計値
+fn empty_value(agg_type: &AggregationType) -> Value {
+    match agg_type {
+        AggregationType::Sum | AggregationType::Count | AggregationType::CountDistinct => {
+            Value::from(0)
+        }
+        AggregationType::Avg
+        | AggregationType::Min
+        | AggregationType::Max
+        | AggregationType::Median
+        | AggregationType::Percentile(_) => Value::Null,
+    }
+}
+
+fn first_day(year: i32, month: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, 1).expect("first day of month is valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_start() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+        let cases = [
+            (TimeUnit::Day, (2024, 5, 15)),
+            (TimeUnit::Week, (2024, 5, 13)),
+            (TimeUnit::Month, (2024, 5, 1)),
+            (TimeUnit::Quarter, (2024, 4, 1)),
+            (TimeUnit::Year, (2024, 1, 1)),
+        ];
+
+        for (unit, (y, m, d)) in cases {
+            assert_eq!(
+                bucket_start(date, unit),
+                NaiveDate::from_ymd_opt(y, m, d).unwrap()
+            );
+        }
+    }
+}
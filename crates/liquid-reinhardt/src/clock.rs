@@ -0,0 +1,106 @@
+// FR-06: Clock / Timezone for Relative Date Resolution
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, TimeZone, Utc};
+use liquid_protocol::RelativeDate;
+
+pub use chrono_tz::Tz;
+
+/// 現在時刻の取得元
+///
+/// 相対日付 (`{"relative": "start_of_month"}` など) の解決に使う。
+/// テストや過去時点の再現では`FixedClock`を差し込む。
+pub trait Clock: Send + Sync {
+    /// 現在時刻 (UTC)
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// システム時刻を返すClock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// 常に同じ時刻を返すClock
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(DateTime<Utc>);
+
+impl FixedClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self(now)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// 相対日付を指定タイムゾーンの暦日に解決
+pub fn resolve_relative_date(
+    relative: &RelativeDate,
+    now: DateTime<Utc>,
+    timezone: Tz,
+) -> NaiveDate {
+    let today = now.with_timezone(&timezone).date_naive();
+
+    match relative {
+        RelativeDate::Today => today,
+        RelativeDate::Yesterday => today - Days::new(1),
+        RelativeDate::StartOfWeek => {
+            today - Days::new(u64::from(today.weekday().num_days_from_monday()))
+        }
+        RelativeDate::StartOfMonth => first_day(today.year(), today.month()),
+        RelativeDate::StartOfQuarter => first_day(today.year(), (today.month0() / 3) * 3 + 1),
+        RelativeDate::StartOfYear => first_day(today.year(), 1),
+        RelativeDate::LastNDays { n } => today
+            .checked_sub_days(Days::new(u64::from(*n)))
+            .unwrap_or(NaiveDate::MIN),
+        RelativeDate::LastNMonths { n } => today
+            .checked_sub_months(Months::new(*n))
+            .unwrap_or(NaiveDate::MIN),
+    }
+}
+
+/// 暦日の開始時刻 (指定タイムゾーンの0時) をUTCで返す
+///
+/// 夏時間の切り替えで0時が存在しない日は、その日の最初の有効な時刻を使う。
+pub fn start_of_day(date: NaiveDate, timezone: Tz) -> DateTime<Utc> {
+    let midnight = date.and_hms_opt(0, 0, 0).expect("midnight is a valid time");
+    (0..24)
+        .find_map(|hour| {
+            timezone
+                .from_local_datetime(&(midnight + chrono::Duration::hours(hour)))
+                .earliest()
+        })
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .unwrap_or_else(|| midnight.and_utc())
+}
+
+fn first_day(year: i32, month: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, 1).expect("first day of month is valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 15, 12, 0, 0).unwrap();
+        assert_eq!(FixedClock::new(now).now(), now);
+    }
+
+    #[test]
+    fn test_start_of_day_in_timezone() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(
+            start_of_day(date, chrono_tz::Asia::Tokyo),
+            Utc.with_ymd_and_hms(2023, 12, 31, 15, 0, 0).unwrap()
+        );
+    }
+}
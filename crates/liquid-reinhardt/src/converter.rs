@@ -1,22 +1,166 @@
 // FR-06: DataSource → ORM Converter Implementation
 
-use liquid_protocol::{DataSource, Filter, FilterOperator, FilterValue, FilterValueScalar};
+use crate::clock::{resolve_relative_date, start_of_day, Clock, SystemClock};
+use crate::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+use liquid_protocol::{
+    Aggregation, AggregationType, DataSource, Filter, FilterNode, FilterOperator, FilterValue,
+    FilterValueScalar, Metric, NullsOrder, Page, RelativeDate, SortDirection, SortKeys, TimeBucket,
+    TimeUnit,
+};
+use serde::Serialize;
 use std::fmt;
+use std::sync::Arc;
 
-/// クエリ条件を表すEnum
-#[derive(Debug, Clone, PartialEq)]
+/// 型付きのクエリ値
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum QueryValue {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    Date(NaiveDate),
+    DateTime(DateTime<Utc>),
+    Null,
+}
+
+impl From<&str> for QueryValue {
+    fn from(value: &str) -> Self {
+        QueryValue::String(value.to_string())
+    }
+}
+
+impl From<String> for QueryValue {
+    fn from(value: String) -> Self {
+        QueryValue::String(value)
+    }
+}
+
+impl From<i64> for QueryValue {
+    fn from(value: i64) -> Self {
+        QueryValue::Integer(value)
+    }
+}
+
+impl From<u64> for QueryValue {
+    /// i64に収まらないIDは文字列として扱う
+    fn from(value: u64) -> Self {
+        i64::try_from(value)
+            .map(QueryValue::Integer)
+            .unwrap_or_else(|_| QueryValue::String(value.to_string()))
+    }
+}
+
+impl From<f64> for QueryValue {
+    fn from(value: f64) -> Self {
+        QueryValue::Float(value)
+    }
+}
+
+impl From<bool> for QueryValue {
+    fn from(value: bool) -> Self {
+        QueryValue::Boolean(value)
+    }
+}
+
+impl From<NaiveDate> for QueryValue {
+    fn from(value: NaiveDate) -> Self {
+        QueryValue::Date(value)
+    }
+}
+
+impl From<DateTime<Utc>> for QueryValue {
+    fn from(value: DateTime<Utc>) -> Self {
+        QueryValue::DateTime(value)
+    }
+}
+
+/// クエリ条件を表すEnum (監査ログでは `{"op": "eq", "field": ..., "value": ...}` で出力)
+///
+/// `And` / `Or` / `Not` は条件をネストするグループ。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
 pub enum QueryCondition {
-    Eq { field: String, value: String },
-    Neq { field: String, value: String },
-    Gt { field: String, value: f64 },
-    Gte { field: String, value: f64 },
-    Lt { field: String, value: f64 },
-    Lte { field: String, value: f64 },
-    In { field: String, values: Vec<String> },
-    Contains { field: String, value: String },
+    Eq {
+        field: String,
+        value: QueryValue,
+    },
+    Neq {
+        field: String,
+        value: QueryValue,
+    },
+    Gt {
+        field: String,
+        value: QueryValue,
+    },
+    Gte {
+        field: String,
+        value: QueryValue,
+    },
+    Lt {
+        field: String,
+        value: QueryValue,
+    },
+    Lte {
+        field: String,
+        value: QueryValue,
+    },
+    In {
+        field: String,
+        values: Vec<QueryValue>,
+    },
+    NotIn {
+        field: String,
+        values: Vec<QueryValue>,
+    },
+    /// 両端を含む範囲 (`low <= field <= high`)
+    Between {
+        field: String,
+        low: QueryValue,
+        high: QueryValue,
+    },
+    IsNull {
+        field: String,
+    },
+    IsNotNull {
+        field: String,
+    },
+    /// 部分一致 (大文字小文字を区別)
+    Contains {
+        field: String,
+        value: String,
+    },
+    /// 部分一致 (大文字小文字を区別しない)
+    #[serde(rename = "icontains")]
+    IContains {
+        field: String,
+        value: String,
+    },
+    /// 前方一致 (大文字小文字を区別)
+    StartsWith {
+        field: String,
+        value: String,
+    },
+    /// 後方一致 (大文字小文字を区別)
+    EndsWith {
+        field: String,
+        value: String,
+    },
+    And {
+        conditions: Vec<QueryCondition>,
+    },
+    Or {
+        conditions: Vec<QueryCondition>,
+    },
+    Not {
+        condition: Box<QueryCondition>,
+    },
 }
 
 impl QueryCondition {
+    /// 比較条件のフィールド名 (グループ条件は空文字列、`fields()` を使う)
     pub fn field(&self) -> &str {
         match self {
             QueryCondition::Eq { field, .. } => field,
@@ -26,9 +170,213 @@ impl QueryCondition {
             QueryCondition::Lt { field, .. } => field,
             QueryCondition::Lte { field, .. } => field,
             QueryCondition::In { field, .. } => field,
+            QueryCondition::NotIn { field, .. } => field,
+            QueryCondition::Between { field, .. } => field,
+            QueryCondition::IsNull { field } => field,
+            QueryCondition::IsNotNull { field } => field,
             QueryCondition::Contains { field, .. } => field,
+            QueryCondition::IContains { field, .. } => field,
+            QueryCondition::StartsWith { field, .. } => field,
+            QueryCondition::EndsWith { field, .. } => field,
+            QueryCondition::And { .. } | QueryCondition::Or { .. } | QueryCondition::Not { .. } => {
+                ""
+            }
+        }
+    }
+
+    /// 条件が参照する全フィールド (グループは子条件を再帰的に列挙)
+    pub fn fields(&self) -> Vec<&str> {
+        match self {
+            QueryCondition::And { conditions } | QueryCondition::Or { conditions } => {
+                conditions.iter().flat_map(QueryCondition::fields).collect()
+            }
+            QueryCondition::Not { condition } => condition.fields(),
+            leaf => vec![leaf.field()],
+        }
+    }
+
+    pub fn is_group(&self) -> bool {
+        matches!(
+            self,
+            QueryCondition::And { .. } | QueryCondition::Or { .. } | QueryCondition::Not { .. }
+        )
+    }
+}
+
+/// 名前付きの集計値 (結果のカラム名は`alias`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryMetric {
+    alias: String,
+    agg_type: AggregationType,
+    field: String,
+}
+
+impl QueryMetric {
+    pub fn new(alias: String, agg_type: AggregationType, field: String) -> Self {
+        Self {
+            alias,
+            agg_type,
+            field,
+        }
+    }
+
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    pub fn agg_type(&self) -> &AggregationType {
+        &self.agg_type
+    }
+
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+}
+
+/// 日時フィールドを期間の開始日に切り詰めるグループ化
+///
+/// `timezone`がNoneの場合は値をそのまま (UTCまたは暦日として) 切り詰める。
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryTimeBucket {
+    field: String,
+    unit: TimeUnit,
+    timezone: Option<Tz>,
+    zero_fill: bool,
+}
+
+impl QueryTimeBucket {
+    pub fn new(field: String, unit: TimeUnit, timezone: Option<Tz>, zero_fill: bool) -> Self {
+        Self {
+            field,
+            unit,
+            timezone,
+            zero_fill,
+        }
+    }
+
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    pub fn timezone(&self) -> Option<Tz> {
+        self.timezone
+    }
+
+    /// 結果に空の期間を補完するか
+    pub fn zero_fill(&self) -> bool {
+        self.zero_fill
+    }
+}
+
+/// 集計条件 (複数の集計値と複数フィールドのグループ化)
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryAggregation {
+    metrics: Vec<QueryMetric>,
+    group_by: Vec<String>,
+    bucket: Option<QueryTimeBucket>,
+}
+
+impl QueryAggregation {
+    /// 単一の集計値 (カラム名は`{field}_{type}`、TypeScript実装と同一)
+    pub fn new(agg_type: AggregationType, field: String, group_by: Option<String>) -> Self {
+        let alias = format!("{}_{}", field, agg_type.as_str());
+        Self::with_metrics(
+            vec![QueryMetric::new(alias, agg_type, field)],
+            group_by.into_iter().collect(),
+        )
+    }
+
+    pub fn with_metrics(metrics: Vec<QueryMetric>, group_by: Vec<String>) -> Self {
+        Self {
+            metrics,
+            group_by,
+            bucket: None,
+        }
+    }
+
+    /// グループ化フィールドの1つを期間で切り詰める
+    pub fn with_bucket(mut self, bucket: QueryTimeBucket) -> Self {
+        self.bucket = Some(bucket);
+        self
+    }
+
+    pub fn metrics(&self) -> &[QueryMetric] {
+        &self.metrics
+    }
+
+    pub fn group_by(&self) -> &[String] {
+        &self.group_by
+    }
+
+    pub fn bucket(&self) -> Option<&QueryTimeBucket> {
+        self.bucket.as_ref()
+    }
+
+    /// 集計値のカラム名に一致するか
+    pub fn has_alias(&self, name: &str) -> bool {
+        self.metrics.iter().any(|metric| metric.alias == name)
+    }
+
+    /// 参照する元フィールド (集計対象とグループ化フィールド)
+    pub fn fields(&self) -> Vec<&str> {
+        self.group_by
+            .iter()
+            .map(String::as_str)
+            .chain(self.metrics.iter().map(QueryMetric::field))
+            .collect()
+    }
+}
+
+/// ソート条件
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuerySort {
+    field: String,
+    direction: SortDirection,
+    nulls: Option<NullsOrder>,
+}
+
+impl QuerySort {
+    pub fn new(field: String, direction: SortDirection) -> Self {
+        Self {
+            field,
+            direction,
+            nulls: None,
         }
     }
+
+    /// NULLの並び順を指定
+    pub fn with_nulls(mut self, nulls: NullsOrder) -> Self {
+        self.nulls = Some(nulls);
+        self
+    }
+
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    pub fn direction(&self) -> &SortDirection {
+        &self.direction
+    }
+
+    /// NULLの並び順 (未指定ならデータベースの既定)
+    pub fn nulls(&self) -> Option<NullsOrder> {
+        self.nulls
+    }
+}
+
+/// 取得するページ (ページサイズは`limit`)
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryPage {
+    /// 先頭から`offset`件を読み飛ばす
+    Offset(usize),
+    /// 未検証のカーソル (実行前に`CursorCodec`で`After`に解決する)
+    Cursor(String),
+    /// ソートキーの値がこの行より後の行 (検証済みカーソルの位置)
+    After(Vec<QueryValue>),
 }
 
 /// 変換後のクエリ構造
@@ -36,7 +384,11 @@ impl QueryCondition {
 pub struct ConvertedQuery {
     resource: String,
     conditions: Vec<QueryCondition>,
+    system_conditions: Vec<QueryCondition>,
+    aggregation: Option<QueryAggregation>,
+    sort: Vec<QuerySort>,
     limit: Option<usize>,
+    page: Option<QueryPage>,
 }
 
 impl ConvertedQuery {
@@ -44,7 +396,11 @@ impl ConvertedQuery {
         Self {
             resource,
             conditions: Vec::new(),
+            system_conditions: Vec::new(),
+            aggregation: None,
+            sort: Vec::new(),
             limit: None,
+            page: None,
         }
     }
 
@@ -52,21 +408,72 @@ impl ConvertedQuery {
         &self.resource
     }
 
+    /// ユーザー (AI) が指定した条件
     pub fn conditions(&self) -> &[QueryCondition] {
         &self.conditions
     }
 
+    /// RLSなどシステムが強制する条件
+    pub fn system_conditions(&self) -> &[QueryCondition] {
+        &self.system_conditions
+    }
+
+    /// WHERE句に使う全条件 (ユーザー条件 → システム条件の順、全てAND)
+    pub fn all_conditions(&self) -> impl Iterator<Item = &QueryCondition> {
+        self.conditions.iter().chain(&self.system_conditions)
+    }
+
+    pub fn aggregation(&self) -> Option<&QueryAggregation> {
+        self.aggregation.as_ref()
+    }
+
+    /// ソートキー (先頭から優先)
+    pub fn sort(&self) -> &[QuerySort] {
+        &self.sort
+    }
+
     pub fn limit(&self) -> Option<usize> {
         self.limit
     }
 
+    pub fn page(&self) -> Option<&QueryPage> {
+        self.page.as_ref()
+    }
+
+    /// カーソル (キーセット) ページングできるか (集計しないソート済みのクエリ)
+    pub fn supports_cursor(&self) -> bool {
+        !self.sort.is_empty() && self.aggregation.is_none()
+    }
+
     pub fn add_condition(&mut self, condition: QueryCondition) {
         self.conditions.push(condition);
     }
 
+    pub fn add_system_condition(&mut self, condition: QueryCondition) {
+        self.system_conditions.push(condition);
+    }
+
+    /// 条件を満たすユーザー条件のみ残す
+    pub fn retain_conditions(&mut self, f: impl FnMut(&QueryCondition) -> bool) {
+        self.conditions.retain(f);
+    }
+
+    pub fn set_aggregation(&mut self, aggregation: QueryAggregation) {
+        self.aggregation = Some(aggregation);
+    }
+
+    /// ソートキーを追加 (既存のキーが同値の行の並び順を決める)
+    pub fn add_sort(&mut self, sort: QuerySort) {
+        self.sort.push(sort);
+    }
+
     pub fn set_limit(&mut self, limit: usize) {
         self.limit = Some(limit);
     }
+
+    pub fn set_page(&mut self, page: QueryPage) {
+        self.page = Some(page);
+    }
 }
 
 /// 変換エラー
@@ -102,141 +509,697 @@ impl fmt::Display for ConversionError {
 impl std::error::Error for ConversionError {}
 
 /// DataSource → ORM Query Converter
-pub struct DataSourceConverter;
+///
+/// 相対日付のフィルタ値は`clock`の現在時刻を`timezone`の暦日として解決する (既定はシステム時刻・UTC)。
+pub struct DataSourceConverter {
+    registry: Option<ResourceRegistry>,
+    clock: Arc<dyn Clock>,
+    timezone: Tz,
+}
 
 impl DataSourceConverter {
     pub fn new() -> Self {
-        Self
+        Self {
+            registry: None,
+            clock: Arc::new(SystemClock),
+            timezone: Tz::UTC,
+        }
+    }
+
+    /// リソースレジストリを指定して生成
+    ///
+    /// レジストリに登録されていないリソース・フィールドを参照するDataSourceは拒否される。
+    pub fn with_registry(registry: ResourceRegistry) -> Self {
+        Self {
+            registry: Some(registry),
+            ..Self::new()
+        }
+    }
+
+    /// 相対日付の解決に使うClockを設定
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// 相対日付の解決に使うタイムゾーンを設定
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    pub fn registry(&self) -> Option<&ResourceRegistry> {
+        self.registry.as_ref()
+    }
+
+    pub fn timezone(&self) -> Tz {
+        self.timezone
     }
 
     /// DataSourceをConvertedQueryに変換
     pub fn convert(&self, ds: &DataSource) -> Result<ConvertedQuery, ConversionError> {
+        let resource = self.lookup_resource(&ds.resource)?;
         let mut query = ConvertedQuery::new(ds.resource.clone());
 
         // フィルタ変換
         if let Some(filters) = &ds.filters {
-            for filter in filters {
-                let condition = self.convert_filter(filter)?;
+            for node in filters {
+                let condition = self.convert_filter_node(node, resource)?;
                 query.add_condition(condition);
             }
         }
 
+        // 集計設定 (`aggregation`は単一metricの省略形)
+        if ds.is_aggregated() {
+            query.set_aggregation(self.convert_aggregation(ds, resource)?);
+        }
+
+        // ソート設定 (リストの順にキーを適用)
+        for (_, key) in ds.sort.iter().flat_map(SortKeys::keys) {
+            let mut sort = QuerySort::new(key.field.clone(), key.direction.clone());
+            if let Some(nulls) = key.nulls {
+                sort = sort.with_nulls(nulls);
+            }
+            query.add_sort(sort);
+        }
+
         // Limit設定 (u32 → usize)
         if let Some(limit) = ds.limit {
             query.set_limit(limit as usize);
         }
 
+        // ページ設定 (ページサイズはlimit)
+        if let Some(page) = &ds.page {
+            query.set_page(convert_page(page, &query)?);
+        }
+
+        if let Some(resource) = resource {
+            self.check_fields(resource, &query)?;
+        }
+        check_sort_fields(&query)?;
+
+        // 主キーを宣言したリソースのページングできるクエリは、主キーを最終ソートキーにして
+        // 同じ値の行の順序を固定する
+        if query.limit().is_some() && query.supports_cursor() {
+            if let Some(key) = resource.and_then(ResourceDefinition::primary_key) {
+                if !query.sort().iter().any(|sort| sort.field() == key) {
+                    query.add_sort(QuerySort::new(key.to_string(), SortDirection::Asc));
+                }
+            }
+        }
+
         Ok(query)
     }
 
-    /// 個別フィルタ変換
-    fn convert_filter(&self, filter: &Filter) -> Result<QueryCondition, ConversionError> {
-        match &filter.op {
-            FilterOperator::Eq => {
-                let value = self.extract_string_value(&filter.value)?;
-                Ok(QueryCondition::Eq {
-                    field: filter.field.clone(),
-                    value,
-                })
+    /// DataSourceの集計設定をQueryAggregationに変換
+    ///
+    /// `aggregation`と`metrics`/`group_by`の併用、空の`metrics`、カラム名の重複はエラー
+    fn convert_aggregation(
+        &self,
+        ds: &DataSource,
+        resource: Option<&ResourceDefinition>,
+    ) -> Result<QueryAggregation, ConversionError> {
+        if ds.aggregation.is_some() && (ds.metrics.is_some() || ds.group_by.is_some()) {
+            return Err(ConversionError::new(
+                "INVALID_AGGREGATION",
+                "aggregation cannot be combined with metrics or group_by",
+            ));
+        }
+        let metrics = ds.output_metrics();
+        if metrics.is_empty() {
+            return Err(ConversionError::new(
+                "INVALID_AGGREGATION",
+                "group_by requires at least one metric",
+            ));
+        }
+
+        let group_by: Vec<String> = ds.group_by_fields().into_iter().map(String::from).collect();
+        let mut columns: Vec<&str> = group_by.iter().map(String::as_str).collect();
+        for metric in &metrics {
+            if columns.contains(&metric.alias.as_str()) {
+                return Err(ConversionError::new(
+                    "DUPLICATE_METRIC_ALIAS",
+                    format!("Duplicate metric alias: {}", metric.alias),
+                ));
             }
-            FilterOperator::Neq => {
-                let value = self.extract_string_value(&filter.value)?;
-                Ok(QueryCondition::Neq {
-                    field: filter.field.clone(),
-                    value,
-                })
+            columns.push(&metric.alias);
+            check_metric_type(metric, resource)?;
+        }
+
+        let metrics = metrics
+            .into_iter()
+            .map(|metric| QueryMetric::new(metric.alias, metric.agg_type, metric.field))
+            .collect();
+        let aggregation = QueryAggregation::with_metrics(metrics, group_by);
+
+        match &ds.aggregation {
+            Some(Aggregation {
+                by,
+                bucket: Some(bucket),
+                ..
+            }) => Ok(aggregation.with_bucket(self.convert_bucket(
+                by.as_deref(),
+                bucket,
+                resource,
+            )?)),
+            _ => Ok(aggregation),
+        }
+    }
+
+    /// 期間によるグループ化を変換
+    ///
+    /// 日時フィールドは`timezone` (未指定時はConverterのタイムゾーン) の暦で切り詰め、
+    /// 日付フィールドは暦日そのものを切り詰める。
+    fn convert_bucket(
+        &self,
+        by: Option<&str>,
+        bucket: &TimeBucket,
+        resource: Option<&ResourceDefinition>,
+    ) -> Result<QueryTimeBucket, ConversionError> {
+        let Some(field) = by else {
+            return Err(ConversionError::new(
+                "INVALID_AGGREGATION",
+                "bucket requires a group by field (by)",
+            ));
+        };
+        let timezone = match &bucket.timezone {
+            Some(name) => name.parse::<Tz>().map_err(|_| {
+                ConversionError::new("INVALID_TIMEZONE", format!("Unknown timezone: {}", name))
+            })?,
+            None => self.timezone,
+        };
+
+        let is_datetime = match resource.and_then(|resource| resource.field_type(field)) {
+            Some(FieldType::DateTime) | None => true,
+            Some(FieldType::Date) => false,
+            Some(field_type) => {
+                return Err(ConversionError::new(
+                    "INVALID_TIME_BUCKET",
+                    format!(
+                        "Time bucket requires a date or datetime field, not {}: {}",
+                        field_type.as_str(),
+                        field
+                    ),
+                ))
             }
-            FilterOperator::Gt => {
-                let value = self.extract_number_value(&filter.value)?;
-                Ok(QueryCondition::Gt {
-                    field: filter.field.clone(),
-                    value,
-                })
+        };
+        // UTCの日時は変換不要
+        let timezone = Some(timezone).filter(|timezone| is_datetime && *timezone != Tz::UTC);
+
+        Ok(QueryTimeBucket::new(
+            field.to_string(),
+            bucket.unit,
+            timezone,
+            bucket.zero_fill,
+        ))
+    }
+
+    /// レジストリからリソース定義を取得 (レジストリ未設定時はNone)
+    fn lookup_resource(&self, name: &str) -> Result<Option<&ResourceDefinition>, ConversionError> {
+        let Some(registry) = &self.registry else {
+            return Ok(None);
+        };
+
+        registry.resource(name).map(Some).ok_or_else(|| {
+            ConversionError::new("UNKNOWN_RESOURCE", format!("Unknown resource: {}", name))
+        })
+    }
+
+    /// クエリが参照するフィールドがすべて登録済みか確認
+    fn check_fields(
+        &self,
+        resource: &ResourceDefinition,
+        query: &ConvertedQuery,
+    ) -> Result<(), ConversionError> {
+        let require = |field: &str| {
+            if resource.has_field(field) {
+                Ok(())
+            } else {
+                Err(unknown_field(resource, field))
             }
-            FilterOperator::Gte => {
-                let value = self.extract_number_value(&filter.value)?;
-                Ok(QueryCondition::Gte {
-                    field: filter.field.clone(),
-                    value,
-                })
+        };
+
+        for field in query.conditions().iter().flat_map(QueryCondition::fields) {
+            require(field)?;
+        }
+
+        if let Some(aggregation) = query.aggregation() {
+            for field in aggregation.fields() {
+                require(field)?;
             }
-            FilterOperator::Lt => {
-                let value = self.extract_number_value(&filter.value)?;
-                Ok(QueryCondition::Lt {
-                    field: filter.field.clone(),
-                    value,
-                })
+        }
+
+        // 集計時のソートキーは集計結果のカラムとしてcheck_sort_fieldsで確認
+        if query.aggregation().is_none() {
+            for sort in query.sort() {
+                require(sort.field())?;
             }
-            FilterOperator::Lte => {
-                let value = self.extract_number_value(&filter.value)?;
-                Ok(QueryCondition::Lte {
-                    field: filter.field.clone(),
-                    value,
-                })
+        }
+
+        Ok(())
+    }
+
+    /// フィルタツリーを条件ツリーに変換
+    fn convert_filter_node(
+        &self,
+        node: &FilterNode,
+        resource: Option<&ResourceDefinition>,
+    ) -> Result<QueryCondition, ConversionError> {
+        let convert_all = |children: &[FilterNode]| {
+            children
+                .iter()
+                .map(|child| self.convert_filter_node(child, resource))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        match node {
+            FilterNode::And { and } => Ok(QueryCondition::And {
+                conditions: convert_all(and)?,
+            }),
+            FilterNode::Or { or } => Ok(QueryCondition::Or {
+                conditions: convert_all(or)?,
+            }),
+            FilterNode::Not { not } => Ok(QueryCondition::Not {
+                condition: Box::new(self.convert_filter_node(not, resource)?),
+            }),
+            FilterNode::Filter(filter) => self.convert_filter(filter, resource),
+        }
+    }
+
+    /// 個別フィルタ変換
+    ///
+    /// レジストリがある場合は宣言済みのフィールド型に合わせて値を型付けする。
+    fn convert_filter(
+        &self,
+        filter: &Filter,
+        resource: Option<&ResourceDefinition>,
+    ) -> Result<QueryCondition, ConversionError> {
+        let field_type = match resource {
+            Some(resource) => Some(
+                resource
+                    .field_type(&filter.field)
+                    .ok_or_else(|| unknown_field(resource, &filter.field))?,
+            ),
+            None => None,
+        };
+        let field = filter.field.clone();
+        let value = &filter.value;
+
+        match &filter.op {
+            FilterOperator::Eq => Ok(QueryCondition::Eq {
+                value: self.extract_scalar_value(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::Neq => Ok(QueryCondition::Neq {
+                value: self.extract_scalar_value(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::Gt => Ok(QueryCondition::Gt {
+                value: self.extract_comparable_value(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::Gte => Ok(QueryCondition::Gte {
+                value: self.extract_comparable_value(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::Lt => Ok(QueryCondition::Lt {
+                value: self.extract_comparable_value(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::Lte => Ok(QueryCondition::Lte {
+                value: self.extract_comparable_value(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::In => Ok(QueryCondition::In {
+                values: self.extract_array_values(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::NotIn => Ok(QueryCondition::NotIn {
+                values: self.extract_array_values(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::Between => {
+                let (low, high) = self.extract_range_values(&field, value, field_type)?;
+                Ok(QueryCondition::Between { field, low, high })
             }
-            FilterOperator::In => {
-                let values = self.extract_array_values(&filter.value)?;
-                Ok(QueryCondition::In {
-                    field: filter.field.clone(),
-                    values,
-                })
+            FilterOperator::IsNull => {
+                self.expect_no_value(&filter.op, value)?;
+                Ok(QueryCondition::IsNull { field })
             }
-            FilterOperator::Contains => {
-                let value = self.extract_string_value(&filter.value)?;
-                Ok(QueryCondition::Contains {
-                    field: filter.field.clone(),
-                    value,
-                })
+            FilterOperator::IsNotNull => {
+                self.expect_no_value(&filter.op, value)?;
+                Ok(QueryCondition::IsNotNull { field })
             }
+            FilterOperator::Contains => Ok(QueryCondition::Contains {
+                value: self.extract_string_value(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::IContains => Ok(QueryCondition::IContains {
+                value: self.extract_string_value(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::StartsWith => Ok(QueryCondition::StartsWith {
+                value: self.extract_string_value(&field, value, field_type)?,
+                field,
+            }),
+            FilterOperator::EndsWith => Ok(QueryCondition::EndsWith {
+                value: self.extract_string_value(&field, value, field_type)?,
+                field,
+            }),
         }
     }
 
-    /// FilterValueから文字列を抽出
-    fn extract_string_value(&self, value: &FilterValue) -> Result<String, ConversionError> {
-        match value {
-            FilterValue::String(s) => Ok(s.clone()),
-            FilterValue::Number(n) => Ok(n.to_string()),
-            FilterValue::Boolean(b) => Ok(b.to_string()),
-            FilterValue::Array(_) => Err(ConversionError::new(
+    /// 値を取らない演算子 (`is_null` / `is_not_null`) に値が指定されていないことを確認
+    fn expect_no_value(
+        &self,
+        op: &FilterOperator,
+        value: &FilterValue,
+    ) -> Result<(), ConversionError> {
+        if value.is_null() {
+            Ok(())
+        } else {
+            Err(ConversionError::new(
+                "INVALID_FILTER_VALUE_TYPE",
+                format!("Operator '{}' does not take a value", op.as_str()),
+            ))
+        }
+    }
+
+    /// FilterValueからスカラー値を抽出
+    fn extract_scalar_value(
+        &self,
+        field: &str,
+        value: &FilterValue,
+        field_type: Option<FieldType>,
+    ) -> Result<QueryValue, ConversionError> {
+        match (value, field_type) {
+            (FilterValue::Array(_), _) => Err(ConversionError::new(
+                "INVALID_FILTER_VALUE_TYPE",
+                "Expected scalar value, got array",
+            )),
+            (FilterValue::Null, _) => Err(missing_value()),
+            (FilterValue::Relative(relative), _) => {
+                self.resolve_relative(field, relative, field_type)
+            }
+            (_, Some(field_type)) => coerce_value(field, value, field_type),
+            (FilterValue::String(s), None) => Ok(QueryValue::String(s.clone())),
+            (FilterValue::Number(n), None) => Ok(QueryValue::Float(*n)),
+            (FilterValue::Boolean(b), None) => Ok(QueryValue::Boolean(*b)),
+        }
+    }
+
+    /// FilterValueから大小比較可能な値を抽出
+    ///
+    /// 型が宣言されていないフィールドは数値のみ比較可能。
+    fn extract_comparable_value(
+        &self,
+        field: &str,
+        value: &FilterValue,
+        field_type: Option<FieldType>,
+    ) -> Result<QueryValue, ConversionError> {
+        match (value, field_type) {
+            (_, Some(FieldType::Boolean)) => Err(type_mismatch(
+                field,
+                "boolean fields do not support ordering comparisons",
+            )),
+            (FilterValue::Array(_), _) => Err(ConversionError::new(
                 "INVALID_FILTER_VALUE_TYPE",
                 "Expected scalar value, got array",
             )),
+            (FilterValue::Null, _) => Err(missing_value()),
+            (FilterValue::Relative(relative), _) => {
+                self.resolve_relative(field, relative, field_type)
+            }
+            (_, Some(field_type)) => coerce_value(field, value, field_type),
+            (FilterValue::Number(n), None) => Ok(QueryValue::Float(*n)),
+            (_, None) => Err(ConversionError::new(
+                "INVALID_FILTER_VALUE_TYPE",
+                "Expected number value",
+            )),
         }
     }
 
-    /// FilterValueから数値を抽出
-    fn extract_number_value(&self, value: &FilterValue) -> Result<f64, ConversionError> {
+    /// FilterValueから範囲の両端を抽出 (`between`用)
+    fn extract_range_values(
+        &self,
+        field: &str,
+        value: &FilterValue,
+        field_type: Option<FieldType>,
+    ) -> Result<(QueryValue, QueryValue), ConversionError> {
         match value {
-            FilterValue::Number(n) => Ok(*n),
+            FilterValue::Array(arr) if arr.len() == 2 => {
+                let mut bounds = arr.iter().map(|item| {
+                    self.extract_comparable_value(field, &scalar_to_value(item), field_type)
+                });
+                let low = bounds.next().unwrap()?;
+                let high = bounds.next().unwrap()?;
+                Ok((low, high))
+            }
             _ => Err(ConversionError::new(
                 "INVALID_FILTER_VALUE_TYPE",
-                "Expected number value",
+                "Expected [low, high] array for 'between' operator",
+            )),
+        }
+    }
+
+    /// FilterValueから文字列を抽出 (部分一致・前方一致・後方一致用)
+    fn extract_string_value(
+        &self,
+        field: &str,
+        value: &FilterValue,
+        field_type: Option<FieldType>,
+    ) -> Result<String, ConversionError> {
+        match (value, field_type) {
+            (FilterValue::Array(_), _) => Err(ConversionError::new(
+                "INVALID_FILTER_VALUE_TYPE",
+                "Expected scalar value, got array",
+            )),
+            (FilterValue::Null, _) => Err(missing_value()),
+            (FilterValue::String(s), None | Some(FieldType::String)) => Ok(s.clone()),
+            (_, Some(_)) => Err(type_mismatch(
+                field,
+                "pattern match requires a string field and a string value",
+            )),
+            (FilterValue::Number(n), None) => Ok(n.to_string()),
+            (FilterValue::Boolean(b), None) => Ok(b.to_string()),
+            (FilterValue::Relative(_), None) => Err(type_mismatch(
+                field,
+                "pattern match does not accept a relative date",
+            )),
+        }
+    }
+
+    /// 相対日付を現在時刻から具体的な値に解決
+    ///
+    /// DateTimeフィールドはタイムゾーンでの0時 (UTC)、それ以外は暦日になる。
+    fn resolve_relative(
+        &self,
+        field: &str,
+        relative: &RelativeDate,
+        field_type: Option<FieldType>,
+    ) -> Result<QueryValue, ConversionError> {
+        let date = resolve_relative_date(relative, self.clock.now(), self.timezone);
+        match field_type {
+            Some(FieldType::DateTime) => {
+                Ok(QueryValue::DateTime(start_of_day(date, self.timezone)))
+            }
+            Some(FieldType::Date) | None => Ok(QueryValue::Date(date)),
+            Some(field_type) => Err(type_mismatch(
+                field,
+                &format!(
+                    "relative date '{}' requires a date or datetime field, not {}",
+                    relative.as_str(),
+                    field_type.as_str()
+                ),
             )),
         }
     }
 
     /// FilterValueから配列を抽出
-    fn extract_array_values(&self, value: &FilterValue) -> Result<Vec<String>, ConversionError> {
+    fn extract_array_values(
+        &self,
+        field: &str,
+        value: &FilterValue,
+        field_type: Option<FieldType>,
+    ) -> Result<Vec<QueryValue>, ConversionError> {
         match value {
-            FilterValue::Array(arr) => {
-                let mut result = Vec::new();
-                for item in arr {
-                    let string_value = match item {
-                        FilterValueScalar::String(s) => s.clone(),
-                        FilterValueScalar::Number(n) => n.to_string(),
-                    };
-                    result.push(string_value);
-                }
-                Ok(result)
-            }
+            FilterValue::Array(arr) => arr
+                .iter()
+                .map(|item| self.extract_scalar_value(field, &scalar_to_value(item), field_type))
+                .collect(),
             _ => Err(ConversionError::new(
                 "INVALID_FILTER_VALUE_TYPE",
-                "Expected array value for 'in' operator",
+                "Expected array value for 'in' / 'not_in' operator",
             )),
         }
     }
 }
 
+/// 宣言済みのフィールド型に合わせてスカラー値を変換
+fn coerce_value(
+    field: &str,
+    value: &FilterValue,
+    field_type: FieldType,
+) -> Result<QueryValue, ConversionError> {
+    let coerced = match (field_type, value) {
+        (FieldType::String, FilterValue::String(s)) => Some(QueryValue::String(s.clone())),
+        (FieldType::Integer, FilterValue::Number(n)) => {
+            // 小数部があるか、i64の範囲外の値は整数として扱わない
+            if n.fract() == 0.0 && *n >= i64::MIN as f64 && *n <= i64::MAX as f64 {
+                Some(QueryValue::Integer(*n as i64))
+            } else {
+                None
+            }
+        }
+        (FieldType::Float, FilterValue::Number(n)) => Some(QueryValue::Float(*n)),
+        (FieldType::Boolean, FilterValue::Boolean(b)) => Some(QueryValue::Boolean(*b)),
+        (FieldType::Date, FilterValue::String(s)) => parse_date(s).map(QueryValue::Date),
+        (FieldType::DateTime, FilterValue::String(s)) => {
+            parse_datetime(s).map(QueryValue::DateTime)
+        }
+        _ => None,
+    };
+
+    coerced.ok_or_else(|| {
+        type_mismatch(
+            field,
+            &format!("value {:?} is not a valid {}", value, field_type.as_str()),
+        )
+    })
+}
+
+/// `YYYY-MM-DD`形式の日付をパース
+pub(crate) fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+/// 日時をパース (RFC 3339、タイムゾーンなしの日時はUTC、日付のみは0時UTC)
+pub(crate) fn parse_datetime(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(datetime.and_utc());
+        }
+    }
+    parse_date(value)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+/// 配列要素をスカラーのFilterValueに変換
+fn scalar_to_value(item: &FilterValueScalar) -> FilterValue {
+    match item {
+        FilterValueScalar::String(s) => FilterValue::String(s.clone()),
+        FilterValueScalar::Number(n) => FilterValue::Number(*n),
+    }
+}
+
+/// ページ指定を変換
+///
+/// カーソルは前ページ最終行のソートキー値から続けるため、集計しないソート済みのクエリのみ対象。
+/// カーソルはここでは検証せず、実行時に`CursorCodec::resolve`で検証する。
+fn convert_page(page: &Page, query: &ConvertedQuery) -> Result<QueryPage, ConversionError> {
+    if query.limit().is_none() {
+        return Err(ConversionError::new(
+            "INVALID_PAGINATION",
+            "page requires a limit (page size)",
+        ));
+    }
+    match page {
+        Page::Offset(offset) => Ok(QueryPage::Offset(*offset as usize)),
+        Page::After(cursor) => {
+            if !query.supports_cursor() {
+                return Err(ConversionError::new(
+                    "INVALID_PAGINATION",
+                    "cursor pagination requires a sort on a non-aggregated data source",
+                ));
+            }
+            Ok(QueryPage::Cursor(cursor.clone()))
+        }
+    }
+}
+
+/// 集計結果のソートキーがグループ化フィールドか集計値の別名か確認
+fn check_sort_fields(query: &ConvertedQuery) -> Result<(), ConversionError> {
+    let Some(aggregation) = query.aggregation() else {
+        return Ok(());
+    };
+    for sort in query.sort() {
+        let in_result = aggregation
+            .group_by()
+            .iter()
+            .any(|field| field == sort.field())
+            || aggregation.has_alias(sort.field());
+        if !in_result {
+            return Err(ConversionError::new(
+                "INVALID_SORT_FIELD",
+                format!(
+                    "Sort field '{}' is not a group_by field or metric alias",
+                    sort.field()
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// 集計関数と対象フィールドの型を検証
+///
+/// パーセンタイルは0〜100の範囲のみ。中央値・パーセンタイルは数値フィールド
+/// (レジストリで型が分かる場合) のみ対象にできる。
+fn check_metric_type(
+    metric: &Metric,
+    resource: Option<&ResourceDefinition>,
+) -> Result<(), ConversionError> {
+    if !metric.agg_type.is_valid() {
+        return Err(ConversionError::new(
+            "INVALID_PERCENTILE",
+            format!(
+                "Percentile of metric '{}' must be between 0 and 100",
+                metric.alias
+            ),
+        ));
+    }
+    if metric.agg_type.percentile_fraction().is_none() {
+        return Ok(());
+    }
+    match resource.and_then(|resource| resource.field_type(&metric.field)) {
+        Some(FieldType::Integer | FieldType::Float) | None => Ok(()),
+        Some(field_type) => Err(ConversionError::new(
+            "AGGREGATION_TYPE_MISMATCH",
+            format!(
+                "{} requires a numeric field, but '{}' is {}",
+                metric.agg_type.as_str(),
+                metric.field,
+                field_type.as_str()
+            ),
+        )),
+    }
+}
+
+fn missing_value() -> ConversionError {
+    ConversionError::new(
+        "INVALID_FILTER_VALUE_TYPE",
+        "Expected scalar value, got null",
+    )
+}
+
+fn unknown_field(resource: &ResourceDefinition, field: &str) -> ConversionError {
+    ConversionError::new(
+        "UNKNOWN_FIELD",
+        format!("Unknown field: {}.{}", resource.name(), field),
+    )
+}
+
+fn type_mismatch(field: &str, reason: &str) -> ConversionError {
+    ConversionError::new(
+        "FILTER_TYPE_MISMATCH",
+        format!("Filter on field '{}' has wrong type: {}", field, reason),
+    )
+}
+
 impl Default for DataSourceConverter {
     fn default() -> Self {
         Self::new()
@@ -251,19 +1214,78 @@ mod tests {
     fn test_query_condition_field() {
         let cond = QueryCondition::Eq {
             field: "test".to_string(),
-            value: "value".to_string(),
+            value: "value".into(),
         };
         assert_eq!(cond.field(), "test");
     }
 
+    #[test]
+    fn test_query_condition_group_fields() {
+        let cond = QueryCondition::Or {
+            conditions: vec![
+                QueryCondition::Eq {
+                    field: "a".to_string(),
+                    value: "x".into(),
+                },
+                QueryCondition::Not {
+                    condition: Box::new(QueryCondition::Gt {
+                        field: "b".to_string(),
+                        value: 1i64.into(),
+                    }),
+                },
+            ],
+        };
+        assert!(cond.is_group());
+        assert_eq!(cond.field(), "");
+        assert_eq!(cond.fields(), vec!["a", "b"]);
+    }
+
     #[test]
     fn test_converted_query_new() {
         let query = ConvertedQuery::new("users".to_string());
         assert_eq!(query.resource(), "users");
         assert!(query.conditions().is_empty());
+        assert!(query.aggregation().is_none());
+        assert!(query.sort().is_empty());
         assert_eq!(query.limit(), None);
     }
 
+    #[test]
+    fn test_query_aggregation_alias() {
+        let agg = QueryAggregation::new(
+            AggregationType::Sum,
+            "amount".to_string(),
+            Some("month".to_string()),
+        );
+        assert_eq!(agg.metrics()[0].alias(), "amount_sum");
+        assert_eq!(agg.group_by(), ["month"]);
+        assert!(agg.has_alias("amount_sum"));
+        assert_eq!(agg.fields(), vec!["month", "amount"]);
+    }
+
+    #[test]
+    fn test_query_value_from_u64() {
+        assert_eq!(QueryValue::from(42u64), QueryValue::Integer(42));
+        assert_eq!(
+            QueryValue::from(u64::MAX),
+            QueryValue::String(u64::MAX.to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_datetime_formats() {
+        let expected = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap()
+            .and_utc();
+        assert_eq!(parse_datetime("2024-01-01T09:30:00Z"), Some(expected));
+        assert_eq!(parse_datetime("2024-01-01T18:30:00+09:00"), Some(expected));
+        assert_eq!(parse_datetime("2024-01-01 09:30:00"), Some(expected));
+        assert!(parse_datetime("2024-01-01").is_some());
+        assert!(parse_datetime("yesterday").is_none());
+    }
+
     #[test]
     fn test_conversion_error_display() {
         let err = ConversionError::new("TEST_CODE", "Test message");
@@ -0,0 +1,383 @@
+// FR-08: SQL Dialect Abstraction
+
+use liquid_protocol::{NullsOrder, SortDirection, TimeUnit};
+
+/// LIKEパターンのエスケープ文字
+const LIKE_ESCAPE: char = '\\';
+
+/// 文字列パターン一致の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextMatch {
+    /// 部分一致
+    Contains,
+    /// 前方一致
+    StartsWith,
+    /// 後方一致
+    EndsWith,
+}
+
+/// SQLダイアレクト
+///
+/// `SqlBuilder`が出力するSQLのうち、DBエンジンごとに異なる部分を表す。
+/// 識別子は`SqlBuilder`側で検証済みのものだけが渡される。
+pub trait Dialect: Send + Sync {
+    /// ダイアレクト名
+    fn name(&self) -> &'static str;
+
+    /// n番目 (1始まり) のバインドパラメータのプレースホルダー
+    fn placeholder(&self, index: usize) -> String;
+
+    /// 識別子のクォート
+    fn quote_identifier(&self, identifier: &str) -> String;
+
+    /// LIKE句のESCAPE指定 (エスケープ文字はバックスラッシュ)
+    fn like_escape(&self) -> &'static str {
+        "ESCAPE '\\'"
+    }
+
+    /// 大文字小文字を区別するパターン一致 (`placeholder`には`case_sensitive_pattern`の値をバインドする)
+    fn case_sensitive_like(&self, column: &str, placeholder: &str) -> String {
+        format!("{} LIKE {} {}", column, placeholder, self.like_escape())
+    }
+
+    /// 大文字小文字を区別するパターン一致にバインドするパターン
+    fn case_sensitive_pattern(&self, value: &str, kind: TextMatch) -> String {
+        like_pattern(value, kind)
+    }
+
+    /// 大文字小文字を区別しないパターン一致 (`placeholder`には`like_pattern`の値をバインドする)
+    fn case_insensitive_like(&self, column: &str, placeholder: &str) -> String {
+        format!(
+            "LOWER({}) LIKE LOWER({}) {}",
+            column,
+            placeholder,
+            self.like_escape()
+        )
+    }
+
+    /// 日時カラムを期間の開始日 (`YYYY-MM-DD`形式の文字列) に切り詰める式
+    ///
+    /// `timezone`はUTCの日時をそのタイムゾーンの暦で切り詰める場合に指定される
+    /// (chrono-tzの正規名のみ)。対応できない場合はNone。
+    ///
+    /// PostgreSQLの`AT TIME ZONE`は`timestamptz`カラムを前提とする
+    /// (`timestamp`カラムに適用すると逆方向に変換される)。
+    fn time_bucket(&self, column: &str, unit: TimeUnit, timezone: Option<&str>) -> Option<String>;
+
+    /// 連続分布のパーセンタイル (`fraction`は0.0〜1.0) を求める集計式
+    ///
+    /// 中央値は`fraction = 0.5`。対応できない場合はNone。
+    fn percentile(&self, _column: &str, _fraction: f64) -> Option<String> {
+        None
+    }
+
+    /// ORDER BYのキー (`direction`は`ASC`/`DESC`)
+    ///
+    /// `nulls`指定時はNULLを先頭・末尾に並べる。
+    fn order_key(&self, column: &str, direction: &str, nulls: Option<NullsOrder>) -> String {
+        match nulls {
+            Some(NullsOrder::First) => format!("{} {} NULLS FIRST", column, direction),
+            Some(NullsOrder::Last) => format!("{} {} NULLS LAST", column, direction),
+            None => format!("{} {}", column, direction),
+        }
+    }
+
+    /// `nulls`未指定時のNULLの位置 (キーセットページングの条件に使う)
+    ///
+    /// 既定はNULLを最小値として扱う (SQLite・MySQL)。
+    fn default_nulls(&self, direction: &SortDirection) -> NullsOrder {
+        match direction {
+            SortDirection::Asc => NullsOrder::First,
+            SortDirection::Desc => NullsOrder::Last,
+        }
+    }
+
+    /// LIMIT / OFFSET句 (どちらも指定がなければNone)
+    fn limit_offset(&self, limit: Option<usize>, offset: Option<usize>) -> Option<String> {
+        standard_limit_offset(limit, offset)
+    }
+}
+
+/// SQLite
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SqliteDialect;
+
+impl Dialect for SqliteDialect {
+    fn name(&self) -> &'static str {
+        "sqlite"
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    fn quote_identifier(&self, identifier: &str) -> String {
+        format!("\"{}\"", identifier)
+    }
+
+    // SQLiteのLIKEはASCIIの大文字小文字を区別しないため、GLOBを使う
+    fn case_sensitive_like(&self, column: &str, placeholder: &str) -> String {
+        format!("{} GLOB {}", column, placeholder)
+    }
+
+    fn case_sensitive_pattern(&self, value: &str, kind: TextMatch) -> String {
+        let mut escaped = String::with_capacity(value.len());
+        for c in value.chars() {
+            match c {
+                '*' | '?' | '[' => {
+                    escaped.push('[');
+                    escaped.push(c);
+                    escaped.push(']');
+                }
+                _ => escaped.push(c),
+            }
+        }
+        wrap_pattern(&escaped, kind, '*')
+    }
+
+    fn time_bucket(&self, column: &str, unit: TimeUnit, timezone: Option<&str>) -> Option<String> {
+        // SQLiteはタイムゾーンデータベースを持たないため、UTC以外の暦では切り詰められない
+        if timezone.is_some() {
+            return None;
+        }
+        let c = column;
+        Some(match unit {
+            TimeUnit::Day => format!("date({})", c),
+            // %wは日曜始まり (0-6) のため、月曜からの日数に変換して戻す
+            TimeUnit::Week => format!(
+                "date({c}, '-' || ((CAST(strftime('%w', {c}) AS INTEGER) + 6) % 7) || ' days')"
+            ),
+            TimeUnit::Month => format!("strftime('%Y-%m-01', {})", c),
+            TimeUnit::Quarter => format!(
+                "printf('%s-%02d-01', strftime('%Y', {c}), \
+                 (CAST(strftime('%m', {c}) AS INTEGER) - 1) / 3 * 3 + 1)"
+            ),
+            TimeUnit::Year => format!("strftime('%Y-01-01', {})", c),
+        })
+    }
+
+    // SQLiteに組み込みのパーセンタイル関数はないため、SqliteExecutorが登録する
+    // 集計関数liquid_percentileで代替する (percentile_contと同じ線形補間)
+    fn percentile(&self, column: &str, fraction: f64) -> Option<String> {
+        Some(format!("liquid_percentile({}, {:?})", column, fraction))
+    }
+
+    fn limit_offset(&self, limit: Option<usize>, offset: Option<usize>) -> Option<String> {
+        match (limit, offset) {
+            // SQLiteはLIMITなしのOFFSETを受け付けないため、-1 (無制限) を指定する
+            (None, Some(offset)) => Some(format!("LIMIT -1 OFFSET {}", offset)),
+            _ => standard_limit_offset(limit, offset),
+        }
+    }
+}
+
+/// PostgreSQL
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PostgresDialect;
+
+impl Dialect for PostgresDialect {
+    fn name(&self) -> &'static str {
+        "postgres"
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("${}", index)
+    }
+
+    fn quote_identifier(&self, identifier: &str) -> String {
+        format!("\"{}\"", identifier)
+    }
+
+    fn case_insensitive_like(&self, column: &str, placeholder: &str) -> String {
+        format!("{} ILIKE {} {}", column, placeholder, self.like_escape())
+    }
+
+    // timestamptzカラムを前提に、AT TIME ZONEでローカル時刻に変換してから切り詰める
+    fn time_bucket(&self, column: &str, unit: TimeUnit, timezone: Option<&str>) -> Option<String> {
+        let local = match timezone {
+            Some(timezone) => format!("{} AT TIME ZONE '{}'", column, timezone),
+            None => column.to_string(),
+        };
+        Some(format!(
+            "to_char(date_trunc('{}', {}), 'YYYY-MM-DD')",
+            unit.as_str(),
+            local
+        ))
+    }
+
+    fn percentile(&self, column: &str, fraction: f64) -> Option<String> {
+        Some(format!(
+            "percentile_cont({:?}) WITHIN GROUP (ORDER BY {})",
+            fraction, column
+        ))
+    }
+
+    // NULLを最大値として扱う
+    fn default_nulls(&self, direction: &SortDirection) -> NullsOrder {
+        match direction {
+            SortDirection::Asc => NullsOrder::Last,
+            SortDirection::Desc => NullsOrder::First,
+        }
+    }
+}
+
+/// MySQL
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MySqlDialect;
+
+impl Dialect for MySqlDialect {
+    fn name(&self) -> &'static str {
+        "mysql"
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    fn quote_identifier(&self, identifier: &str) -> String {
+        format!("`{}`", identifier)
+    }
+
+    fn like_escape(&self) -> &'static str {
+        // MySQLの文字列リテラルではバックスラッシュ自体をエスケープする必要がある
+        "ESCAPE '\\\\'"
+    }
+
+    // 既定の照合順序は大文字小文字を区別しないため、バイナリとして比較する
+    fn case_sensitive_like(&self, column: &str, placeholder: &str) -> String {
+        format!(
+            "CAST({} AS BINARY) LIKE {} {}",
+            column,
+            placeholder,
+            self.like_escape()
+        )
+    }
+
+    fn time_bucket(&self, column: &str, unit: TimeUnit, timezone: Option<&str>) -> Option<String> {
+        // CONVERT_TZは名前付きタイムゾーンのテーブルが未ロードだとNULLを返すため、
+        // UTC以外の暦では切り詰めない
+        if timezone.is_some() {
+            return None;
+        }
+        let l = column;
+        Some(match unit {
+            TimeUnit::Day => format!("DATE_FORMAT({}, '%Y-%m-%d')", l),
+            TimeUnit::Week => {
+                format!("DATE_FORMAT(DATE_SUB({l}, INTERVAL WEEKDAY({l}) DAY), '%Y-%m-%d')")
+            }
+            TimeUnit::Month => format!("DATE_FORMAT({}, '%Y-%m-01')", l),
+            TimeUnit::Quarter => {
+                format!("CONCAT(YEAR({l}), '-', LPAD(QUARTER({l}) * 3 - 2, 2, '0'), '-01')")
+            }
+            TimeUnit::Year => format!("DATE_FORMAT({}, '%Y-01-01')", l),
+        })
+    }
+
+    // NULLS FIRST/LASTに未対応のため、`IS NULL` (0/1) を先行キーにして並べる
+    fn order_key(&self, column: &str, direction: &str, nulls: Option<NullsOrder>) -> String {
+        match nulls {
+            Some(NullsOrder::First) => {
+                format!("{c} IS NULL DESC, {c} {d}", c = column, d = direction)
+            }
+            Some(NullsOrder::Last) => {
+                format!("{c} IS NULL ASC, {c} {d}", c = column, d = direction)
+            }
+            None => format!("{} {}", column, direction),
+        }
+    }
+
+    fn limit_offset(&self, limit: Option<usize>, offset: Option<usize>) -> Option<String> {
+        match (limit, offset) {
+            // MySQLはLIMITなしのOFFSETを受け付けないため、最大値を指定する
+            (None, Some(offset)) => Some(format!("LIMIT {} OFFSET {}", u64::MAX, offset)),
+            _ => standard_limit_offset(limit, offset),
+        }
+    }
+}
+
+/// メタ文字をエスケープしたLIKEパターン
+pub(crate) fn like_pattern(value: &str, kind: TextMatch) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c == '%' || c == '_' || c == LIKE_ESCAPE {
+            escaped.push(LIKE_ESCAPE);
+        }
+        escaped.push(c);
+    }
+    wrap_pattern(&escaped, kind, '%')
+}
+
+/// 一致の種類に合わせてワイルドカードを付与
+fn wrap_pattern(escaped: &str, kind: TextMatch, wildcard: char) -> String {
+    match kind {
+        TextMatch::Contains => format!("{}{}{}", wildcard, escaped, wildcard),
+        TextMatch::StartsWith => format!("{}{}", escaped, wildcard),
+        TextMatch::EndsWith => format!("{}{}", wildcard, escaped),
+    }
+}
+
+/// 標準的なLIMIT / OFFSET句
+fn standard_limit_offset(limit: Option<usize>, offset: Option<usize>) -> Option<String> {
+    match (limit, offset) {
+        (Some(limit), Some(offset)) => Some(format!("LIMIT {} OFFSET {}", limit, offset)),
+        (Some(limit), None) => Some(format!("LIMIT {}", limit)),
+        (None, Some(offset)) => Some(format!("OFFSET {}", offset)),
+        (None, None) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dialect_names() {
+        assert_eq!(SqliteDialect.name(), "sqlite");
+        assert_eq!(PostgresDialect.name(), "postgres");
+        assert_eq!(MySqlDialect.name(), "mysql");
+    }
+
+    #[test]
+    fn test_offset_without_limit() {
+        assert_eq!(
+            SqliteDialect.limit_offset(None, Some(20)).unwrap(),
+            "LIMIT -1 OFFSET 20"
+        );
+        assert_eq!(
+            PostgresDialect.limit_offset(None, Some(20)).unwrap(),
+            "OFFSET 20"
+        );
+        assert_eq!(
+            MySqlDialect.limit_offset(None, Some(20)).unwrap(),
+            "LIMIT 18446744073709551615 OFFSET 20"
+        );
+    }
+
+    #[test]
+    fn test_like_pattern() {
+        assert_eq!(
+            like_pattern("50%_off\\", TextMatch::Contains),
+            "%50\\%\\_off\\\\%"
+        );
+        assert_eq!(like_pattern("plain", TextMatch::StartsWith), "plain%");
+        assert_eq!(like_pattern("plain", TextMatch::EndsWith), "%plain");
+    }
+
+    #[test]
+    fn test_sqlite_glob_pattern() {
+        assert_eq!(
+            SqliteDialect.case_sensitive_pattern("a*b?[c]", TextMatch::Contains),
+            "*a[*]b[?][[]c]*"
+        );
+        assert_eq!(
+            SqliteDialect.case_sensitive_pattern("50%", TextMatch::StartsWith),
+            "50%*"
+        );
+    }
+
+    #[test]
+    fn test_no_limit_no_offset() {
+        assert!(SqliteDialect.limit_offset(None, None).is_none());
+        assert!(MySqlDialect.limit_offset(None, None).is_none());
+    }
+}
@@ -0,0 +1,425 @@
+// FR-12: Column-Level Security (Field Permissions / Masking)
+
+use crate::converter::{ConversionError, ConvertedQuery, QueryCondition};
+use crate::security::CurrentUser;
+use liquid_protocol::{
+    AggregationType, AxisFields, Component, DataSource, Layout, LayoutChild, LiquidViewSchema,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// マスク時の置換文字列
+const MASK_CHAR: char = '*';
+
+/// 全体をマスクした場合の表示
+const REDACTED: &str = "****";
+
+/// フィールド単位の権限とマスク設定
+///
+/// 権限を指定しない操作は制限しない。フィルタ・ソートには読み取り権限も必要。
+/// マスク対象のフィールドは、マスク解除権限がなければフィルタと件数以外の集計に使えない。
+///
+/// ```toml
+/// [fields.employees.salary]
+/// read = "hr:salary"
+///
+/// [fields.employees.card_number]
+/// mask = { show_last = 4 }
+/// unmask = "billing:admin"
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldPolicy {
+    /// 読み取り (SELECT / Table.columns / チャート軸 / 集計) に必要な権限
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    read: Option<String>,
+    /// フィルタに必要な権限
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    filter: Option<String>,
+    /// ソートに必要な権限
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sort: Option<String>,
+    /// 返却値のマスク方法
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mask: Option<FieldMask>,
+    /// マスクを解除する権限
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unmask: Option<String>,
+}
+
+impl FieldPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 読み取り権限を要求
+    pub fn require_read(mut self, permission: impl Into<String>) -> Self {
+        self.read = Some(permission.into());
+        self
+    }
+
+    /// フィルタ権限を要求
+    pub fn require_filter(mut self, permission: impl Into<String>) -> Self {
+        self.filter = Some(permission.into());
+        self
+    }
+
+    /// ソート権限を要求
+    pub fn require_sort(mut self, permission: impl Into<String>) -> Self {
+        self.sort = Some(permission.into());
+        self
+    }
+
+    /// 返却値をマスク
+    pub fn masked(mut self, mask: FieldMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// マスクを解除する権限を指定
+    pub fn unmask_with(mut self, permission: impl Into<String>) -> Self {
+        self.unmask = Some(permission.into());
+        self
+    }
+
+    pub fn can_read(&self, user: &CurrentUser) -> bool {
+        has(user, &self.read)
+    }
+
+    pub fn can_filter(&self, user: &CurrentUser) -> bool {
+        self.can_read(user) && has(user, &self.filter)
+    }
+
+    pub fn can_sort(&self, user: &CurrentUser) -> bool {
+        self.can_read(user) && has(user, &self.sort)
+    }
+
+    /// ユーザーに適用するマスク (マスク解除権限があればNone)
+    pub fn mask_for(&self, user: &CurrentUser) -> Option<&FieldMask> {
+        match &self.unmask {
+            Some(permission) if user.has_permission(permission) => None,
+            _ => self.mask.as_ref(),
+        }
+    }
+}
+
+/// 権限未指定の場合は許可
+fn has(user: &CurrentUser, permission: &Option<String>) -> bool {
+    permission
+        .as_deref()
+        .map_or(true, |p| user.has_permission(p))
+}
+
+/// 返却値のマスク方法
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldMask {
+    /// 値全体を隠す (長さも隠す)
+    Redact,
+    /// 末尾n文字のみ表示 (例: カード番号の下4桁)
+    ShowLast(usize),
+}
+
+impl FieldMask {
+    /// 値をマスク (NULLはそのまま)
+    pub fn apply(&self, value: &Value) -> Value {
+        let text = match value {
+            Value::Null => return Value::Null,
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        match self {
+            FieldMask::Redact => Value::String(REDACTED.to_string()),
+            FieldMask::ShowLast(n) => {
+                let chars: Vec<char> = text.chars().collect();
+                let hidden = chars.len().saturating_sub(*n);
+                let masked: String = std::iter::repeat(MASK_CHAR)
+                    .take(hidden)
+                    .chain(chars[hidden..].iter().copied())
+                    .collect();
+                Value::String(masked)
+            }
+        }
+    }
+}
+
+/// フィールドに対する操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldAction {
+    Read,
+    Filter,
+    Sort,
+}
+
+impl FieldAction {
+    /// 操作ごとのエラーコード
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            FieldAction::Read => "FIELD_READ_DENIED",
+            FieldAction::Filter => "FIELD_FILTER_DENIED",
+            FieldAction::Sort => "FIELD_SORT_DENIED",
+        }
+    }
+
+    fn verb(&self) -> &'static str {
+        match self {
+            FieldAction::Read => "read",
+            FieldAction::Filter => "filter on",
+            FieldAction::Sort => "sort by",
+        }
+    }
+}
+
+/// リソース別のフィールドポリシー
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FieldSecurity {
+    policies: HashMap<String, HashMap<String, FieldPolicy>>,
+}
+
+impl FieldSecurity {
+    pub(crate) fn set(&mut self, resource: String, field: String, policy: FieldPolicy) {
+        self.policies
+            .entry(resource)
+            .or_default()
+            .insert(field, policy);
+    }
+
+    pub(crate) fn get(&self, resource: &str, field: &str) -> Option<&FieldPolicy> {
+        self.policies.get(resource)?.get(field)
+    }
+
+    /// フィールド操作の権限を確認
+    pub(crate) fn check(
+        &self,
+        resource: &str,
+        field: &str,
+        action: FieldAction,
+        user: &CurrentUser,
+    ) -> Result<(), ConversionError> {
+        let Some(policy) = self.get(resource, field) else {
+            return Ok(());
+        };
+        let allowed = match action {
+            FieldAction::Read => policy.can_read(user),
+            FieldAction::Filter => policy.can_filter(user),
+            FieldAction::Sort => policy.can_sort(user),
+        };
+        if allowed {
+            Ok(())
+        } else {
+            Err(ConversionError::new(
+                action.error_code(),
+                format!(
+                    "Not allowed to {} field '{}' of '{}'",
+                    action.verb(),
+                    field,
+                    resource
+                ),
+            ))
+        }
+    }
+
+    /// マスク対象のフィールドの操作を確認
+    ///
+    /// フィルタ条件や集計値からは元の値を推測できるため、マスク解除権限がなければ拒否する。
+    fn check_unmasked(
+        &self,
+        resource: &str,
+        field: &str,
+        action: FieldAction,
+        user: &CurrentUser,
+    ) -> Result<(), ConversionError> {
+        match self.get(resource, field) {
+            Some(policy) if policy.mask_for(user).is_some() => Err(ConversionError::new(
+                action.error_code(),
+                format!(
+                    "Not allowed to {} masked field '{}' of '{}'",
+                    action.verb(),
+                    field,
+                    resource
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// クエリが参照するフィールドを確認 (フィルタ・ソート・集計)
+    ///
+    /// `stripped` は除去予定のユーザー条件で、確認対象外。
+    /// マスク対象のフィールドは件数以外の集計・フィルタ・ソートにも使えない。
+    pub(crate) fn check_query(
+        &self,
+        query: &ConvertedQuery,
+        user: &CurrentUser,
+        stripped: &[QueryCondition],
+    ) -> Result<(), ConversionError> {
+        let resource = query.resource();
+        for field in query
+            .conditions()
+            .iter()
+            .filter(|condition| !stripped.contains(condition))
+            .flat_map(QueryCondition::fields)
+        {
+            self.check(resource, field, FieldAction::Filter, user)?;
+            self.check_unmasked(resource, field, FieldAction::Filter, user)?;
+        }
+        for sort in query.sort() {
+            self.check(resource, sort.field(), FieldAction::Sort, user)?;
+            // 並び順からマスクされた値を推測できる
+            self.check_unmasked(resource, sort.field(), FieldAction::Sort, user)?;
+        }
+        if let Some(aggregation) = query.aggregation() {
+            for field in aggregation.fields() {
+                self.check(resource, field, FieldAction::Read, user)?;
+            }
+            // 集計値の列は元のフィールド名と異なるため、結果行のマスクが適用されない
+            for metric in aggregation.metrics() {
+                if !matches!(
+                    metric.agg_type(),
+                    AggregationType::Count | AggregationType::CountDistinct
+                ) {
+                    self.check_unmasked(resource, metric.field(), FieldAction::Read, user)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// レイアウト内のTable.columnsとチャート軸を確認
+    pub(crate) fn check_schema(
+        &self,
+        schema: &LiquidViewSchema,
+        user: &CurrentUser,
+    ) -> Result<(), ConversionError> {
+        self.check_layout(&schema.layout, &schema.data_sources, user)
+    }
+
+    fn check_layout(
+        &self,
+        layout: &Layout,
+        data_sources: &HashMap<String, DataSource>,
+        user: &CurrentUser,
+    ) -> Result<(), ConversionError> {
+        for child in layout.children() {
+            match child {
+                LayoutChild::Layout(nested) => self.check_layout(nested, data_sources, user)?,
+                LayoutChild::Component(component) => {
+                    self.check_component(component, data_sources, user)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_component(
+        &self,
+        component: &Component,
+        data_sources: &HashMap<String, DataSource>,
+        user: &CurrentUser,
+    ) -> Result<(), ConversionError> {
+        match component {
+            Component::Table {
+                data_source: Some(key),
+                columns,
+                sortable,
+                ..
+            } => {
+                let Some(ds) = data_sources.get(key) else {
+                    return Ok(());
+                };
+                for column in columns {
+                    let field = source_field(ds, column);
+                    self.check(&ds.resource, field, FieldAction::Read, user)?;
+                    if *sortable == Some(true) {
+                        self.check(&ds.resource, field, FieldAction::Sort, user)?;
+                        self.check_unmasked(&ds.resource, field, FieldAction::Sort, user)?;
+                    }
+                }
+                Ok(())
+            }
+            Component::Chart {
+                data_source: Some(key),
+                x_axis,
+                y_axis,
+                ..
+            } => {
+                let Some(ds) = data_sources.get(key) else {
+                    return Ok(());
+                };
+                let y_fields = y_axis.iter().flat_map(AxisFields::fields);
+                for axis in x_axis
+                    .as_deref()
+                    .into_iter()
+                    .chain(y_fields.map(|(_, field)| field))
+                {
+                    self.check(
+                        &ds.resource,
+                        source_field(ds, axis),
+                        FieldAction::Read,
+                        user,
+                    )?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 結果行から読み取り不可のフィールドを除き、マスクを適用
+    pub(crate) fn mask_rows(&self, resource: &str, rows: &mut [Value], user: &CurrentUser) {
+        let Some(policies) = self.policies.get(resource) else {
+            return;
+        };
+        for row in rows.iter_mut() {
+            let Value::Object(object) = row else {
+                continue;
+            };
+            for (field, policy) in policies {
+                if !policy.can_read(user) {
+                    object.remove(field);
+                } else if let (Some(mask), Some(value)) =
+                    (policy.mask_for(user), object.get_mut(field))
+                {
+                    *value = mask.apply(value);
+                }
+            }
+        }
+    }
+}
+
+/// 集計エイリアス (`amount_sum` / metricsのalias) を元のフィールドに解決
+fn source_field<'a>(ds: &'a DataSource, column: &'a str) -> &'a str {
+    if let Some(metric) = ds.metrics.iter().flatten().find(|m| m.alias == column) {
+        return &metric.field;
+    }
+    match &ds.aggregation {
+        Some(aggregation) if aggregation.alias() == column => &aggregation.field,
+        _ => column,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_field_mask_apply() {
+        assert_eq!(
+            FieldMask::ShowLast(4).apply(&json!("4111111111111111")),
+            json!("************1111")
+        );
+        assert_eq!(FieldMask::ShowLast(4).apply(&json!("12")), json!("12"));
+        assert_eq!(FieldMask::ShowLast(2).apply(&json!(12345)), json!("***45"));
+        assert_eq!(FieldMask::Redact.apply(&json!("a@b.c")), json!("****"));
+        assert_eq!(FieldMask::Redact.apply(&Value::Null), Value::Null);
+    }
+
+    #[test]
+    fn test_field_action_error_codes() {
+        assert_eq!(FieldAction::Read.error_code(), "FIELD_READ_DENIED");
+        assert_eq!(FieldAction::Filter.error_code(), "FIELD_FILTER_DENIED");
+        assert_eq!(FieldAction::Sort.error_code(), "FIELD_SORT_DENIED");
+    }
+}
@@ -3,10 +3,48 @@
 //! reinhardt-web integration for Liquid Protocol
 //!
 //! This crate provides DataSource to ORM conversion (FR-06)
-//! and Row-Level Security implementation (FR-07).
+//! with relative dates resolved against an injectable clock and timezone
+//! and time-bucketed grouping with optional zero fill,
+//! restricted by a resource registry (FR-10),
+//! Row-Level Security implementation (FR-07)
+//! with declarative TOML/JSON policies (FR-11),
+//! column-level security (FR-12) and a policy decision audit log (FR-13),
+//! parameterized SQL generation (FR-08) with offset and signed keyset
+//! cursor pagination (FR-14)
+//! and an embedded SQLite executor behind the `sqlite` feature (FR-09).
 
+pub mod audit;
+pub mod bucket;
+pub mod clock;
 pub mod converter;
+pub mod dialect;
+pub mod field_security;
+pub mod pagination;
+pub mod policy;
+pub mod registry;
 pub mod security;
+pub mod sql;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 
-pub use converter::{ConversionError, ConvertedQuery, DataSourceConverter, QueryCondition};
-pub use security::{CurrentUser, SecurityEnforcer, SecurityPolicy};
+pub use audit::{AuditDecision, AuditEvent, AuditSink, InMemoryAuditSink, JsonLinesAuditSink};
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use converter::{
+    ConversionError, ConvertedQuery, DataSourceConverter, QueryAggregation, QueryCondition,
+    QueryMetric, QueryPage, QuerySort, QueryTimeBucket, QueryValue,
+};
+pub use dialect::{Dialect, MySqlDialect, PostgresDialect, SqliteDialect, TextMatch};
+pub use field_security::{FieldAction, FieldMask, FieldPolicy};
+pub use pagination::{CursorCodec, PageResult};
+pub use policy::{
+    InjectOperator, InjectRule, PolicyCombinator, PolicyDocument, PolicyEffect, PolicyRule,
+    PolicyValue,
+};
+pub use registry::{FieldType, ResourceDefinition, ResourceRegistry};
+pub use security::{
+    CurrentUser, ProtectedFieldMode, SecurityEnforcer, SecurityPolicy, UserAttribute,
+    DEFAULT_POLICY_NAME, PUBLIC_POLICY_NAME, WILDCARD_RESOURCE,
+};
+pub use sql::{SqlBuilder, SqlQuery, SqlValue};
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteExecutor;
@@ -0,0 +1,219 @@
+// FR-14: Signed Pagination Cursors
+
+use crate::converter::{
+    parse_date, parse_datetime, ConversionError, ConvertedQuery, QueryPage, QueryValue,
+};
+use crate::registry::{FieldType, ResourceRegistry};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::fmt;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// キーセットページングのカーソルを発行・検証する
+///
+/// カーソルは前ページ最終行のソートキー値をHMAC-SHA256で署名した不透明なトークン
+/// (`{値のJSON}.{署名}`、どちらもURL-safe Base64)。署名にはRLS適用後の全条件と
+/// ソートキーを含めるため、改ざんしたカーソルや、別のユーザー・別のクエリ向けに
+/// 発行されたカーソルは検証に失敗する。
+#[derive(Clone)]
+pub struct CursorCodec {
+    secret: Vec<u8>,
+}
+
+impl CursorCodec {
+    /// 署名鍵を指定して生成 (サーバー側でのみ保持する)
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            secret: secret.into(),
+        }
+    }
+
+    /// 結果行の次から始まるページのカーソルを発行
+    ///
+    /// `query`はRLS適用後のクエリ。ソートキーの値はNULLでもよい。
+    pub fn encode(&self, query: &ConvertedQuery, row: &Value) -> Result<String, ConversionError> {
+        let values = query
+            .sort()
+            .iter()
+            .map(|sort| match row.get(sort.field()) {
+                Some(
+                    value @ (Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_)),
+                ) => Ok(value.clone()),
+                _ => Err(invalid_cursor(format!(
+                    "sort key '{}' of the last row must be a scalar",
+                    sort.field()
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let payload = serde_json::to_vec(&values).map_err(|err| invalid_cursor(err.to_string()))?;
+        let signature = self.sign(query, &payload).finalize().into_bytes();
+        Ok(format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(&payload),
+            URL_SAFE_NO_PAD.encode(signature)
+        ))
+    }
+
+    /// カーソルを検証し、前ページ最終行のソートキー値を返す
+    ///
+    /// `registry`に型が宣言されたソートキーは、その型の値 (日付・日時など) に変換する。
+    pub fn decode(
+        &self,
+        query: &ConvertedQuery,
+        cursor: &str,
+        registry: Option<&ResourceRegistry>,
+    ) -> Result<Vec<QueryValue>, ConversionError> {
+        let (payload, signature) = cursor
+            .split_once('.')
+            .ok_or_else(|| invalid_cursor("malformed cursor"))?;
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| invalid_cursor("malformed cursor"))?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| invalid_cursor("malformed cursor"))?;
+        self.sign(query, &payload)
+            .verify_slice(&signature)
+            .map_err(|_| invalid_cursor("cursor was not issued for this query"))?;
+
+        let values: Vec<Value> =
+            serde_json::from_slice(&payload).map_err(|_| invalid_cursor("malformed cursor"))?;
+        if values.len() != query.sort().len() {
+            return Err(invalid_cursor(
+                "cursor does not match the sort keys of the query",
+            ));
+        }
+        let resource = registry.and_then(|registry| registry.resource(query.resource()));
+        query
+            .sort()
+            .iter()
+            .zip(values)
+            .map(|(sort, value)| {
+                let field_type = resource.and_then(|resource| resource.field_type(sort.field()));
+                cursor_value(value, field_type).ok_or_else(|| {
+                    invalid_cursor(format!("invalid cursor value for '{}'", sort.field()))
+                })
+            })
+            .collect()
+    }
+
+    /// クエリの未検証カーソル (`QueryPage::Cursor`) を検証済みの位置 (`QueryPage::After`) に置き換える
+    pub fn resolve(
+        &self,
+        query: &mut ConvertedQuery,
+        registry: Option<&ResourceRegistry>,
+    ) -> Result<(), ConversionError> {
+        if let Some(QueryPage::Cursor(cursor)) = query.page() {
+            let values = self.decode(query, cursor, registry)?;
+            query.set_page(QueryPage::After(values));
+        }
+        Ok(())
+    }
+
+    /// クエリの範囲 (リソース・全条件・ソートキー) とカーソル値に対する署名
+    fn sign(&self, query: &ConvertedQuery, payload: &[u8]) -> HmacSha256 {
+        let sort: Vec<Value> = query
+            .sort()
+            .iter()
+            .map(|sort| json!([sort.field(), sort.direction(), sort.nulls()]))
+            .collect();
+        let scope = json!({
+            "resource": query.resource(),
+            "conditions": query.all_conditions().collect::<Vec<_>>(),
+            "sort": sort,
+        });
+
+        let mut mac =
+            HmacSha256::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(scope.to_string().as_bytes());
+        // JSONの文字列中の改行はエスケープされるため、区切りとして曖昧にならない
+        mac.update(b"\n");
+        mac.update(payload);
+        mac
+    }
+}
+
+// 署名鍵をログに出さない
+impl fmt::Debug for CursorCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CursorCodec").finish_non_exhaustive()
+    }
+}
+
+/// ページングした実行結果
+///
+/// 続きがある場合、オフセット指定のページには`next_offset`、カーソルを発行できる
+/// クエリ (集計しないソート済みのクエリ) には`next_cursor`が付く。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PageResult {
+    rows: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<String>,
+}
+
+impl PageResult {
+    pub fn new(rows: Vec<Value>, next_offset: Option<usize>, next_cursor: Option<String>) -> Self {
+        Self {
+            rows,
+            next_offset,
+            next_cursor,
+        }
+    }
+
+    pub fn rows(&self) -> &[Value] {
+        &self.rows
+    }
+
+    pub fn into_rows(self) -> Vec<Value> {
+        self.rows
+    }
+
+    /// 次ページの`{"offset": n}`
+    pub fn next_offset(&self) -> Option<usize> {
+        self.next_offset
+    }
+
+    /// 次ページの`{"after": cursor}`
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+
+    /// 続きのページがあるか
+    pub fn has_more(&self) -> bool {
+        self.next_offset.is_some() || self.next_cursor.is_some()
+    }
+}
+
+/// カーソルのJSON値をクエリ値に変換 (型の宣言がなければJSONの型のまま)
+fn cursor_value(value: Value, field_type: Option<FieldType>) -> Option<QueryValue> {
+    match (field_type, value) {
+        (_, Value::Null) => Some(QueryValue::Null),
+        (Some(FieldType::Integer) | None, Value::Number(n)) if n.is_i64() => {
+            n.as_i64().map(QueryValue::Integer)
+        }
+        (Some(FieldType::Float) | None, Value::Number(n)) => n.as_f64().map(QueryValue::Float),
+        (Some(FieldType::String) | None, Value::String(s)) => Some(QueryValue::String(s)),
+        (Some(FieldType::Boolean) | None, Value::Bool(b)) => Some(QueryValue::Boolean(b)),
+        // SQLiteは真偽値を0/1で返す
+        (Some(FieldType::Boolean), Value::Number(n)) => {
+            n.as_i64().map(|i| QueryValue::Boolean(i != 0))
+        }
+        (Some(FieldType::Date), Value::String(s)) => parse_date(&s).map(QueryValue::Date),
+        (Some(FieldType::DateTime), Value::String(s)) => {
+            parse_datetime(&s).map(QueryValue::DateTime)
+        }
+        _ => None,
+    }
+}
+
+fn invalid_cursor(message: impl Into<String>) -> ConversionError {
+    ConversionError::new("INVALID_CURSOR", message)
+}
@@ -0,0 +1,474 @@
+// FR-11: Declarative RLS Policy Format (TOML / JSON)
+
+use crate::converter::{ConversionError, ConvertedQuery, QueryCondition, QueryValue};
+use crate::field_security::FieldPolicy;
+use crate::security::{CurrentUser, UserAttribute};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// ユーザー属性参照のプレフィックス (`user.id` など)
+const USER_REF_PREFIX: &str = "user.";
+
+/// ポリシー定義ファイル
+///
+/// ```toml
+/// [[policies]]
+/// name = "expenses_owner"
+/// resource = "expenses"
+/// require_permissions = ["read:expenses"]
+/// deny_filter_fields = ["salary"]
+/// inject = [
+///     { field = "org_id", from = "user.org_id" },
+///     { field = "team_id", op = "in", from = "user.team_ids" },
+/// ]
+///
+/// [[policies]]
+/// name = "suspended_users"
+/// resource = "*"
+/// effect = "deny"
+/// require_permissions = ["suspended"]
+///
+/// public_resources = ["exchange_rates"]
+///
+/// [combinators]
+/// expenses = "deny_overrides"
+///
+/// [fields.employees.salary]
+/// read = "hr:salary"
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyDocument {
+    #[serde(default)]
+    pub policies: Vec<PolicyRule>,
+    /// 行フィルタなしで参照できる公開リソース
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub public_resources: Vec<String>,
+    /// リソース別の結合方法 (`*` は全リソースのデフォルト)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub combinators: BTreeMap<String, PolicyCombinator>,
+    /// リソース別・フィールド別の権限とマスク
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, BTreeMap<String, FieldPolicy>>,
+}
+
+impl PolicyDocument {
+    /// TOML文字列から読み込み
+    pub fn from_toml(source: &str) -> Result<Self, ConversionError> {
+        let document: Self = toml::from_str(source).map_err(invalid_policy)?;
+        document.validate()?;
+        Ok(document)
+    }
+
+    /// JSON文字列から読み込み
+    pub fn from_json(source: &str) -> Result<Self, ConversionError> {
+        let document: Self = serde_json::from_str(source).map_err(invalid_policy)?;
+        document.validate()?;
+        Ok(document)
+    }
+
+    /// ファイルから読み込み (拡張子 `.toml` / `.json` で形式を判定)
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, ConversionError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(invalid_policy)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&source),
+            Some("json") => Self::from_json(&source),
+            _ => Err(ConversionError::new(
+                "INVALID_POLICY",
+                format!("Unsupported policy file: {}", path.display()),
+            )),
+        }
+    }
+
+    /// TOML文字列に書き出し (レビュー・バージョン管理用)
+    pub fn to_toml(&self) -> Result<String, ConversionError> {
+        toml::to_string_pretty(self).map_err(invalid_policy)
+    }
+
+    /// 全ルールの整合性を検証
+    pub fn validate(&self) -> Result<(), ConversionError> {
+        self.policies.iter().try_for_each(PolicyRule::validate)
+    }
+}
+
+/// リソース単位のポリシールール
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyRule {
+    /// ポリシー名 (エラー・監査ログに表示)
+    pub name: String,
+    /// 対象リソース (`*` は全リソース)
+    pub resource: String,
+    /// 許可ポリシーか拒否ポリシーか (デフォルト: allow)
+    #[serde(default, skip_serializing_if = "PolicyEffect::is_allow")]
+    pub effect: PolicyEffect,
+    /// 必要な権限 (全て必要)。拒否ポリシーでは全て持つユーザーが対象
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub require_permissions: Vec<String>,
+    /// クエリに注入する条件
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inject: Vec<InjectRule>,
+    /// フィルタでの使用を禁止するフィールド
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny_filter_fields: Vec<String>,
+}
+
+impl PolicyRule {
+    /// ルールの整合性を検証
+    pub fn validate(&self) -> Result<(), ConversionError> {
+        if self.name.is_empty() || self.resource.is_empty() {
+            return Err(ConversionError::new(
+                "INVALID_POLICY",
+                "Policy must have a name and a resource",
+            ));
+        }
+        if self.effect == PolicyEffect::Deny && !self.inject.is_empty() {
+            return Err(ConversionError::new(
+                "INVALID_POLICY",
+                format!("{}: deny policies cannot inject conditions", self.name),
+            ));
+        }
+        for rule in &self.inject {
+            rule.validate().map_err(|err| {
+                ConversionError::new(err.code(), format!("{}: {}", self.name, err.message()))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// ポリシーを評価 (クエリは変更しない)
+    pub(crate) fn decide(&self, query: &ConvertedQuery, user: &CurrentUser) -> PolicyDecision {
+        let missing_permission = self
+            .require_permissions
+            .iter()
+            .find(|permission| !user.has_permission(permission));
+
+        if let Some(field) = query
+            .conditions()
+            .iter()
+            .flat_map(QueryCondition::fields)
+            .find(|field| self.deny_filter_fields.iter().any(|f| f == field))
+        {
+            return PolicyDecision::Deny(access_denied(
+                &self.name,
+                &format!("filtering on '{}' is not allowed", field),
+            ));
+        }
+
+        match (self.effect, missing_permission) {
+            (PolicyEffect::Deny, None) => {
+                PolicyDecision::Deny(access_denied(&self.name, "denied by rule"))
+            }
+            (PolicyEffect::Deny, Some(_)) => PolicyDecision::NotApplicable,
+            (PolicyEffect::Allow, Some(missing)) => PolicyDecision::NotGranted(access_denied(
+                &self.name,
+                &format!("missing permission '{}'", missing),
+            )),
+            (PolicyEffect::Allow, None) => {
+                // 全ての注入条件が解決できた場合のみ許可する
+                let conditions = self
+                    .inject
+                    .iter()
+                    .map(|rule| {
+                        rule.to_condition(user).ok_or_else(|| {
+                            access_denied(
+                                &self.name,
+                                &format!("cannot resolve value for '{}'", rule.field),
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>();
+                match conditions {
+                    Ok(conditions) => PolicyDecision::Allow(conditions),
+                    Err(err) => PolicyDecision::NotGranted(err),
+                }
+            }
+        }
+    }
+}
+
+/// ポリシーの効果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyEffect {
+    /// 条件を満たすとアクセスを許可
+    #[default]
+    Allow,
+    /// 条件を満たすとアクセスを拒否
+    Deny,
+}
+
+impl PolicyEffect {
+    fn is_allow(&self) -> bool {
+        *self == PolicyEffect::Allow
+    }
+}
+
+/// 同じリソースに複数のポリシーがある場合の結合方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyCombinator {
+    /// 全ての許可ポリシーを満たす必要がある (注入条件は全てAND)
+    #[default]
+    AllOf,
+    /// いずれかの許可ポリシーを満たせばよい (許可したポリシーの条件をORで注入)
+    AnyOf,
+    /// 拒否ポリシーに該当すれば拒否、それ以外はいずれかの許可ポリシーで許可 (条件はOR)
+    ///
+    /// 拒否はどの結合方法でも優先されるため`AnyOf`と同じ動作 (意図を明示するための指定)
+    DenyOverrides,
+}
+
+impl PolicyCombinator {
+    /// 評価結果を結合し、注入する条件を返す
+    ///
+    /// 拒否 (拒否ポリシーへの該当・フィルタ禁止フィールドの使用) は常にアクセスを拒否し、
+    /// 結合方法が扱うのは許可ポリシーの結果のみ
+    pub(crate) fn combine(
+        self,
+        resource: &str,
+        decisions: Vec<PolicyDecision>,
+    ) -> Result<Vec<QueryCondition>, ConversionError> {
+        let mut granted: Vec<Vec<QueryCondition>> = Vec::new();
+        let mut first_error = None;
+
+        for decision in decisions {
+            match (self, decision) {
+                (_, PolicyDecision::NotApplicable) => {}
+                (PolicyCombinator::AllOf, PolicyDecision::Allow(conditions)) => {
+                    match granted.first_mut() {
+                        Some(all) => all.extend(conditions),
+                        None => granted.push(conditions),
+                    }
+                }
+                // 拒否ポリシーとフィルタ禁止フィールドは結合方法によらず優先する
+                (_, PolicyDecision::Deny(err))
+                | (PolicyCombinator::AllOf, PolicyDecision::NotGranted(err)) => return Err(err),
+                (_, PolicyDecision::Allow(conditions)) => {
+                    if !granted.contains(&conditions) {
+                        granted.push(conditions);
+                    }
+                }
+                (_, PolicyDecision::NotGranted(err)) => {
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+
+        let granted = match granted.len() {
+            0 => None,
+            1 => granted.pop(),
+            // 条件なしで許可するポリシーがあれば制限しない
+            _ if granted.iter().any(Vec::is_empty) => Some(Vec::new()),
+            _ => Some(vec![QueryCondition::Or {
+                conditions: granted.into_iter().map(all_of).collect(),
+            }]),
+        };
+
+        granted.ok_or_else(|| {
+            first_error.unwrap_or_else(|| {
+                ConversionError::new(
+                    "ACCESS_DENIED",
+                    format!("Access denied: no policy grants access to '{}'", resource),
+                )
+            })
+        })
+    }
+}
+
+/// 条件リストを1つの条件にまとめる (複数ならAND)
+fn all_of(mut conditions: Vec<QueryCondition>) -> QueryCondition {
+    if conditions.len() == 1 {
+        conditions.remove(0)
+    } else {
+        QueryCondition::And { conditions }
+    }
+}
+
+/// 単一ポリシーの評価結果
+#[derive(Debug)]
+pub(crate) enum PolicyDecision {
+    /// 許可 (注入する条件)
+    Allow(Vec<QueryCondition>),
+    /// 許可ポリシーの条件を満たさない
+    NotGranted(ConversionError),
+    /// 拒否ポリシーに該当
+    Deny(ConversionError),
+    /// 拒否ポリシーに該当しない
+    NotApplicable,
+}
+
+/// 注入条件 (`field = user.id` など)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InjectRule {
+    /// 条件を追加するフィールド
+    pub field: String,
+    /// 演算子 (デフォルト: eq)
+    #[serde(default)]
+    pub op: InjectOperator,
+    /// ユーザー属性参照 (`user.id`, `user.roles`, `user.<属性名>`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// 固定値
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<PolicyValue>,
+}
+
+impl InjectRule {
+    fn validate(&self) -> Result<(), ConversionError> {
+        match (&self.from, &self.value) {
+            (Some(reference), None) if reference.starts_with(USER_REF_PREFIX) => Ok(()),
+            (Some(reference), None) => Err(ConversionError::new(
+                "INVALID_POLICY",
+                format!(
+                    "inject '{}' must reference a user attribute (user.*), got '{}'",
+                    self.field, reference
+                ),
+            )),
+            (None, Some(PolicyValue::List(_))) if self.op != InjectOperator::In => {
+                Err(ConversionError::new(
+                    "INVALID_POLICY",
+                    format!(
+                        "inject '{}' can only use a list value with op 'in'",
+                        self.field
+                    ),
+                ))
+            }
+            (None, Some(_)) => Ok(()),
+            _ => Err(ConversionError::new(
+                "INVALID_POLICY",
+                format!(
+                    "inject '{}' must have exactly one of 'from' or 'value'",
+                    self.field
+                ),
+            )),
+        }
+    }
+
+    /// ユーザーコンテキストで値を解決して条件に変換 (解決できない場合はNone)
+    fn to_condition(&self, user: &CurrentUser) -> Option<QueryCondition> {
+        let attribute = match (&self.from, &self.value) {
+            (Some(reference), _) => resolve_user_ref(user, reference)?,
+            (None, Some(value)) => value.to_attribute(),
+            (None, None) => return None,
+        };
+
+        let field = self.field.clone();
+        match self.op {
+            InjectOperator::In => Some(QueryCondition::In {
+                field,
+                values: attribute.values(),
+            }),
+            // 複数値の属性は eq / neq では比較できない
+            InjectOperator::Eq => Some(QueryCondition::Eq {
+                field,
+                value: attribute.as_single()?.clone(),
+            }),
+            InjectOperator::Neq => Some(QueryCondition::Neq {
+                field,
+                value: attribute.as_single()?.clone(),
+            }),
+        }
+    }
+}
+
+/// 注入条件の演算子
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InjectOperator {
+    #[default]
+    Eq,
+    Neq,
+    In,
+}
+
+/// ポリシーに記述する固定値
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PolicyValue {
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    List(Vec<PolicyValue>),
+}
+
+impl PolicyValue {
+    fn to_query_values(&self) -> Vec<QueryValue> {
+        match self {
+            PolicyValue::List(values) => values.iter().flat_map(Self::to_query_values).collect(),
+            PolicyValue::Boolean(b) => vec![QueryValue::Boolean(*b)],
+            PolicyValue::Integer(i) => vec![QueryValue::Integer(*i)],
+            PolicyValue::Float(f) => vec![QueryValue::Float(*f)],
+            PolicyValue::String(s) => vec![QueryValue::String(s.clone())],
+        }
+    }
+
+    fn to_attribute(&self) -> UserAttribute {
+        match self {
+            PolicyValue::List(_) => UserAttribute::List(self.to_query_values()),
+            _ => UserAttribute::Single(self.to_query_values().remove(0)),
+        }
+    }
+}
+
+/// `user.*` 参照を解決 (`id` / `roles` 以外は任意属性)
+fn resolve_user_ref(user: &CurrentUser, reference: &str) -> Option<UserAttribute> {
+    match reference.strip_prefix(USER_REF_PREFIX)? {
+        "id" => Some(UserAttribute::from(user.id())),
+        "roles" => Some(UserAttribute::from(user.roles().to_vec())),
+        name => user.attribute(name).cloned(),
+    }
+}
+
+fn access_denied(policy: &str, reason: &str) -> ConversionError {
+    ConversionError::new(
+        "ACCESS_DENIED",
+        format!("Access denied by policy: {} ({})", policy, reason),
+    )
+}
+
+fn invalid_policy(err: impl std::fmt::Display) -> ConversionError {
+    ConversionError::new("INVALID_POLICY", format!("Invalid policy: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_value_list_flattens() {
+        let value = PolicyValue::List(vec![
+            PolicyValue::Integer(1),
+            PolicyValue::String("a".into()),
+        ]);
+        assert_eq!(
+            value.to_query_values(),
+            vec![QueryValue::Integer(1), QueryValue::String("a".into())]
+        );
+    }
+
+    #[test]
+    fn test_resolve_user_ref() {
+        let user = CurrentUser::new(7, vec![])
+            .with_roles(vec!["manager".to_string()])
+            .with_attribute("org_id", 3i64);
+        assert_eq!(
+            resolve_user_ref(&user, "user.id"),
+            Some(UserAttribute::Single(QueryValue::Integer(7)))
+        );
+        assert_eq!(
+            resolve_user_ref(&user, "user.roles"),
+            Some(UserAttribute::List(vec!["manager".into()]))
+        );
+        assert_eq!(
+            resolve_user_ref(&user, "user.org_id"),
+            Some(UserAttribute::Single(QueryValue::Integer(3)))
+        );
+        assert_eq!(resolve_user_ref(&user, "user.unknown"), None);
+        assert_eq!(resolve_user_ref(&user, "id"), None);
+    }
+}
@@ -0,0 +1,168 @@
+// FR-10: Resource Registry (queryable resource / field allowlist)
+
+use liquid_protocol::FieldCatalog;
+use std::collections::HashMap;
+
+/// フィールド型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    String,
+    Integer,
+    Float,
+    Boolean,
+    Date,
+    DateTime,
+}
+
+impl FieldType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FieldType::String => "string",
+            FieldType::Integer => "integer",
+            FieldType::Float => "float",
+            FieldType::Boolean => "boolean",
+            FieldType::Date => "date",
+            FieldType::DateTime => "datetime",
+        }
+    }
+}
+
+/// クエリ可能なリソース定義
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceDefinition {
+    name: String,
+    fields: HashMap<String, FieldType>,
+    primary_key: Option<String>,
+}
+
+impl ResourceDefinition {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            fields: HashMap::new(),
+            primary_key: None,
+        }
+    }
+
+    /// フィールドを追加 (ビルダー)
+    pub fn field(mut self, name: impl Into<String>, field_type: FieldType) -> Self {
+        self.fields.insert(name.into(), field_type);
+        self
+    }
+
+    /// 主キーのカラムを指定 (ビルダー)
+    ///
+    /// ページングで同じ値の行の順序を固定するため、最終ソートキーとして使う。
+    pub fn with_primary_key(mut self, name: impl Into<String>) -> Self {
+        self.primary_key = Some(name.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn primary_key(&self) -> Option<&str> {
+        self.primary_key.as_deref()
+    }
+
+    pub fn has_field(&self, name: &str) -> bool {
+        self.fields.contains_key(name)
+    }
+
+    pub fn field_type(&self, name: &str) -> Option<FieldType> {
+        self.fields.get(name).copied()
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = (&str, FieldType)> {
+        self.fields.iter().map(|(name, ty)| (name.as_str(), *ty))
+    }
+}
+
+/// リソースレジストリ
+///
+/// 登録されたリソースとフィールドのみをクエリ対象として許可する。
+#[derive(Debug, Clone, Default)]
+pub struct ResourceRegistry {
+    resources: HashMap<String, ResourceDefinition>,
+}
+
+impl ResourceRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// リソースを登録 (同名のリソースは置き換え)
+    pub fn register(&mut self, resource: ResourceDefinition) {
+        self.resources.insert(resource.name.clone(), resource);
+    }
+
+    /// リソースを登録 (ビルダー)
+    pub fn with_resource(mut self, resource: ResourceDefinition) -> Self {
+        self.register(resource);
+        self
+    }
+
+    pub fn resource(&self, name: &str) -> Option<&ResourceDefinition> {
+        self.resources.get(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.resources.contains_key(name)
+    }
+}
+
+/// スキーマ検証 (`SchemaValidator::with_field_catalog`) からの参照用
+impl FieldCatalog for ResourceRegistry {
+    fn has_resource(&self, resource: &str) -> bool {
+        self.contains(resource)
+    }
+
+    fn has_field(&self, resource: &str, field: &str) -> bool {
+        self.resource(resource)
+            .is_some_and(|definition| definition.has_field(field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_definition_fields() {
+        let resource = ResourceDefinition::new("expenses")
+            .field("amount", FieldType::Float)
+            .field("date", FieldType::Date);
+
+        assert_eq!(resource.name(), "expenses");
+        assert!(resource.has_field("amount"));
+        assert!(!resource.has_field("password_hash"));
+        assert_eq!(resource.field_type("date"), Some(FieldType::Date));
+        assert_eq!(resource.fields().count(), 2);
+        assert_eq!(resource.primary_key(), None);
+        assert_eq!(
+            resource.with_primary_key("expense_id").primary_key(),
+            Some("expense_id")
+        );
+    }
+
+    #[test]
+    fn test_registry_register() {
+        let registry = ResourceRegistry::new().with_resource(ResourceDefinition::new("users"));
+
+        assert!(registry.contains("users"));
+        assert!(!registry.contains("admin_users"));
+        assert!(registry.resource("users").is_some());
+    }
+
+    #[test]
+    fn test_registry_as_field_catalog() {
+        let registry = ResourceRegistry::new()
+            .with_resource(ResourceDefinition::new("users").field("name", FieldType::String));
+
+        assert!(FieldCatalog::has_resource(&registry, "users"));
+        assert!(registry.has_field("users", "name"));
+        assert!(!registry.has_field("users", "password_hash"));
+        assert!(!registry.has_field("admin_users", "name"));
+    }
+}
@@ -1,18 +1,47 @@
 // FR-07: Row-Level Security Implementation
 
-use crate::converter::{ConversionError, ConvertedQuery, QueryCondition};
-use std::collections::HashMap;
+use crate::audit::{AuditEvent, AuditSink};
+use crate::converter::{ConversionError, ConvertedQuery, QueryCondition, QueryValue};
+use crate::field_security::{FieldPolicy, FieldSecurity};
+use crate::policy::{PolicyCombinator, PolicyDecision, PolicyDocument, PolicyEffect, PolicyRule};
+use liquid_protocol::LiquidViewSchema;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 /// 現在のユーザーコンテキスト
 #[derive(Debug, Clone)]
 pub struct CurrentUser {
     id: u64,
     permissions: Vec<String>,
+    roles: Vec<String>,
+    attributes: HashMap<String, UserAttribute>,
 }
 
 impl CurrentUser {
     pub fn new(id: u64, permissions: Vec<String>) -> Self {
-        Self { id, permissions }
+        Self {
+            id,
+            permissions,
+            roles: Vec::new(),
+            attributes: HashMap::new(),
+        }
+    }
+
+    /// ロールを設定
+    pub fn with_roles(mut self, roles: Vec<String>) -> Self {
+        self.roles = roles;
+        self
+    }
+
+    /// 任意の属性を設定 (`org_id`, `team_ids`, `region` など)
+    pub fn with_attribute(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<UserAttribute>,
+    ) -> Self {
+        self.attributes.insert(name.into(), value.into());
+        self
     }
 
     pub fn id(&self) -> u64 {
@@ -26,13 +55,131 @@ impl CurrentUser {
     pub fn has_permission(&self, permission: &str) -> bool {
         self.permissions.iter().any(|p| p == permission)
     }
+
+    pub fn roles(&self) -> &[String] {
+        &self.roles
+    }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&UserAttribute> {
+        self.attributes.get(name)
+    }
+
+    pub fn attributes(&self) -> &HashMap<String, UserAttribute> {
+        &self.attributes
+    }
+}
+
+/// ユーザー属性の値
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserAttribute {
+    /// 単一値 (`org_id`, `region` など)
+    Single(QueryValue),
+    /// 複数値 (`team_ids` など)
+    List(Vec<QueryValue>),
+}
+
+impl UserAttribute {
+    /// 単一値 (複数値の場合はNone)
+    pub fn as_single(&self) -> Option<&QueryValue> {
+        match self {
+            UserAttribute::Single(value) => Some(value),
+            UserAttribute::List(_) => None,
+        }
+    }
+
+    /// 全ての値 (単一値は1要素)
+    pub fn values(&self) -> Vec<QueryValue> {
+        match self {
+            UserAttribute::Single(value) => vec![value.clone()],
+            UserAttribute::List(values) => values.clone(),
+        }
+    }
+}
+
+impl From<QueryValue> for UserAttribute {
+    fn from(value: QueryValue) -> Self {
+        UserAttribute::Single(value)
+    }
+}
+
+impl From<&str> for UserAttribute {
+    fn from(value: &str) -> Self {
+        UserAttribute::Single(value.into())
+    }
+}
+
+impl From<String> for UserAttribute {
+    fn from(value: String) -> Self {
+        UserAttribute::Single(value.into())
+    }
+}
+
+impl From<i64> for UserAttribute {
+    fn from(value: i64) -> Self {
+        UserAttribute::Single(value.into())
+    }
+}
+
+impl From<u64> for UserAttribute {
+    fn from(value: u64) -> Self {
+        UserAttribute::Single(value.into())
+    }
+}
+
+impl From<bool> for UserAttribute {
+    fn from(value: bool) -> Self {
+        UserAttribute::Single(value.into())
+    }
+}
+
+impl<T: Into<QueryValue>> From<Vec<T>> for UserAttribute {
+    fn from(values: Vec<T>) -> Self {
+        UserAttribute::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+/// ポリシー評価関数
+type PolicyFn = Box<dyn Fn(&CurrentUser, &ConvertedQuery) -> bool + Send + Sync>;
+
+/// 全リソースに適用するポリシーのリソース名
+pub const WILDCARD_RESOURCE: &str = "*";
+
+/// 監査ログ上のデフォルトポリシー名 (user_id = current_user.id)
+pub const DEFAULT_POLICY_NAME: &str = "default_user_id";
+
+/// 監査ログ上の公開リソースのポリシー名
+pub const PUBLIC_POLICY_NAME: &str = "public_resource";
+
+/// システム条件で制約されたフィールドをユーザー (AI) がフィルタした場合の扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProtectedFieldMode {
+    /// クエリを拒否 (PROTECTED_FIELD_FILTER)
+    #[default]
+    Reject,
+    /// 該当するユーザー条件を取り除いて続行
+    Strip,
 }
 
 /// セキュリティポリシー
 pub struct SecurityPolicy {
     name: String,
-    policy_fn: Box<dyn Fn(&CurrentUser, &ConvertedQuery) -> bool + Send + Sync>,
-    filter_field: Option<String>,
+    effect: PolicyEffect,
+    kind: PolicyKind,
+}
+
+/// ポリシーの実体
+enum PolicyKind {
+    /// Rustのクロージャで記述したポリシー
+    Custom {
+        policy_fn: PolicyFn,
+        filter_field: Option<String>,
+    },
+    /// 宣言的ポリシー (TOML / JSON)
+    Declarative(PolicyRule),
 }
 
 impl SecurityPolicy {
@@ -43,8 +190,22 @@ impl SecurityPolicy {
     {
         Self {
             name: name.into(),
-            policy_fn: Box::new(policy_fn),
-            filter_field: None,
+            effect: PolicyEffect::Allow,
+            kind: PolicyKind::Custom {
+                policy_fn: Box::new(policy_fn),
+                filter_field: None,
+            },
+        }
+    }
+
+    /// 拒否ポリシー (関数がtrueを返すとアクセスを拒否)
+    pub fn deny<F>(name: impl Into<String>, policy_fn: F) -> Self
+    where
+        F: Fn(&CurrentUser, &ConvertedQuery) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            effect: PolicyEffect::Deny,
+            ..Self::new(name, policy_fn)
         }
     }
 
@@ -55,93 +216,366 @@ impl SecurityPolicy {
 
         Self {
             name: format!("custom_field_{}", field),
-            policy_fn: Box::new(move |_user, _query| true),
-            filter_field: Some(field_clone),
+            effect: PolicyEffect::Allow,
+            kind: PolicyKind::Custom {
+                policy_fn: Box::new(move |_user, _query| true),
+                filter_field: Some(field_clone),
+            },
         }
     }
 
-    /// ポリシー評価
-    fn evaluate(&self, user: &CurrentUser, query: &ConvertedQuery) -> bool {
-        (self.policy_fn)(user, query)
+    /// 宣言的ポリシールールから生成
+    pub fn from_rule(rule: PolicyRule) -> Result<Self, ConversionError> {
+        rule.validate()?;
+        Ok(Self {
+            name: rule.name.clone(),
+            effect: rule.effect,
+            kind: PolicyKind::Declarative(rule),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn effect(&self) -> PolicyEffect {
+        self.effect
     }
 
-    /// フィルタフィールド取得
-    fn get_filter_field(&self) -> Option<&str> {
-        self.filter_field.as_deref()
+    /// 宣言的ポリシーの場合は元のルール
+    pub fn rule(&self) -> Option<&PolicyRule> {
+        match &self.kind {
+            PolicyKind::Declarative(rule) => Some(rule),
+            PolicyKind::Custom { .. } => None,
+        }
+    }
+
+    /// ポリシー評価
+    fn decide(&self, user: &CurrentUser, query: &ConvertedQuery) -> PolicyDecision {
+        let (policy_fn, filter_field) = match &self.kind {
+            PolicyKind::Declarative(rule) => return rule.decide(query, user),
+            PolicyKind::Custom {
+                policy_fn,
+                filter_field,
+            } => (policy_fn, filter_field),
+        };
+
+        let matched = policy_fn(user, query);
+        let denied = || {
+            ConversionError::new(
+                "ACCESS_DENIED",
+                format!("Access denied by policy: {}", self.name),
+            )
+        };
+
+        match (self.effect, matched) {
+            (PolicyEffect::Deny, true) => PolicyDecision::Deny(denied()),
+            (PolicyEffect::Deny, false) => PolicyDecision::NotApplicable,
+            (PolicyEffect::Allow, false) => PolicyDecision::NotGranted(denied()),
+            // カスタムフィールドがある場合はフィルタ追加
+            (PolicyEffect::Allow, true) => PolicyDecision::Allow(
+                filter_field
+                    .iter()
+                    .map(|field| QueryCondition::Eq {
+                        field: field.clone(),
+                        value: QueryValue::from(user.id()),
+                    })
+                    .collect(),
+            ),
+        }
     }
 }
 
 /// Row-Level Security Enforcer
 pub struct SecurityEnforcer {
-    resource_policies: HashMap<String, SecurityPolicy>,
+    resource_policies: HashMap<String, Vec<SecurityPolicy>>,
+    default_policies: Vec<SecurityPolicy>,
+    combinators: HashMap<String, PolicyCombinator>,
+    default_combinator: PolicyCombinator,
+    public_resources: HashSet<String>,
+    field_security: FieldSecurity,
+    audit_sink: Option<Arc<dyn AuditSink>>,
+    protected_field_mode: ProtectedFieldMode,
+    strict: bool,
 }
 
 impl SecurityEnforcer {
     pub fn new() -> Self {
         Self {
             resource_policies: HashMap::new(),
+            default_policies: Vec::new(),
+            combinators: HashMap::new(),
+            default_combinator: PolicyCombinator::AllOf,
+            public_resources: HashSet::new(),
+            field_security: FieldSecurity::default(),
+            audit_sink: None,
+            protected_field_mode: ProtectedFieldMode::Reject,
+            strict: false,
         }
     }
 
-    /// リソース別カスタムポリシーを追加
+    /// 厳格モードを設定
+    ///
+    /// 厳格モードではポリシーも公開設定もないリソースへのアクセスを拒否する
+    /// (デフォルトポリシー `user_id = current_user.id` を使用しない)
+    pub fn with_strict_mode(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// 保護フィールドへのユーザー条件の扱いを設定
+    pub fn with_protected_field_mode(mut self, mode: ProtectedFieldMode) -> Self {
+        self.protected_field_mode = mode;
+        self
+    }
+
+    pub fn protected_field_mode(&self) -> ProtectedFieldMode {
+        self.protected_field_mode
+    }
+
+    /// 全てのRLS適用を記録する監査Sinkを設定
+    pub fn with_audit_sink(mut self, sink: Arc<dyn AuditSink>) -> Self {
+        self.audit_sink = Some(sink);
+        self
+    }
+
+    /// 行フィルタなしで参照できる公開リソースを登録
+    pub fn allow_public_resource(&mut self, resource: impl Into<String>) {
+        self.public_resources.insert(resource.into());
+    }
+
+    pub fn is_public_resource(&self, resource: &str) -> bool {
+        self.public_resources.contains(resource)
+    }
+
+    /// リソース別カスタムポリシーを追加 (既存のポリシーは残る)
+    ///
+    /// リソース名が `*` の場合は全リソースに適用する
     pub fn add_policy_for_resource(&mut self, resource: impl Into<String>, policy: SecurityPolicy) {
-        self.resource_policies.insert(resource.into(), policy);
+        let resource = resource.into();
+        if resource == WILDCARD_RESOURCE {
+            self.add_default_policy(policy);
+        } else {
+            self.resource_policies
+                .entry(resource)
+                .or_default()
+                .push(policy);
+        }
     }
 
-    /// RLSを適用
-    pub fn enforce(
+    /// 全リソースに適用するポリシーを追加 (監査ルールなど)
+    pub fn add_default_policy(&mut self, policy: SecurityPolicy) {
+        self.default_policies.push(policy);
+    }
+
+    /// リソースのポリシー結合方法を設定 (`*` は全リソースのデフォルト)
+    pub fn set_combinator(&mut self, resource: impl Into<String>, combinator: PolicyCombinator) {
+        let resource = resource.into();
+        if resource == WILDCARD_RESOURCE {
+            self.default_combinator = combinator;
+        } else {
+            self.combinators.insert(resource, combinator);
+        }
+    }
+
+    /// フィールド単位の権限・マスクを設定
+    pub fn set_field_policy(
+        &mut self,
+        resource: impl Into<String>,
+        field: impl Into<String>,
+        policy: FieldPolicy,
+    ) {
+        self.field_security
+            .set(resource.into(), field.into(), policy);
+    }
+
+    pub fn field_policy(&self, resource: &str, field: &str) -> Option<&FieldPolicy> {
+        self.field_security.get(resource, field)
+    }
+
+    /// スキーマのTable.columnsとチャート軸がユーザーに読み取り可能か確認
+    pub fn check_schema(
         &self,
-        query: &mut ConvertedQuery,
+        schema: &LiquidViewSchema,
         user: &CurrentUser,
     ) -> Result<(), ConversionError> {
-        let resource = query.resource();
+        self.field_security.check_schema(schema, user)
+    }
 
-        // リソース別カスタムポリシーがあるか確認
-        if let Some(policy) = self.resource_policies.get(resource) {
-            return self.apply_custom_policy(query, user, policy);
-        }
+    /// 結果行から読み取り不可のフィールドを除き、マスクを適用
+    pub fn mask_rows(&self, resource: &str, rows: &mut [Value], user: &CurrentUser) {
+        self.field_security.mask_rows(resource, rows, user);
+    }
 
-        // デフォルトポリシー: user_id = current_user.id
-        self.apply_default_policy(query, user);
+    /// リソースに適用される結合方法
+    pub fn combinator(&self, resource: &str) -> PolicyCombinator {
+        self.combinators
+            .get(resource)
+            .copied()
+            .unwrap_or(self.default_combinator)
+    }
 
+    /// ポリシー定義ファイルの全ルールを各リソースに登録
+    pub fn load_policies(&mut self, document: PolicyDocument) -> Result<(), ConversionError> {
+        document.validate()?;
+        for rule in document.policies {
+            let resource = rule.resource.clone();
+            self.add_policy_for_resource(resource, SecurityPolicy::from_rule(rule)?);
+        }
+        for (resource, combinator) in document.combinators {
+            self.set_combinator(resource, combinator);
+        }
+        for resource in document.public_resources {
+            self.allow_public_resource(resource);
+        }
+        for (resource, fields) in document.fields {
+            for (field, policy) in fields {
+                self.set_field_policy(resource.clone(), field, policy);
+            }
+        }
         Ok(())
     }
 
-    /// デフォルトポリシー適用
-    fn apply_default_policy(&self, query: &mut ConvertedQuery, user: &CurrentUser) {
-        let user_id_condition = QueryCondition::Eq {
-            field: "user_id".to_string(),
-            value: user.id().to_string(),
-        };
+    /// TOML形式のポリシー定義を読み込み
+    pub fn load_policies_toml(&mut self, source: &str) -> Result<(), ConversionError> {
+        self.load_policies(PolicyDocument::from_toml(source)?)
+    }
 
-        query.add_condition(user_id_condition);
+    /// JSON形式のポリシー定義を読み込み
+    pub fn load_policies_json(&mut self, source: &str) -> Result<(), ConversionError> {
+        self.load_policies(PolicyDocument::from_json(source)?)
     }
 
-    /// カスタムポリシー適用
-    fn apply_custom_policy(
+    /// RLSを適用
+    ///
+    /// ポリシーが注入する条件はシステム条件 (`ConvertedQuery::system_conditions`) として追加し、
+    /// 同じフィールドへのユーザー条件は保護フィールドとして拒否または除去する。
+    ///
+    /// 全リソース共通のポリシーとリソース別ポリシーを結合方法に従って評価する。
+    /// リソース別ポリシーがない場合、公開リソースは行フィルタなし、それ以外は
+    /// デフォルトポリシー (user_id = current_user.id) を使用する (厳格モードでは拒否)
+    pub fn enforce(
         &self,
         query: &mut ConvertedQuery,
         user: &CurrentUser,
-        policy: &SecurityPolicy,
     ) -> Result<(), ConversionError> {
-        // ポリシー評価
-        if !policy.evaluate(user, query) {
+        let mut evaluated = Vec::new();
+        let result = self.evaluate(query, user, &mut evaluated);
+
+        let (result, stripped) = match result {
+            Ok((conditions, stripped)) => (Ok(conditions), stripped),
+            Err(err) => (Err(err), Vec::new()),
+        };
+
+        // 監査ログに記録できない場合はクエリを実行させない
+        if let Some(sink) = &self.audit_sink {
+            sink.record(
+                &AuditEvent::new(user.id(), query.resource(), evaluated, &result)
+                    .with_stripped_conditions(stripped.clone()),
+            )?;
+        }
+
+        let conditions = result?;
+        query.retain_conditions(|condition| !stripped.contains(condition));
+        for condition in conditions {
+            query.add_system_condition(condition);
+        }
+
+        Ok(())
+    }
+
+    /// ポリシーを評価し、注入する条件と除去するユーザー条件を返す
+    /// (評価したポリシー名を `evaluated` に追加)
+    fn evaluate(
+        &self,
+        query: &ConvertedQuery,
+        user: &CurrentUser,
+        evaluated: &mut Vec<String>,
+    ) -> Result<(Vec<QueryCondition>, Vec<QueryCondition>), ConversionError> {
+        let resource = query.resource();
+
+        if self.strict
+            && !self.resource_policies.contains_key(resource)
+            && !self.is_public_resource(resource)
+        {
             return Err(ConversionError::new(
                 "ACCESS_DENIED",
-                format!("Access denied by policy: {}", policy.name),
+                format!(
+                    "Access denied: no security policy registered for resource '{}'",
+                    resource
+                ),
             ));
         }
 
-        // カスタムフィールドがある場合はフィルタ追加
-        if let Some(field) = policy.get_filter_field() {
-            let condition = QueryCondition::Eq {
-                field: field.to_string(),
-                value: user.id().to_string(),
-            };
-            query.add_condition(condition);
+        let mut decisions = Vec::new();
+        let policies = self
+            .default_policies
+            .iter()
+            .chain(self.resource_policies.get(resource).into_iter().flatten());
+        for policy in policies {
+            evaluated.push(policy.name.clone());
+            decisions.push(policy.decide(user, query));
+        }
+        if !self.resource_policies.contains_key(resource) {
+            if self.is_public_resource(resource) {
+                evaluated.push(PUBLIC_POLICY_NAME.to_string());
+                decisions.push(PolicyDecision::Allow(Vec::new()));
+            } else {
+                evaluated.push(DEFAULT_POLICY_NAME.to_string());
+                decisions.push(self.default_decision(user));
+            }
         }
 
-        Ok(())
+        let conditions = self.combinator(resource).combine(resource, decisions)?;
+
+        // システム条件のフィールドをユーザー条件で上書き・拡張させない
+        // (グループ内で参照している場合はグループごと対象)
+        let protected_fields: Vec<&str> =
+            conditions.iter().flat_map(QueryCondition::fields).collect();
+        let protected: Vec<(&QueryCondition, &str)> = query
+            .conditions()
+            .iter()
+            .filter_map(|condition| {
+                let field = condition
+                    .fields()
+                    .into_iter()
+                    .find(|field| protected_fields.contains(field))?;
+                Some((condition, field))
+            })
+            .collect();
+        let stripped = match (self.protected_field_mode, protected.first()) {
+            (_, None) => Vec::new(),
+            (ProtectedFieldMode::Reject, Some((_, field))) => {
+                return Err(ConversionError::new(
+                    "PROTECTED_FIELD_FILTER",
+                    format!(
+                        "Filter on '{}' is not allowed: the field is enforced by a security policy",
+                        field
+                    ),
+                ))
+            }
+            (ProtectedFieldMode::Strip, Some(_)) => protected
+                .into_iter()
+                .map(|(condition, _)| condition.clone())
+                .collect(),
+        };
+
+        // ユーザー・AI指定の条件のみフィールド権限を確認
+        self.field_security.check_query(query, user, &stripped)?;
+
+        Ok((conditions, stripped))
+    }
+
+    /// デフォルトポリシー: user_id = current_user.id
+    fn default_decision(&self, user: &CurrentUser) -> PolicyDecision {
+        PolicyDecision::Allow(vec![QueryCondition::Eq {
+            field: "user_id".to_string(),
+            value: QueryValue::from(user.id()),
+        }])
     }
 }
 
@@ -170,12 +604,33 @@ mod tests {
         assert!(!user.has_permission("delete"));
     }
 
+    #[test]
+    fn test_user_attribute_values() {
+        let single = UserAttribute::from(7i64);
+        let list = UserAttribute::from(vec![1i64, 2]);
+
+        assert_eq!(single.as_single(), Some(&QueryValue::Integer(7)));
+        assert_eq!(single.values(), vec![QueryValue::Integer(7)]);
+        assert_eq!(list.as_single(), None);
+        assert_eq!(
+            list.values(),
+            vec![QueryValue::Integer(1), QueryValue::Integer(2)]
+        );
+    }
+
     #[test]
     fn test_security_enforcer_new() {
         let enforcer = SecurityEnforcer::new();
         assert_eq!(enforcer.resource_policies.len(), 0);
     }
 
+    #[test]
+    fn test_strict_mode_builder() {
+        let enforcer = SecurityEnforcer::new().with_strict_mode(true);
+        assert!(enforcer.is_strict());
+        assert!(!SecurityEnforcer::default().is_strict());
+    }
+
     #[test]
     fn test_add_policy() {
         let mut enforcer = SecurityEnforcer::new();
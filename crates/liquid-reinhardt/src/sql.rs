@@ -0,0 +1,519 @@
+// FR-08: ConvertedQuery → Parameterized SQL Generation
+
+use crate::converter::{
+    ConversionError, ConvertedQuery, QueryAggregation, QueryCondition, QueryMetric, QueryPage,
+    QuerySort, QueryValue,
+};
+use crate::dialect::{like_pattern, Dialect, PostgresDialect, TextMatch};
+use chrono::{DateTime, NaiveDate, Utc};
+use liquid_protocol::{AggregationType, NullsOrder, SortDirection};
+
+/// バインドパラメータ値
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlValue {
+    Text(String),
+    Integer(i64),
+    Real(f64),
+    Boolean(bool),
+    Date(NaiveDate),
+    DateTime(DateTime<Utc>),
+    Null,
+}
+
+impl From<&QueryValue> for SqlValue {
+    fn from(value: &QueryValue) -> Self {
+        match value {
+            QueryValue::String(s) => SqlValue::Text(s.clone()),
+            QueryValue::Integer(i) => SqlValue::Integer(*i),
+            QueryValue::Float(f) => SqlValue::Real(*f),
+            QueryValue::Boolean(b) => SqlValue::Boolean(*b),
+            QueryValue::Date(d) => SqlValue::Date(*d),
+            QueryValue::DateTime(dt) => SqlValue::DateTime(*dt),
+            QueryValue::Null => SqlValue::Null,
+        }
+    }
+}
+
+/// 生成されたSQLとバインドパラメータ
+///
+/// 値は必ず`params`側に格納され、SQL文字列にはプレースホルダーのみが含まれる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqlQuery {
+    sql: String,
+    params: Vec<SqlValue>,
+}
+
+impl SqlQuery {
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    pub fn params(&self) -> &[SqlValue] {
+        &self.params
+    }
+}
+
+/// ConvertedQuery → SQL Builder
+///
+/// デフォルトはPostgreSQLダイアレクト (`$1, $2, ...`の番号付きプレースホルダー)。
+pub struct SqlBuilder {
+    dialect: Box<dyn Dialect>,
+}
+
+impl SqlBuilder {
+    pub fn new() -> Self {
+        Self::with_dialect(PostgresDialect)
+    }
+
+    /// ダイアレクトを指定して生成
+    pub fn with_dialect(dialect: impl Dialect + 'static) -> Self {
+        Self {
+            dialect: Box::new(dialect),
+        }
+    }
+
+    pub fn dialect(&self) -> &dyn Dialect {
+        self.dialect.as_ref()
+    }
+
+    /// ConvertedQueryをパラメータ化SQLに変換
+    pub fn build(&self, query: &ConvertedQuery) -> Result<SqlQuery, ConversionError> {
+        let mut writer = SqlWriter::new(self.dialect.as_ref());
+
+        let select = match query.aggregation() {
+            Some(aggregation) => self.build_aggregate_select(aggregation)?,
+            None => "*".to_string(),
+        };
+        let mut sql = format!(
+            "SELECT {} FROM {}",
+            select,
+            self.quote_identifier(query.resource())?
+        );
+
+        // WHERE句 (カーソル指定時はキーセット条件を追加)
+        let (offset, keyset) = match query.page() {
+            None => (None, None),
+            Some(QueryPage::Offset(offset)) => (Some(*offset), None),
+            Some(QueryPage::After(values)) => {
+                (None, Some(self.keyset_condition(query.sort(), values)?))
+            }
+            Some(QueryPage::Cursor(_)) => {
+                return Err(ConversionError::new(
+                    "UNVERIFIED_CURSOR",
+                    "cursor must be verified with a CursorCodec before building SQL",
+                ))
+            }
+        };
+        let mut clauses = Vec::new();
+        for condition in query.all_conditions().chain(&keyset) {
+            clauses.push(self.build_condition(condition, &mut writer)?);
+        }
+        if !clauses.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&clauses.join(" AND "));
+        }
+
+        // GROUP BY句
+        if let Some(aggregation) = query.aggregation() {
+            if !aggregation.group_by().is_empty() {
+                let columns = aggregation
+                    .group_by()
+                    .iter()
+                    .map(|field| self.group_expression(aggregation, field))
+                    .collect::<Result<Vec<_>, _>>()?;
+                sql.push_str(&format!(" GROUP BY {}", columns.join(", ")));
+            }
+        }
+
+        // ORDER BY句 (キーの順に優先)
+        if !query.sort().is_empty() {
+            let keys = query
+                .sort()
+                .iter()
+                .map(|sort| {
+                    let direction = match sort.direction() {
+                        SortDirection::Asc => "ASC",
+                        SortDirection::Desc => "DESC",
+                    };
+                    Ok(self.dialect.order_key(
+                        &self.quote_identifier(sort.field())?,
+                        direction,
+                        sort.nulls(),
+                    ))
+                })
+                .collect::<Result<Vec<_>, ConversionError>>()?;
+            sql.push_str(&format!(" ORDER BY {}", keys.join(", ")));
+        }
+
+        // LIMIT / OFFSET句 (usizeのため直接埋め込んでも安全)
+        if let Some(clause) = self.dialect.limit_offset(query.limit(), offset) {
+            sql.push(' ');
+            sql.push_str(&clause);
+        }
+
+        Ok(SqlQuery {
+            sql,
+            params: writer.params,
+        })
+    }
+
+    /// 識別子を検証してダイアレクトに応じてクォート
+    fn quote_identifier(&self, name: &str) -> Result<String, ConversionError> {
+        validate_identifier(name)?;
+        Ok(self.dialect.quote_identifier(name))
+    }
+
+    /// 集計時のSELECT句を生成 (グループ化フィールド、集計値の順)
+    fn build_aggregate_select(
+        &self,
+        aggregation: &QueryAggregation,
+    ) -> Result<String, ConversionError> {
+        let mut columns = Vec::new();
+        for field in aggregation.group_by() {
+            let column = self.quote_identifier(field)?;
+            let expression = self.group_expression(aggregation, field)?;
+            if expression == column {
+                columns.push(column);
+            } else {
+                // 期間で切り詰めた値は元のフィールド名で返す
+                columns.push(format!("{} AS {}", expression, column));
+            }
+        }
+        for metric in aggregation.metrics() {
+            columns.push(self.build_metric(metric)?);
+        }
+        Ok(columns.join(", "))
+    }
+
+    /// グループ化に使う式 (期間指定のあるフィールドは切り詰め式)
+    fn group_expression(
+        &self,
+        aggregation: &QueryAggregation,
+        field: &str,
+    ) -> Result<String, ConversionError> {
+        let column = self.quote_identifier(field)?;
+        let Some(bucket) = aggregation
+            .bucket()
+            .filter(|bucket| bucket.field() == field)
+        else {
+            return Ok(column);
+        };
+        let timezone = bucket.timezone().map(|timezone| timezone.name());
+        self.dialect
+            .time_bucket(&column, bucket.unit(), timezone)
+            .ok_or_else(|| {
+                ConversionError::new(
+                    "UNSUPPORTED_TIME_BUCKET",
+                    format!(
+                        "{} cannot bucket '{}' in timezone {}",
+                        self.dialect.name(),
+                        field,
+                        timezone.unwrap_or("UTC")
+                    ),
+                )
+            })
+    }
+
+    /// 集計値を`FUNC(field) AS alias`に変換
+    fn build_metric(&self, metric: &QueryMetric) -> Result<String, ConversionError> {
+        let column = self.quote_identifier(metric.field())?;
+        let expression = match metric.agg_type() {
+            AggregationType::Sum => format!("SUM({})", column),
+            AggregationType::Avg => format!("AVG({})", column),
+            AggregationType::Count => format!("COUNT({})", column),
+            AggregationType::Min => format!("MIN({})", column),
+            AggregationType::Max => format!("MAX({})", column),
+            AggregationType::CountDistinct => format!("COUNT(DISTINCT {})", column),
+            AggregationType::Median | AggregationType::Percentile(_) => {
+                let fraction = metric.agg_type().percentile_fraction().unwrap_or(0.5);
+                self.dialect.percentile(&column, fraction).ok_or_else(|| {
+                    ConversionError::new(
+                        "UNSUPPORTED_AGGREGATION",
+                        format!(
+                            "{} does not support {} aggregation",
+                            self.dialect.name(),
+                            metric.agg_type().as_str()
+                        ),
+                    )
+                })?
+            }
+        };
+        Ok(format!(
+            "{} AS {}",
+            expression,
+            self.quote_identifier(metric.alias())?
+        ))
+    }
+
+    /// キーセットページングの条件 (ORDER BYの並びで前ページ最終行より後の行)
+    ///
+    /// `k1 > v1 OR (k1 = v1 AND k2 > v2) OR ...` の形 (降順のキーは`<`)。
+    /// NULLはORDER BYと同じ位置 (未指定ならダイアレクトの既定) にあるものとして比較する。
+    fn keyset_condition(
+        &self,
+        sort: &[QuerySort],
+        values: &[QueryValue],
+    ) -> Result<QueryCondition, ConversionError> {
+        if sort.is_empty() || sort.len() != values.len() {
+            return Err(ConversionError::new(
+                "INVALID_CURSOR",
+                "cursor does not match the sort keys of the query",
+            ));
+        }
+
+        let mut branches = Vec::new();
+        let mut equal = Vec::new();
+        for (key, value) in sort.iter().zip(values) {
+            let field = key.field().to_string();
+            let nulls = key
+                .nulls()
+                .unwrap_or_else(|| self.dialect.default_nulls(key.direction()));
+
+            // このキーで境界より後ろにある行 (NULLが末尾ならNULLの行も含む)
+            let after = match (value, nulls) {
+                (QueryValue::Null, NullsOrder::First) => Some(QueryCondition::IsNotNull {
+                    field: field.clone(),
+                }),
+                (QueryValue::Null, NullsOrder::Last) => None,
+                (value, nulls) => {
+                    let value = value.clone();
+                    let beyond = match key.direction() {
+                        SortDirection::Asc => QueryCondition::Gt {
+                            field: field.clone(),
+                            value,
+                        },
+                        SortDirection::Desc => QueryCondition::Lt {
+                            field: field.clone(),
+                            value,
+                        },
+                    };
+                    Some(match nulls {
+                        NullsOrder::First => beyond,
+                        NullsOrder::Last => QueryCondition::Or {
+                            conditions: vec![
+                                beyond,
+                                QueryCondition::IsNull {
+                                    field: field.clone(),
+                                },
+                            ],
+                        },
+                    })
+                }
+            };
+            if let Some(after) = after {
+                let mut conditions = equal.clone();
+                conditions.push(after);
+                branches.push(QueryCondition::And { conditions });
+            }
+
+            equal.push(match value {
+                QueryValue::Null => QueryCondition::IsNull { field },
+                value => QueryCondition::Eq {
+                    field,
+                    value: value.clone(),
+                },
+            });
+        }
+
+        Ok(QueryCondition::Or {
+            conditions: branches,
+        })
+    }
+
+    /// 個別条件をSQL断片に変換
+    fn build_condition(
+        &self,
+        condition: &QueryCondition,
+        writer: &mut SqlWriter,
+    ) -> Result<String, ConversionError> {
+        // グループは括弧で囲んで再帰的に変換 (空のANDは常に真、空のORは常に偽)
+        match condition {
+            QueryCondition::And { conditions } => {
+                return self.build_group(conditions, " AND ", "1 = 1", writer)
+            }
+            QueryCondition::Or { conditions } => {
+                return self.build_group(conditions, " OR ", "1 = 0", writer)
+            }
+            QueryCondition::Not { condition } => {
+                return Ok(format!(
+                    "NOT ({})",
+                    self.build_condition(condition, writer)?
+                ))
+            }
+            _ => {}
+        }
+
+        let column = self.quote_identifier(condition.field())?;
+
+        let clause = match condition {
+            QueryCondition::Eq { value, .. } => {
+                format!("{} = {}", column, writer.bind(value.into()))
+            }
+            QueryCondition::Neq { value, .. } => {
+                format!("{} <> {}", column, writer.bind(value.into()))
+            }
+            QueryCondition::Gt { value, .. } => {
+                format!("{} > {}", column, writer.bind(value.into()))
+            }
+            QueryCondition::Gte { value, .. } => {
+                format!("{} >= {}", column, writer.bind(value.into()))
+            }
+            QueryCondition::Lt { value, .. } => {
+                format!("{} < {}", column, writer.bind(value.into()))
+            }
+            QueryCondition::Lte { value, .. } => {
+                format!("{} <= {}", column, writer.bind(value.into()))
+            }
+            QueryCondition::In { values, .. } => {
+                // 空のIN句は構文エラーになるため、常に偽となる条件に置き換える
+                if values.is_empty() {
+                    return Ok("1 = 0".to_string());
+                }
+                let placeholders: Vec<String> =
+                    values.iter().map(|v| writer.bind(v.into())).collect();
+                format!("{} IN ({})", column, placeholders.join(", "))
+            }
+            QueryCondition::NotIn { values, .. } => {
+                // 空のNOT IN句は常に真となる条件に置き換える
+                if values.is_empty() {
+                    return Ok("1 = 1".to_string());
+                }
+                let placeholders: Vec<String> =
+                    values.iter().map(|v| writer.bind(v.into())).collect();
+                format!("{} NOT IN ({})", column, placeholders.join(", "))
+            }
+            QueryCondition::Between { low, high, .. } => {
+                let low = writer.bind(low.into());
+                let high = writer.bind(high.into());
+                format!("{} BETWEEN {} AND {}", column, low, high)
+            }
+            QueryCondition::IsNull { .. } => format!("{} IS NULL", column),
+            QueryCondition::IsNotNull { .. } => format!("{} IS NOT NULL", column),
+            QueryCondition::Contains { value, .. } => {
+                self.case_sensitive_match(&column, value, TextMatch::Contains, writer)
+            }
+            QueryCondition::StartsWith { value, .. } => {
+                self.case_sensitive_match(&column, value, TextMatch::StartsWith, writer)
+            }
+            QueryCondition::EndsWith { value, .. } => {
+                self.case_sensitive_match(&column, value, TextMatch::EndsWith, writer)
+            }
+            QueryCondition::IContains { value, .. } => {
+                let pattern = like_pattern(value, TextMatch::Contains);
+                let placeholder = writer.bind(SqlValue::Text(pattern));
+                self.dialect.case_insensitive_like(&column, &placeholder)
+            }
+            QueryCondition::And { .. } | QueryCondition::Or { .. } | QueryCondition::Not { .. } => {
+                unreachable!("condition groups are handled above")
+            }
+        };
+
+        Ok(clause)
+    }
+
+    /// 大文字小文字を区別するパターン一致をSQL断片に変換
+    fn case_sensitive_match(
+        &self,
+        column: &str,
+        value: &str,
+        kind: TextMatch,
+        writer: &mut SqlWriter,
+    ) -> String {
+        let pattern = self.dialect.case_sensitive_pattern(value, kind);
+        let placeholder = writer.bind(SqlValue::Text(pattern));
+        self.dialect.case_sensitive_like(column, &placeholder)
+    }
+
+    /// 条件グループを括弧付きのSQL断片に変換
+    fn build_group(
+        &self,
+        conditions: &[QueryCondition],
+        separator: &str,
+        empty: &str,
+        writer: &mut SqlWriter,
+    ) -> Result<String, ConversionError> {
+        if conditions.is_empty() {
+            return Ok(empty.to_string());
+        }
+        let clauses = conditions
+            .iter()
+            .map(|condition| self.build_condition(condition, writer))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(format!("({})", clauses.join(separator)))
+    }
+}
+
+impl Default for SqlBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// バインドパラメータの蓄積
+struct SqlWriter<'a> {
+    dialect: &'a dyn Dialect,
+    params: Vec<SqlValue>,
+}
+
+impl<'a> SqlWriter<'a> {
+    fn new(dialect: &'a dyn Dialect) -> Self {
+        Self {
+            dialect,
+            params: Vec::new(),
+        }
+    }
+
+    /// 値を登録してプレースホルダーを返す
+    fn bind(&mut self, value: SqlValue) -> String {
+        self.params.push(value);
+        self.dialect.placeholder(self.params.len())
+    }
+}
+
+/// 識別子を検証
+///
+/// テーブル名・カラム名はAI出力由来のため、英数字とアンダースコア以外を拒否する。
+fn validate_identifier(name: &str) -> Result<(), ConversionError> {
+    let mut chars = name.chars();
+    let valid = match chars.next() {
+        Some(first) => {
+            (first.is_ascii_alphabetic() || first == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        None => false,
+    };
+
+    if !valid {
+        return Err(ConversionError::new(
+            "INVALID_IDENTIFIER",
+            format!("Invalid identifier: {:?}", name),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_identifier() {
+        assert!(validate_identifier("user_id").is_ok());
+        assert!(validate_identifier("_private").is_ok());
+    }
+
+    #[test]
+    fn test_validate_identifier_rejects_invalid() {
+        for name in ["", "1abc", "users; DROP TABLE x", "a\"b", "a`b", "a.b"] {
+            let err = validate_identifier(name).unwrap_err();
+            assert_eq!(err.code(), "INVALID_IDENTIFIER");
+        }
+    }
+
+    #[test]
+    fn test_sql_builder_default_is_postgres() {
+        let builder = SqlBuilder::default();
+        assert_eq!(builder.dialect().name(), "postgres");
+    }
+}
@@ -0,0 +1,333 @@
+// FR-09: Embedded SQLite Executor (feature = "sqlite")
+
+use crate::bucket::fill_empty_buckets;
+use crate::converter::{ConversionError, ConvertedQuery, DataSourceConverter, QueryPage};
+use crate::dialect::SqliteDialect;
+use crate::pagination::{CursorCodec, PageResult};
+use crate::security::{CurrentUser, SecurityEnforcer};
+use crate::sql::{SqlBuilder, SqlValue};
+use liquid_protocol::{DataSource, LiquidViewSchema};
+use rusqlite::functions::{Aggregate, Context, FunctionFlags};
+use rusqlite::types::{Value as SqliteValue, ValueRef};
+use rusqlite::Connection;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// ConvertedQueryをSQLiteで実行するExecutor
+///
+/// 実行前に必ず`SecurityEnforcer::enforce`を適用し、結果行をJSONオブジェクトで返す。
+/// 結果行には読み取り不可フィールドの除去とマスクを適用する。
+pub struct SqliteExecutor {
+    connection: Connection,
+    enforcer: SecurityEnforcer,
+    converter: DataSourceConverter,
+    builder: SqlBuilder,
+    cursors: Option<CursorCodec>,
+}
+
+impl SqliteExecutor {
+    pub fn new(
+        connection: Connection,
+        enforcer: SecurityEnforcer,
+    ) -> Result<Self, ConversionError> {
+        // 中央値・パーセンタイル集計 (SqliteDialect::percentile) のための集計関数
+        connection
+            .create_aggregate_function(
+                "liquid_percentile",
+                2,
+                FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+                Percentile,
+            )
+            .map_err(execution_error)?;
+        Ok(Self {
+            connection,
+            enforcer,
+            converter: DataSourceConverter::new(),
+            builder: SqlBuilder::with_dialect(SqliteDialect),
+            cursors: None,
+        })
+    }
+
+    /// DataSource変換に使うConverterを指定 (リソースレジストリの適用など)
+    pub fn with_converter(mut self, converter: DataSourceConverter) -> Self {
+        self.converter = converter;
+        self
+    }
+
+    /// カーソルページングに使う署名鍵を指定 (未指定の場合はカーソルを発行・受理しない)
+    pub fn with_cursor_codec(mut self, codec: CursorCodec) -> Self {
+        self.cursors = Some(codec);
+        self
+    }
+
+    /// インメモリDBで生成 (テスト用)
+    pub fn open_in_memory(enforcer: SecurityEnforcer) -> Result<Self, ConversionError> {
+        let connection = Connection::open_in_memory().map_err(execution_error)?;
+        Self::new(connection, enforcer)
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    /// RLSを適用してクエリを実行
+    pub fn execute(
+        &self,
+        query: &ConvertedQuery,
+        user: &CurrentUser,
+    ) -> Result<Vec<Value>, ConversionError> {
+        let query = self.prepare(query, user)?;
+        let mut rows = self.fetch(&query)?;
+        self.enforcer.mask_rows(query.resource(), &mut rows, user);
+        fill_empty_buckets(&query, &mut rows);
+        Ok(rows)
+    }
+
+    /// RLSを適用してクエリの1ページを実行し、次ページの位置を返す
+    ///
+    /// 次ページの有無はページサイズより1行多く取得して判定する。
+    /// カーソルの値はクライアントから読めるが、読み取り不可・マスク対象のフィールドでの
+    /// ソートはRLS適用時に拒否されるため、ソートキーの値はユーザーに見えるものに限られる。
+    pub fn execute_page(
+        &self,
+        query: &ConvertedQuery,
+        user: &CurrentUser,
+    ) -> Result<PageResult, ConversionError> {
+        let mut query = self.prepare(query, user)?;
+        let limit = query.limit();
+        if let Some(limit) = limit {
+            query.set_limit(limit + 1);
+        }
+
+        let mut rows = self.fetch(&query)?;
+        let has_more = limit.is_some_and(|limit| rows.len() > limit);
+        if let Some(limit) = limit {
+            rows.truncate(limit);
+        }
+
+        let next_offset = match query.page() {
+            _ if !has_more => None,
+            Some(QueryPage::Offset(offset)) => Some(offset + rows.len()),
+            None => Some(rows.len()),
+            Some(_) => None,
+        };
+        let next_cursor = match (&self.cursors, rows.last()) {
+            (Some(codec), Some(last)) if has_more && query.supports_cursor() => {
+                Some(codec.encode(&query, last)?)
+            }
+            _ => None,
+        };
+
+        self.enforcer.mask_rows(query.resource(), &mut rows, user);
+        fill_empty_buckets(&query, &mut rows);
+        Ok(PageResult::new(rows, next_offset, next_cursor))
+    }
+
+    /// DataSourceを変換して実行
+    pub fn execute_data_source(
+        &self,
+        ds: &DataSource,
+        user: &CurrentUser,
+    ) -> Result<Vec<Value>, ConversionError> {
+        let query = self.converter.convert(ds)?;
+        self.execute(&query, user)
+    }
+
+    /// DataSourceを変換して1ページを実行
+    pub fn execute_data_source_page(
+        &self,
+        ds: &DataSource,
+        user: &CurrentUser,
+    ) -> Result<PageResult, ConversionError> {
+        let query = self.converter.convert(ds)?;
+        self.execute_page(&query, user)
+    }
+
+    /// スキーマ内の全DataSourceを実行
+    pub fn execute_schema(
+        &self,
+        schema: &LiquidViewSchema,
+        user: &CurrentUser,
+    ) -> Result<HashMap<String, Vec<Value>>, ConversionError> {
+        self.enforcer.check_schema(schema, user)?;
+
+        let mut results = HashMap::with_capacity(schema.data_sources.len());
+        for (key, ds) in &schema.data_sources {
+            results.insert(key.clone(), self.execute_data_source(ds, user)?);
+        }
+        Ok(results)
+    }
+
+    /// RLSを適用し、カーソルを検証済みの位置に置き換える
+    fn prepare(
+        &self,
+        query: &ConvertedQuery,
+        user: &CurrentUser,
+    ) -> Result<ConvertedQuery, ConversionError> {
+        let mut query = query.clone();
+        self.enforcer.enforce(&mut query, user)?;
+        // 署名はRLS適用後の条件に対して検証するため、別ユーザーのカーソルは受理しない
+        if let Some(QueryPage::Cursor(_)) = query.page() {
+            self.cursors
+                .as_ref()
+                .ok_or_else(|| {
+                    ConversionError::new("INVALID_CURSOR", "cursor pagination is not enabled")
+                })?
+                .resolve(&mut query, self.converter.registry())?;
+        }
+        Ok(query)
+    }
+
+    /// SQLを生成して実行し、結果行をJSONオブジェクトで返す
+    fn fetch(&self, query: &ConvertedQuery) -> Result<Vec<Value>, ConversionError> {
+        let sql = self.builder.build(query)?;
+        let mut statement = self
+            .connection
+            .prepare(sql.sql())
+            .map_err(execution_error)?;
+        let columns: Vec<String> = statement
+            .column_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        let params = rusqlite::params_from_iter(sql.params().iter().map(to_sqlite_value));
+        let rows = statement
+            .query_map(params, |row| {
+                let mut object = Map::with_capacity(columns.len());
+                for (index, column) in columns.iter().enumerate() {
+                    object.insert(column.clone(), to_json_value(row.get_ref(index)?));
+                }
+                Ok(Value::Object(object))
+            })
+            .map_err(execution_error)?;
+
+        rows.collect::<Result<Vec<_>, _>>().map_err(execution_error)
+    }
+}
+
+/// `liquid_percentile(value, fraction)`集計関数
+///
+/// PostgreSQLの`percentile_cont`と同じく、NULLを除いた値を並べて線形補間する。
+/// 値が1件もない場合はNULL。
+struct Percentile;
+
+impl Aggregate<(Vec<f64>, f64), Option<f64>> for Percentile {
+    fn init(&self, _ctx: &mut Context<'_>) -> rusqlite::Result<(Vec<f64>, f64)> {
+        Ok((Vec::new(), 0.5))
+    }
+
+    fn step(&self, ctx: &mut Context<'_>, acc: &mut (Vec<f64>, f64)) -> rusqlite::Result<()> {
+        if let Some(value) = ctx.get::<Option<f64>>(0)? {
+            acc.0.push(value);
+        }
+        acc.1 = ctx.get::<f64>(1)?;
+        Ok(())
+    }
+
+    fn finalize(
+        &self,
+        _ctx: &mut Context<'_>,
+        acc: Option<(Vec<f64>, f64)>,
+    ) -> rusqlite::Result<Option<f64>> {
+        let Some((mut values, fraction)) = acc.filter(|(values, _)| !values.is_empty()) else {
+            return Ok(None);
+        };
+        values.sort_by(f64::total_cmp);
+        let rank = fraction.clamp(0.0, 1.0) * (values.len() - 1) as f64;
+        let (lower, upper) = (values[rank.floor() as usize], values[rank.ceil() as usize]);
+        Ok(Some(lower + (upper - lower) * rank.fract()))
+    }
+}
+
+/// バインドパラメータをSQLite値に変換
+fn to_sqlite_value(value: &SqlValue) -> SqliteValue {
+    match value {
+        SqlValue::Text(s) => SqliteValue::Text(s.clone()),
+        SqlValue::Integer(i) => SqliteValue::Integer(*i),
+        SqlValue::Real(n) => SqliteValue::Real(*n),
+        SqlValue::Boolean(b) => SqliteValue::Integer(i64::from(*b)),
+        // SQLiteには日付型がないため、辞書順で比較できる文字列で保存されている前提。
+        // 日付は`YYYY-MM-DD`、日時はSQLiteの日付関数・CURRENT_TIMESTAMPと同じUTCの
+        // `YYYY-MM-DD HH:MM:SS` (結果の値は`parse_datetime`で読み戻せる)
+        SqlValue::Date(d) => SqliteValue::Text(d.format("%Y-%m-%d").to_string()),
+        SqlValue::DateTime(dt) => SqliteValue::Text(dt.format("%Y-%m-%d %H:%M:%S").to_string()),
+        SqlValue::Null => SqliteValue::Null,
+    }
+}
+
+/// SQLite値をJSON値に変換
+fn to_json_value(value: ValueRef<'_>) -> Value {
+    match value {
+        ValueRef::Null => Value::Null,
+        ValueRef::Integer(i) => Value::from(i),
+        ValueRef::Real(f) => Value::from(f),
+        ValueRef::Text(bytes) => Value::String(String::from_utf8_lossy(bytes).into_owned()),
+        ValueRef::Blob(bytes) => Value::from(bytes.to_vec()),
+    }
+}
+
+/// SQLiteエラーを変換エラーに変換
+fn execution_error(err: rusqlite::Error) -> ConversionError {
+    ConversionError::new("EXECUTION_ERROR", err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json_value() {
+        assert_eq!(to_json_value(ValueRef::Null), Value::Null);
+        assert_eq!(to_json_value(ValueRef::Integer(3)), Value::from(3));
+        assert_eq!(to_json_value(ValueRef::Real(1.5)), Value::from(1.5));
+        assert_eq!(to_json_value(ValueRef::Text(b"abc")), Value::from("abc"));
+    }
+
+    #[test]
+    fn test_to_sqlite_value_typed() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(
+            to_sqlite_value(&SqlValue::Boolean(true)),
+            SqliteValue::Integer(1)
+        );
+        assert_eq!(
+            to_sqlite_value(&SqlValue::Date(date)),
+            SqliteValue::Text("2024-03-01".to_string())
+        );
+        assert_eq!(
+            to_sqlite_value(&SqlValue::DateTime(
+                date.and_hms_opt(9, 0, 0).unwrap().and_utc()
+            )),
+            SqliteValue::Text("2024-03-01 09:00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn test_percentile_interpolates() {
+        let executor = SqliteExecutor::open_in_memory(SecurityEnforcer::new()).unwrap();
+        let percentile = |fraction: f64| -> Option<f64> {
+            executor
+                .connection()
+                .query_row(
+                    "SELECT liquid_percentile(value, ?1) FROM \
+                     (SELECT 1 AS value UNION ALL SELECT 4 UNION ALL SELECT NULL \
+                      UNION ALL SELECT 2 UNION ALL SELECT 3)",
+                    [fraction],
+                    |row| row.get(0),
+                )
+                .unwrap()
+        };
+
+        assert_eq!(percentile(0.5), Some(2.5));
+        assert_eq!(percentile(0.9), Some(3.7));
+        assert_eq!(percentile(0.0), Some(1.0));
+        assert_eq!(percentile(1.0), Some(4.0));
+    }
+
+    #[test]
+    fn test_execution_error_code() {
+        let err = execution_error(rusqlite::Error::InvalidQuery);
+        assert_eq!(err.code(), "EXECUTION_ERROR");
+    }
+}
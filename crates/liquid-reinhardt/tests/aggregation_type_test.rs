@@ -0,0 +1,147 @@
+// FR-06: Distinct Count / Median / Percentile Aggregation Tests
+
+use liquid_protocol::{AggregationType, DataSource, Metric};
+use liquid_reinhardt::converter::DataSourceConverter;
+use liquid_reinhardt::dialect::{Dialect, MySqlDialect, PostgresDialect, SqliteDialect};
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use liquid_reinhardt::sql::SqlBuilder;
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a converter whose `expenses` resource declares typed fields
+fn create_converter() -> DataSourceConverter {
+    DataSourceConverter::with_registry(
+        ResourceRegistry::new().with_resource(
+            ResourceDefinition::new("expenses")
+                .field("amount", FieldType::Float)
+                .field("category", FieldType::String)
+                .field("user_name", FieldType::String),
+        ),
+    )
+}
+
+/// Creates an `expenses` DataSource with one metric per category
+fn create_data_source(agg_type: AggregationType, field: &str) -> DataSource {
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: None,
+        aggregation: None,
+        metrics: Some(vec![Metric {
+            alias: "value".to_string(),
+            agg_type,
+            field: field.to_string(),
+        }]),
+        group_by: Some(vec!["category".to_string()]),
+        sort: None,
+        limit: None,
+        page: None,
+    }
+}
+
+/// Builds the SQL for a single metric with the given dialect
+fn build_with(dialect: impl Dialect + 'static, agg_type: AggregationType) -> String {
+    let query = create_converter()
+        .convert(&create_data_source(agg_type, "amount"))
+        .unwrap();
+    SqlBuilder::with_dialect(dialect)
+        .build(&query)
+        .unwrap()
+        .sql()
+        .to_string()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_count_distinct_sql() {
+    let query = create_converter()
+        .convert(&create_data_source(
+            AggregationType::CountDistinct,
+            "user_name",
+        ))
+        .unwrap();
+
+    let sql = SqlBuilder::with_dialect(MySqlDialect)
+        .build(&query)
+        .unwrap();
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT `category`, COUNT(DISTINCT `user_name`) AS `value` FROM `expenses` GROUP BY `category`"
+    );
+}
+
+#[test]
+fn test_postgres_percentile_sql() {
+    assert_eq!(
+        build_with(PostgresDialect, AggregationType::Median),
+        "SELECT \"category\", percentile_cont(0.5) WITHIN GROUP (ORDER BY \"amount\") AS \"value\" \
+         FROM \"expenses\" GROUP BY \"category\""
+    );
+    assert_eq!(
+        build_with(PostgresDialect, AggregationType::Percentile(95.0)),
+        "SELECT \"category\", percentile_cont(0.95) WITHIN GROUP (ORDER BY \"amount\") AS \"value\" \
+         FROM \"expenses\" GROUP BY \"category\""
+    );
+}
+
+#[test]
+fn test_sqlite_percentile_uses_registered_function() {
+    assert_eq!(
+        build_with(SqliteDialect, AggregationType::Percentile(90.0)),
+        "SELECT \"category\", liquid_percentile(\"amount\", 0.9) AS \"value\" \
+         FROM \"expenses\" GROUP BY \"category\""
+    );
+}
+
+#[test]
+fn test_mysql_rejects_percentile() {
+    let query = create_converter()
+        .convert(&create_data_source(AggregationType::Median, "amount"))
+        .unwrap();
+
+    let err = SqlBuilder::with_dialect(MySqlDialect)
+        .build(&query)
+        .unwrap_err();
+
+    assert_eq!(err.code(), "UNSUPPORTED_AGGREGATION");
+}
+
+#[test]
+fn test_invalid_percentiles_are_rejected() {
+    let converter = create_converter();
+    let cases = [
+        (
+            create_data_source(AggregationType::Median, "user_name"),
+            "AGGREGATION_TYPE_MISMATCH",
+        ),
+        (
+            create_data_source(AggregationType::Percentile(101.0), "amount"),
+            "INVALID_PERCENTILE",
+        ),
+        (
+            create_data_source(AggregationType::Percentile(-1.0), "amount"),
+            "INVALID_PERCENTILE",
+        ),
+    ];
+
+    for (ds, code) in cases {
+        assert_eq!(converter.convert(&ds).unwrap_err().code(), code);
+    }
+}
+
+#[test]
+fn test_count_distinct_accepts_any_field_type() {
+    let converter = create_converter();
+
+    assert!(converter
+        .convert(&create_data_source(
+            AggregationType::CountDistinct,
+            "category"
+        ))
+        .is_ok());
+}
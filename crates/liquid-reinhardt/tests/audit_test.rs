@@ -0,0 +1,237 @@
+// FR-13: Policy Decision Audit Log Tests
+
+use liquid_reinhardt::audit::{
+    AuditDecision, AuditEvent, AuditSink, InMemoryAuditSink, JsonLinesAuditSink,
+};
+use liquid_reinhardt::converter::{ConversionError, ConvertedQuery, QueryCondition, QueryValue};
+use liquid_reinhardt::field_security::FieldPolicy;
+use liquid_reinhardt::security::{
+    CurrentUser, SecurityEnforcer, SecurityPolicy, DEFAULT_POLICY_NAME, PUBLIC_POLICY_NAME,
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a CurrentUser with the given ID and permission strings
+fn create_user(id: u64, permissions: &[&str]) -> CurrentUser {
+    CurrentUser::new(id, permissions.iter().map(|s| s.to_string()).collect())
+}
+
+/// Creates an enforcer recording into the returned in-memory sink
+fn create_audited_enforcer() -> (SecurityEnforcer, Arc<InMemoryAuditSink>) {
+    let sink = Arc::new(InMemoryAuditSink::new());
+    let mut enforcer = SecurityEnforcer::new().with_audit_sink(sink.clone());
+    enforcer.add_default_policy(SecurityPolicy::new("logged_in", |_, _| true));
+    enforcer.add_policy_for_resource("expenses", SecurityPolicy::custom_field("owner_id"));
+    enforcer.add_policy_for_resource(
+        "expenses",
+        SecurityPolicy::new("expenses_reader", |user, _| {
+            user.has_permission("read:expenses")
+        }),
+    );
+    enforcer.allow_public_resource("exchange_rates");
+    (enforcer, sink)
+}
+
+/// Sink that always fails
+struct FailingSink;
+
+impl AuditSink for FailingSink {
+    fn record(&self, _event: &AuditEvent) -> Result<(), ConversionError> {
+        Err(ConversionError::new("AUDIT_ERROR", "disk full"))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_allowed_query_is_audited() {
+    let (enforcer, sink) = create_audited_enforcer();
+    let mut query = ConvertedQuery::new("expenses".to_string());
+
+    enforcer
+        .enforce(&mut query, &create_user(42, &["read:expenses"]))
+        .unwrap();
+
+    let events = sink.events();
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.user_id, 42);
+    assert_eq!(event.resource, "expenses");
+    assert_eq!(
+        event.policies,
+        vec!["logged_in", "custom_field_owner_id", "expenses_reader"]
+    );
+    assert_eq!(event.decision, AuditDecision::Allow);
+    assert_eq!(event.error_code, None);
+    assert_eq!(
+        event.injected_conditions,
+        vec![QueryCondition::Eq {
+            field: "owner_id".to_string(),
+            value: QueryValue::Integer(42),
+        }]
+    );
+}
+
+#[test]
+fn test_denied_query_is_audited() {
+    let (enforcer, sink) = create_audited_enforcer();
+    let mut query = ConvertedQuery::new("expenses".to_string());
+
+    let err = enforcer
+        .enforce(&mut query, &create_user(42, &[]))
+        .unwrap_err();
+
+    let event = &sink.events()[0];
+    assert_eq!(event.decision, AuditDecision::Deny);
+    assert_eq!(event.error_code.as_deref(), Some("ACCESS_DENIED"));
+    assert_eq!(event.reason.as_deref(), Some(err.message()));
+    assert!(event.injected_conditions.is_empty());
+}
+
+#[test]
+fn test_builtin_policies_are_named() {
+    let (enforcer, sink) = create_audited_enforcer();
+
+    enforcer
+        .enforce(
+            &mut ConvertedQuery::new("orders".to_string()),
+            &create_user(1, &[]),
+        )
+        .unwrap();
+    enforcer
+        .enforce(
+            &mut ConvertedQuery::new("exchange_rates".to_string()),
+            &create_user(1, &[]),
+        )
+        .unwrap();
+
+    let events = sink.events();
+    assert_eq!(events[0].policies, vec!["logged_in", DEFAULT_POLICY_NAME]);
+    assert_eq!(events[1].policies, vec!["logged_in", PUBLIC_POLICY_NAME]);
+    assert!(events[1].injected_conditions.is_empty());
+}
+
+#[test]
+fn test_strict_mode_denial_is_audited() {
+    let sink = Arc::new(InMemoryAuditSink::new());
+    let enforcer = SecurityEnforcer::new()
+        .with_strict_mode(true)
+        .with_audit_sink(sink.clone());
+
+    let result = enforcer.enforce(
+        &mut ConvertedQuery::new("payroll".to_string()),
+        &create_user(3, &[]),
+    );
+
+    assert!(result.is_err());
+    let event = &sink.events()[0];
+    assert_eq!(event.decision, AuditDecision::Deny);
+    assert!(event.policies.is_empty());
+}
+
+#[test]
+fn test_field_denial_is_audited() {
+    let (mut enforcer, sink) = create_audited_enforcer();
+    enforcer.set_field_policy(
+        "orders",
+        "margin",
+        FieldPolicy::new().require_read("finance"),
+    );
+    let mut query = ConvertedQuery::new("orders".to_string());
+    query.add_condition(QueryCondition::Gt {
+        field: "margin".to_string(),
+        value: QueryValue::Float(0.5),
+    });
+
+    assert!(enforcer.enforce(&mut query, &create_user(1, &[])).is_err());
+
+    assert_eq!(
+        sink.events()[0].error_code.as_deref(),
+        Some("FIELD_FILTER_DENIED")
+    );
+}
+
+#[test]
+fn test_sink_failure_denies_query() {
+    let enforcer = SecurityEnforcer::new().with_audit_sink(Arc::new(FailingSink));
+    let mut query = ConvertedQuery::new("orders".to_string());
+
+    let err = enforcer
+        .enforce(&mut query, &create_user(1, &[]))
+        .unwrap_err();
+
+    assert_eq!(err.code(), "AUDIT_ERROR");
+    assert!(query.conditions().is_empty());
+}
+
+#[test]
+fn test_in_memory_sink_clear() {
+    let (enforcer, sink) = create_audited_enforcer();
+    enforcer
+        .enforce(
+            &mut ConvertedQuery::new("orders".to_string()),
+            &create_user(1, &[]),
+        )
+        .unwrap();
+
+    sink.clear();
+
+    assert!(sink.events().is_empty());
+}
+
+#[test]
+fn test_json_lines_sink_appends_events() {
+    let path = std::env::temp_dir().join(format!("liquid-audit-{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let sink = Arc::new(JsonLinesAuditSink::open(&path).unwrap());
+    let enforcer = SecurityEnforcer::new().with_audit_sink(sink);
+
+    enforcer
+        .enforce(
+            &mut ConvertedQuery::new("orders".to_string()),
+            &create_user(5, &[]),
+        )
+        .unwrap();
+    enforcer
+        .enforce(
+            &mut ConvertedQuery::new("orders".to_string()),
+            &create_user(6, &[]),
+        )
+        .unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let lines: Vec<Value> = contents
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["user_id"], 5);
+    assert_eq!(lines[0]["resource"], "orders");
+    assert_eq!(lines[0]["policies"], json!([DEFAULT_POLICY_NAME]));
+    assert_eq!(lines[0]["decision"], "allow");
+    assert_eq!(
+        lines[0]["injected_conditions"],
+        json!([{ "op": "eq", "field": "user_id", "value": 5 }])
+    );
+    assert!(lines[0]["timestamp"].is_string());
+    assert_eq!(lines[1]["user_id"], 6);
+}
+
+#[test]
+fn test_json_lines_sink_open_error() {
+    let dir = std::env::temp_dir()
+        .join("liquid-audit-missing-dir")
+        .join("nested");
+
+    let err = JsonLinesAuditSink::open(dir.join("audit.jsonl")).unwrap_err();
+
+    assert_eq!(err.code(), "AUDIT_ERROR");
+}
@@ -1,16 +1,16 @@
 use liquid_protocol::{DataSource, Filter, FilterOperator, FilterValue};
-use liquid_reinhardt::converter::{DataSourceConverter, QueryCondition};
+use liquid_reinhardt::converter::{DataSourceConverter, QueryCondition, QueryValue};
 
-/// Coverage improvement tests
+// Coverage improvement tests
 
 #[test]
 fn test_query_condition_all_field_accessors() {
     // Test all QueryCondition variants' field() method
-    let conditions = vec![
-        QueryCondition::Neq { field: "f1".to_string(), value: "v".to_string() },
-        QueryCondition::Gte { field: "f2".to_string(), value: 10.0 },
-        QueryCondition::Lt { field: "f3".to_string(), value: 20.0 },
-        QueryCondition::Lte { field: "f4".to_string(), value: 30.0 },
+    let conditions = [
+        QueryCondition::Neq { field: "f1".to_string(), value: "v".into() },
+        QueryCondition::Gte { field: "f2".to_string(), value: 10.0.into() },
+        QueryCondition::Lt { field: "f3".to_string(), value: 20.0.into() },
+        QueryCondition::Lte { field: "f4".to_string(), value: 30.0.into() },
         QueryCondition::Contains { field: "f5".to_string(), value: "sub".to_string() },
     ];
 
@@ -39,10 +39,13 @@ fn test_error_on_number_expected() {
             field: "age".to_string(),
             op: FilterOperator::Gt,
             value: FilterValue::String("not_a_number".to_string()),
-        }]),
+        }.into()]),
         aggregation: None,
+        metrics: None,
+        group_by: None,
         sort: None,
         limit: None,
+        page: None,
     };
 
     let converter = DataSourceConverter::new();
@@ -60,10 +63,13 @@ fn test_error_on_array_expected_for_in() {
             field: "status".to_string(),
             op: FilterOperator::In,
             value: FilterValue::String("not_an_array".to_string()),
-        }]),
+        }.into()]),
         aggregation: None,
+        metrics: None,
+        group_by: None,
         sort: None,
         limit: None,
+        page: None,
     };
 
     let converter = DataSourceConverter::new();
@@ -81,10 +87,13 @@ fn test_convert_boolean_value() {
             field: "active".to_string(),
             op: FilterOperator::Eq,
             value: FilterValue::Boolean(true),
-        }]),
+        }.into()]),
         aggregation: None,
+        metrics: None,
+        group_by: None,
         sort: None,
         limit: None,
+        page: None,
     };
 
     let converter = DataSourceConverter::new();
@@ -93,7 +102,7 @@ fn test_convert_boolean_value() {
     let condition = &query.conditions()[0];
     match condition {
         QueryCondition::Eq { value, .. } => {
-            assert_eq!(value, "true");
+            assert_eq!(value, &QueryValue::Boolean(true));
         }
         _ => panic!("Expected Eq condition"),
     }
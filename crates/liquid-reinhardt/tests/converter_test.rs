@@ -1,8 +1,11 @@
 use liquid_protocol::FilterValueScalar;
-use liquid_protocol::{DataSource, Filter, FilterOperator, FilterValue};
-use liquid_reinhardt::converter::{DataSourceConverter, QueryCondition};
+use liquid_protocol::{
+    Aggregation, AggregationType, DataSource, Filter, FilterOperator, FilterValue, Sort,
+    SortDirection,
+};
+use liquid_reinhardt::converter::{DataSourceConverter, QueryCondition, QueryValue};
 
-/// FR-06: DataSource → ORM Converter Tests (TDD Red Phase)
+// FR-06: DataSource → ORM Converter Tests (TDD Red Phase)
 
 // ============================================================================
 // Test Helper Functions
@@ -12,10 +15,13 @@ use liquid_reinhardt::converter::{DataSourceConverter, QueryCondition};
 fn create_data_source_with_filter(resource: &str, filter: Filter) -> DataSource {
     DataSource {
         resource: resource.to_string(),
-        filters: Some(vec![filter]),
+        filters: Some(vec![filter.into()]),
         aggregation: None,
+        metrics: None,
+        group_by: None,
         sort: None,
         limit: None,
+        page: None,
     }
 }
 
@@ -25,8 +31,11 @@ fn create_simple_data_source(resource: &str) -> DataSource {
         resource: resource.to_string(),
         filters: None,
         aggregation: None,
+        metrics: None,
+        group_by: None,
         sort: None,
         limit: None,
+        page: None,
     }
 }
 
@@ -72,7 +81,7 @@ fn test_convert_with_eq_filter() {
     match condition {
         QueryCondition::Eq { field, value } => {
             assert_eq!(field, "category");
-            assert_eq!(value, "food");
+            assert_eq!(value, &QueryValue::from("food"));
         }
         _ => panic!("Expected Eq condition"),
     }
@@ -88,7 +97,7 @@ fn test_convert_with_neq_filter() {
     match condition {
         QueryCondition::Neq { field, value } => {
             assert_eq!(field, "category");
-            assert_eq!(value, "travel");
+            assert_eq!(value, &QueryValue::from("travel"));
         }
         _ => panic!("Expected Neq condition"),
     }
@@ -104,7 +113,7 @@ fn test_convert_with_gt_filter() {
     match condition {
         QueryCondition::Gt { field, value } => {
             assert_eq!(field, "age");
-            assert_eq!(value, &18.0);
+            assert_eq!(value, &QueryValue::Float(18.0));
         }
         _ => panic!("Expected Gt condition"),
     }
@@ -142,16 +151,19 @@ fn test_convert_with_multiple_filters() {
                 field: "status".to_string(),
                 op: FilterOperator::Eq,
                 value: FilterValue::String("completed".to_string()),
-            },
+            }.into(),
             Filter {
                 field: "amount".to_string(),
                 op: FilterOperator::Gt,
                 value: FilterValue::Number(100.0),
-            },
+            }.into(),
         ]),
         aggregation: None,
+        metrics: None,
+        group_by: None,
         sort: None,
         limit: None,
+        page: None,
     };
 
     let converter = DataSourceConverter::new();
@@ -169,9 +181,73 @@ fn test_convert_with_limit() {
     assert_eq!(query.limit(), Some(10));
 }
 
+#[test]
+fn test_convert_with_aggregation() {
+    let mut ds = create_simple_data_source("expenses");
+    ds.aggregation = Some(Aggregation {
+        agg_type: AggregationType::Sum,
+        field: "amount".to_string(),
+        by: Some("month".to_string()),
+        bucket: None,
+    });
+    let query = convert_data_source(&ds).unwrap();
+
+    let aggregation = query.aggregation().expect("aggregation should be carried over");
+    let metric = &aggregation.metrics()[0];
+    assert_eq!(metric.agg_type(), &AggregationType::Sum);
+    assert_eq!(metric.field(), "amount");
+    assert_eq!(aggregation.group_by(), ["month"]);
+    assert_eq!(metric.alias(), "amount_sum");
+}
+
+#[test]
+fn test_convert_with_aggregation_without_group_by() {
+    let mut ds = create_simple_data_source("orders");
+    ds.aggregation = Some(Aggregation {
+        agg_type: AggregationType::Count,
+        field: "id".to_string(),
+        by: None,
+        bucket: None,
+    });
+    let query = convert_data_source(&ds).unwrap();
+
+    let aggregation = query.aggregation().unwrap();
+    assert_eq!(aggregation.metrics()[0].agg_type(), &AggregationType::Count);
+    assert!(aggregation.group_by().is_empty());
+}
+
+#[test]
+fn test_convert_with_sort() {
+    let mut ds = create_simple_data_source("posts");
+    ds.sort = Some(
+        Sort {
+            field: "created_at".to_string(),
+            direction: SortDirection::Desc,
+            nulls: None,
+        }
+        .into(),
+    );
+    let query = convert_data_source(&ds).unwrap();
+
+    let [sort] = query.sort() else {
+        panic!("sort should be carried over");
+    };
+    assert_eq!(sort.field(), "created_at");
+    assert_eq!(sort.direction(), &SortDirection::Desc);
+}
+
+#[test]
+fn test_convert_without_aggregation_or_sort() {
+    let ds = create_simple_data_source("posts");
+    let query = convert_data_source(&ds).unwrap();
+
+    assert!(query.aggregation().is_none());
+    assert!(query.sort().is_empty());
+}
+
 #[test]
 fn test_convert_with_all_filter_operators() {
-    let operators = vec![
+    let operators = [
         (FilterOperator::Eq, FilterValue::String("test".to_string())),
         (FilterOperator::Neq, FilterValue::String("test".to_string())),
         (FilterOperator::Gt, FilterValue::Number(10.0)),
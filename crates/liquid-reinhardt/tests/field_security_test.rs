@@ -0,0 +1,401 @@
+// FR-12: Column-Level Security Tests
+
+use liquid_protocol::{AggregationType, LiquidViewSchema, SortDirection};
+use liquid_reinhardt::converter::{
+    ConvertedQuery, QueryAggregation, QueryCondition, QueryMetric, QuerySort,
+};
+use liquid_reinhardt::field_security::{FieldMask, FieldPolicy};
+use liquid_reinhardt::policy::PolicyDocument;
+use liquid_reinhardt::security::{CurrentUser, SecurityEnforcer};
+use serde_json::{json, Value};
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a CurrentUser with the given permission strings
+fn create_user(permissions: &[&str]) -> CurrentUser {
+    CurrentUser::new(1, permissions.iter().map(|s| s.to_string()).collect())
+}
+
+/// Creates an enforcer protecting `employees.salary`, `email` and `card_number`
+fn create_enforcer() -> SecurityEnforcer {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.allow_public_resource("employees");
+    enforcer.set_field_policy(
+        "employees",
+        "salary",
+        FieldPolicy::new()
+            .require_read("hr:salary")
+            .require_filter("hr:salary:filter"),
+    );
+    enforcer.set_field_policy(
+        "employees",
+        "email",
+        FieldPolicy::new()
+            .require_sort("hr:admin")
+            .masked(FieldMask::Redact)
+            .unmask_with("hr:admin"),
+    );
+    enforcer.set_field_policy(
+        "employees",
+        "card_number",
+        FieldPolicy::new().masked(FieldMask::ShowLast(4)),
+    );
+    enforcer
+}
+
+fn employees_query() -> ConvertedQuery {
+    ConvertedQuery::new("employees".to_string())
+}
+
+/// Enforces the query and returns the error code, if any
+fn enforce_code(query: &mut ConvertedQuery, user: &CurrentUser) -> Option<String> {
+    create_enforcer()
+        .enforce(query, user)
+        .err()
+        .map(|err| err.code().to_string())
+}
+
+/// Builds a schema with one component bound to an `employees` data source
+fn schema_with(component: Value, data_source: Value) -> LiquidViewSchema {
+    serde_json::from_value(json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [{
+                "type": "stack",
+                "props": { "direction": "vertical" },
+                "children": [component]
+            }]
+        },
+        "data_sources": { "people": data_source }
+    }))
+    .unwrap()
+}
+
+fn sample_rows() -> Vec<Value> {
+    vec![
+        json!({ "id": 1, "salary": 500000, "email": "a@example.com", "card_number": "4111111111111111" }),
+        json!({ "id": 2, "salary": 650000, "email": null, "card_number": "5500000000000004" }),
+    ]
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_filter_on_protected_field_is_denied() {
+    let mut query = employees_query();
+    query.add_condition(QueryCondition::Gt {
+        field: "salary".to_string(),
+        value: 100000i64.into(),
+    });
+
+    // 読み取り権限のみではフィルタできない
+    assert_eq!(
+        enforce_code(&mut query.clone(), &create_user(&["hr:salary"])).as_deref(),
+        Some("FIELD_FILTER_DENIED")
+    );
+    assert_eq!(
+        enforce_code(&mut query.clone(), &create_user(&["hr:salary:filter"])).as_deref(),
+        Some("FIELD_FILTER_DENIED")
+    );
+    assert_eq!(
+        enforce_code(&mut query, &create_user(&["hr:salary", "hr:salary:filter"])),
+        None
+    );
+}
+
+#[test]
+fn test_filter_inside_group_is_checked() {
+    let mut query = employees_query();
+    query.add_condition(QueryCondition::Not {
+        condition: Box::new(QueryCondition::Gt {
+            field: "salary".to_string(),
+            value: 100000i64.into(),
+        }),
+    });
+
+    assert_eq!(
+        enforce_code(&mut query, &create_user(&["hr:salary"])).as_deref(),
+        Some("FIELD_FILTER_DENIED")
+    );
+}
+
+#[test]
+fn test_sort_on_protected_field_is_denied() {
+    let mut query = employees_query();
+    query.add_sort(QuerySort::new("email".to_string(), SortDirection::Asc));
+
+    assert_eq!(
+        enforce_code(&mut query.clone(), &create_user(&[])).as_deref(),
+        Some("FIELD_SORT_DENIED")
+    );
+    assert_eq!(enforce_code(&mut query, &create_user(&["hr:admin"])), None);
+}
+
+#[test]
+fn test_sort_on_masked_field_is_denied() {
+    let mut query = employees_query();
+    query.add_sort(QuerySort::new(
+        "card_number".to_string(),
+        SortDirection::Desc,
+    ));
+
+    // 並び順とページングから、マスクされた値を推測できる
+    assert_eq!(
+        enforce_code(&mut query, &create_user(&[])).as_deref(),
+        Some("FIELD_SORT_DENIED")
+    );
+
+    let schema = schema_with(
+        json!({ "type": "table", "data_source": "people", "columns": ["card_number"], "sortable": true }),
+        json!({ "resource": "employees" }),
+    );
+    let err = create_enforcer()
+        .check_schema(&schema, &create_user(&[]))
+        .unwrap_err();
+    assert_eq!(err.code(), "FIELD_SORT_DENIED");
+}
+
+#[test]
+fn test_aggregation_requires_read_permission() {
+    let mut query = employees_query();
+    query.set_aggregation(QueryAggregation::new(
+        AggregationType::Avg,
+        "salary".to_string(),
+        Some("department".to_string()),
+    ));
+
+    assert_eq!(
+        enforce_code(&mut query.clone(), &create_user(&[])).as_deref(),
+        Some("FIELD_READ_DENIED")
+    );
+    assert_eq!(enforce_code(&mut query, &create_user(&["hr:salary"])), None);
+}
+
+#[test]
+fn test_filter_on_masked_field_is_denied() {
+    let mut query = employees_query();
+    query.add_condition(QueryCondition::StartsWith {
+        field: "card_number".to_string(),
+        value: "4111".to_string(),
+    });
+
+    // 条件を変えながら問い合わせると、マスクされた値を推測できる
+    assert_eq!(
+        enforce_code(&mut query, &create_user(&[])).as_deref(),
+        Some("FIELD_FILTER_DENIED")
+    );
+
+    let mut query = employees_query();
+    query.add_condition(QueryCondition::Eq {
+        field: "email".to_string(),
+        value: "a@example.com".into(),
+    });
+    assert_eq!(
+        enforce_code(&mut query.clone(), &create_user(&[])).as_deref(),
+        Some("FIELD_FILTER_DENIED")
+    );
+    assert_eq!(enforce_code(&mut query, &create_user(&["hr:admin"])), None);
+}
+
+#[test]
+fn test_aggregating_masked_field_is_denied() {
+    let metrics = |agg_type: AggregationType| {
+        let mut query = employees_query();
+        query.set_aggregation(QueryAggregation::with_metrics(
+            vec![QueryMetric::new(
+                "email_value".to_string(),
+                agg_type,
+                "email".to_string(),
+            )],
+            vec!["department".to_string()],
+        ));
+        query
+    };
+
+    // 集計値の列 (`email_value`) はマスクされずに返る
+    for agg_type in [AggregationType::Max, AggregationType::Min] {
+        assert_eq!(
+            enforce_code(&mut metrics(agg_type.clone()), &create_user(&[])).as_deref(),
+            Some("FIELD_READ_DENIED")
+        );
+        assert_eq!(
+            enforce_code(&mut metrics(agg_type), &create_user(&["hr:admin"])),
+            None
+        );
+    }
+    // 件数は値を含まない
+    for agg_type in [AggregationType::Count, AggregationType::CountDistinct] {
+        assert_eq!(
+            enforce_code(&mut metrics(agg_type), &create_user(&[])),
+            None
+        );
+    }
+
+    let mut query = employees_query();
+    query.set_aggregation(QueryAggregation::new(
+        AggregationType::Sum,
+        "card_number".to_string(),
+        None,
+    ));
+    assert_eq!(
+        enforce_code(&mut query, &create_user(&[])).as_deref(),
+        Some("FIELD_READ_DENIED")
+    );
+}
+
+#[test]
+fn test_unprotected_fields_are_unrestricted() {
+    let mut query = employees_query();
+    query.add_condition(QueryCondition::Eq {
+        field: "department".to_string(),
+        value: "sales".into(),
+    });
+    query.add_sort(QuerySort::new("name".to_string(), SortDirection::Desc));
+
+    assert_eq!(enforce_code(&mut query, &create_user(&[])), None);
+}
+
+#[test]
+fn test_table_columns_are_checked() {
+    let schema = schema_with(
+        json!({ "type": "table", "data_source": "people", "columns": ["name", "salary"] }),
+        json!({ "resource": "employees" }),
+    );
+    let enforcer = create_enforcer();
+
+    let err = enforcer
+        .check_schema(&schema, &create_user(&[]))
+        .unwrap_err();
+
+    assert_eq!(err.code(), "FIELD_READ_DENIED");
+    assert!(err.message().contains("salary"));
+    assert!(enforcer
+        .check_schema(&schema, &create_user(&["hr:salary"]))
+        .is_ok());
+}
+
+#[test]
+fn test_sortable_table_requires_sort_permission() {
+    let schema = schema_with(
+        json!({ "type": "table", "data_source": "people", "columns": ["email"], "sortable": true }),
+        json!({ "resource": "employees" }),
+    );
+
+    let err = create_enforcer()
+        .check_schema(&schema, &create_user(&[]))
+        .unwrap_err();
+
+    assert_eq!(err.code(), "FIELD_SORT_DENIED");
+}
+
+#[test]
+fn test_chart_axes_are_checked_through_aggregation_alias() {
+    let schema = schema_with(
+        json!({ "type": "chart", "variant": "bar", "data_source": "people", "xAxis": "department", "yAxis": "salary_avg" }),
+        json!({
+            "resource": "employees",
+            "aggregation": { "type": "avg", "field": "salary", "by": "department" }
+        }),
+    );
+    let enforcer = create_enforcer();
+
+    assert_eq!(
+        enforcer
+            .check_schema(&schema, &create_user(&[]))
+            .unwrap_err()
+            .code(),
+        "FIELD_READ_DENIED"
+    );
+    assert!(enforcer
+        .check_schema(&schema, &create_user(&["hr:salary"]))
+        .is_ok());
+}
+
+#[test]
+fn test_mask_rows_hides_and_masks_values() {
+    let enforcer = create_enforcer();
+    let mut rows = sample_rows();
+
+    enforcer.mask_rows("employees", &mut rows, &create_user(&[]));
+
+    assert_eq!(
+        rows,
+        vec![
+            json!({ "id": 1, "email": "****", "card_number": "************1111" }),
+            json!({ "id": 2, "email": null, "card_number": "************0004" }),
+        ]
+    );
+}
+
+#[test]
+fn test_mask_rows_respects_permissions() {
+    let enforcer = create_enforcer();
+    let mut rows = sample_rows();
+
+    enforcer.mask_rows(
+        "employees",
+        &mut rows,
+        &create_user(&["hr:salary", "hr:admin"]),
+    );
+
+    assert_eq!(rows[0]["salary"], json!(500000));
+    assert_eq!(rows[0]["email"], json!("a@example.com"));
+    // card_number には解除権限がない
+    assert_eq!(rows[0]["card_number"], json!("************1111"));
+
+    let mut other = sample_rows();
+    enforcer.mask_rows("projects", &mut other, &create_user(&[]));
+    assert_eq!(other, sample_rows());
+}
+
+#[test]
+fn test_declarative_field_policies() {
+    let document = PolicyDocument::from_toml(
+        r#"
+public_resources = ["employees"]
+
+[fields.employees.salary]
+read = "hr:salary"
+
+[fields.employees.card_number]
+mask = { show_last = 4 }
+unmask = "billing:admin"
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        PolicyDocument::from_toml(&document.to_toml().unwrap()).unwrap(),
+        document
+    );
+
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.load_policies(document).unwrap();
+
+    assert_eq!(
+        enforcer.field_policy("employees", "card_number"),
+        Some(
+            &FieldPolicy::new()
+                .masked(FieldMask::ShowLast(4))
+                .unmask_with("billing:admin")
+        )
+    );
+    let mut rows = sample_rows();
+    enforcer.mask_rows("employees", &mut rows, &create_user(&["billing:admin"]));
+    assert_eq!(
+        rows[1],
+        json!({ "id": 2, "email": null, "card_number": "5500000000000004" })
+    );
+}
+
+#[test]
+fn test_unknown_field_policy_key_is_rejected() {
+    let err = PolicyDocument::from_toml("[fields.employees.salary]\nwrite = \"x\"\n").unwrap_err();
+
+    assert_eq!(err.code(), "INVALID_POLICY");
+}
@@ -0,0 +1,203 @@
+// FR-06: Filter Group (AND / OR / NOT) Tests
+
+use liquid_protocol::{DataSource, FilterNode};
+use liquid_reinhardt::converter::{DataSourceConverter, QueryCondition, QueryValue};
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use liquid_reinhardt::sql::{SqlBuilder, SqlValue};
+use serde_json::json;
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a converter whose `expenses` resource declares typed fields
+fn create_converter() -> DataSourceConverter {
+    DataSourceConverter::with_registry(
+        ResourceRegistry::new().with_resource(
+            ResourceDefinition::new("expenses")
+                .field("category", FieldType::String)
+                .field("amount", FieldType::Integer)
+                .field("refunded", FieldType::Boolean),
+        ),
+    )
+}
+
+/// Creates an `expenses` DataSource from raw JSON filters
+fn create_data_source(filters: serde_json::Value) -> DataSource {
+    let filters: Vec<FilterNode> = serde_json::from_value(filters).unwrap();
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: Some(filters),
+        aggregation: None,
+        metrics: None,
+        group_by: None,
+        sort: None,
+        limit: None,
+        page: None,
+    }
+}
+
+/// "food OR transport, excluding refunds"
+fn food_or_transport() -> DataSource {
+    create_data_source(json!([
+        {
+            "or": [
+                { "field": "category", "op": "eq", "value": "food" },
+                { "field": "category", "op": "eq", "value": "transport" }
+            ]
+        },
+        { "not": { "field": "refunded", "op": "eq", "value": true } }
+    ]))
+}
+
+fn eq(field: &str, value: impl Into<QueryValue>) -> QueryCondition {
+    QueryCondition::Eq {
+        field: field.to_string(),
+        value: value.into(),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_groups_convert_to_condition_tree() {
+    let query = create_converter().convert(&food_or_transport()).unwrap();
+
+    assert_eq!(
+        query.conditions(),
+        &[
+            QueryCondition::Or {
+                conditions: vec![eq("category", "food"), eq("category", "transport")],
+            },
+            QueryCondition::Not {
+                condition: Box::new(eq("refunded", true)),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_values_inside_groups_are_typed() {
+    let ds = create_data_source(json!([{
+        "and": [{ "field": "amount", "op": "gte", "value": 100 }]
+    }]));
+
+    let query = create_converter().convert(&ds).unwrap();
+
+    assert_eq!(
+        query.conditions(),
+        &[QueryCondition::And {
+            conditions: vec![QueryCondition::Gte {
+                field: "amount".to_string(),
+                value: QueryValue::Integer(100),
+            }],
+        }]
+    );
+}
+
+#[test]
+fn test_unknown_field_inside_group_is_rejected() {
+    let ds = create_data_source(json!([{
+        "or": [
+            { "field": "category", "op": "eq", "value": "food" },
+            { "not": { "field": "password_hash", "op": "eq", "value": "x" } }
+        ]
+    }]));
+
+    let err = create_converter().convert(&ds).unwrap_err();
+
+    assert_eq!(err.code(), "UNKNOWN_FIELD");
+    assert!(err.message().contains("password_hash"));
+}
+
+#[test]
+fn test_type_mismatch_inside_group_is_rejected() {
+    let ds = create_data_source(json!([{
+        "not": { "field": "amount", "op": "eq", "value": "lots" }
+    }]));
+
+    let err = create_converter().convert(&ds).unwrap_err();
+
+    assert_eq!(err.code(), "FILTER_TYPE_MISMATCH");
+}
+
+#[test]
+fn test_groups_build_parenthesized_sql() {
+    let query = create_converter().convert(&food_or_transport()).unwrap();
+
+    let sql = SqlBuilder::new().build(&query).unwrap();
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT * FROM \"expenses\" WHERE (\"category\" = $1 OR \"category\" = $2) \
+         AND NOT (\"refunded\" = $3)"
+    );
+    assert_eq!(
+        sql.params(),
+        &[
+            SqlValue::Text("food".to_string()),
+            SqlValue::Text("transport".to_string()),
+            SqlValue::Boolean(true),
+        ]
+    );
+}
+
+#[test]
+fn test_nested_groups_build_sql() {
+    let ds = create_data_source(json!([{
+        "or": [
+            { "and": [
+                { "field": "category", "op": "eq", "value": "food" },
+                { "field": "amount", "op": "gt", "value": 10 }
+            ] },
+            { "not": { "or": [{ "field": "refunded", "op": "eq", "value": false }] } }
+        ]
+    }]));
+    let query = create_converter().convert(&ds).unwrap();
+
+    let sql = SqlBuilder::new().build(&query).unwrap();
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT * FROM \"expenses\" WHERE ((\"category\" = $1 AND \"amount\" > $2) \
+         OR NOT ((\"refunded\" = $3)))"
+    );
+}
+
+#[test]
+fn test_empty_groups_build_constant_sql() {
+    let mut query = create_converter()
+        .convert(&create_data_source(json!([])))
+        .unwrap();
+    query.add_condition(QueryCondition::And { conditions: vec![] });
+    query.add_condition(QueryCondition::Or { conditions: vec![] });
+
+    let sql = SqlBuilder::new().build(&query).unwrap();
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT * FROM \"expenses\" WHERE 1 = 1 AND 1 = 0"
+    );
+}
+
+#[test]
+fn test_group_serialization() {
+    let condition = QueryCondition::Not {
+        condition: Box::new(QueryCondition::Or {
+            conditions: vec![eq("category", "food")],
+        }),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&condition).unwrap(),
+        json!({
+            "op": "not",
+            "condition": {
+                "op": "or",
+                "conditions": [{ "op": "eq", "field": "category", "value": "food" }]
+            }
+        })
+    );
+}
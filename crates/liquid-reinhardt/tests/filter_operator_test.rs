@@ -0,0 +1,293 @@
+// FR-06: Extended Filter Operator Tests
+
+use liquid_protocol::{DataSource, FilterNode};
+use liquid_reinhardt::converter::{DataSourceConverter, QueryCondition, QueryValue};
+use liquid_reinhardt::dialect::{Dialect, MySqlDialect, PostgresDialect, SqliteDialect};
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use liquid_reinhardt::sql::{SqlBuilder, SqlValue};
+use serde_json::json;
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a converter whose `expenses` resource declares typed fields
+fn create_converter() -> DataSourceConverter {
+    DataSourceConverter::with_registry(
+        ResourceRegistry::new().with_resource(
+            ResourceDefinition::new("expenses")
+                .field("memo", FieldType::String)
+                .field("amount", FieldType::Integer)
+                .field("date", FieldType::Date)
+                .field("approved", FieldType::Boolean),
+        ),
+    )
+}
+
+/// Creates an `expenses` DataSource from a single raw JSON filter
+fn create_data_source(filter: serde_json::Value) -> DataSource {
+    let filter: FilterNode = serde_json::from_value(filter).unwrap();
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: Some(vec![filter]),
+        aggregation: None,
+        metrics: None,
+        group_by: None,
+        sort: None,
+        limit: None,
+        page: None,
+    }
+}
+
+/// Converts a single raw JSON filter and returns the resulting condition
+fn convert_one(filter: serde_json::Value) -> QueryCondition {
+    let query = create_converter()
+        .convert(&create_data_source(filter))
+        .unwrap();
+    query.conditions()[0].clone()
+}
+
+/// Converts a single raw JSON filter and returns the error code
+fn convert_error(filter: serde_json::Value) -> String {
+    create_converter()
+        .convert(&create_data_source(filter))
+        .unwrap_err()
+        .code()
+        .to_string()
+}
+
+/// Builds the WHERE clause and params for a single raw JSON filter
+fn build_with(
+    dialect: impl Dialect + 'static,
+    filter: serde_json::Value,
+) -> (String, Vec<SqlValue>) {
+    let query = create_converter()
+        .convert(&create_data_source(filter))
+        .unwrap();
+    let sql = SqlBuilder::with_dialect(dialect).build(&query).unwrap();
+    (sql.sql().to_string(), sql.params().to_vec())
+}
+
+fn text(value: &str) -> SqlValue {
+    SqlValue::Text(value.to_string())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_between_bounds_are_typed() {
+    let condition = convert_one(json!({
+        "field": "date", "op": "between", "value": ["2024-01-01", "2024-03-31"]
+    }));
+
+    assert_eq!(
+        condition,
+        QueryCondition::Between {
+            field: "date".to_string(),
+            low: QueryValue::Date(chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()),
+            high: QueryValue::Date(chrono::NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()),
+        }
+    );
+}
+
+#[test]
+fn test_not_in_and_null_checks_convert() {
+    assert_eq!(
+        convert_one(json!({ "field": "amount", "op": "not_in", "value": [1, 2] })),
+        QueryCondition::NotIn {
+            field: "amount".to_string(),
+            values: vec![QueryValue::Integer(1), QueryValue::Integer(2)],
+        }
+    );
+    assert_eq!(
+        convert_one(json!({ "field": "memo", "op": "is_null" })),
+        QueryCondition::IsNull {
+            field: "memo".to_string(),
+        }
+    );
+    assert_eq!(
+        convert_one(json!({ "field": "memo", "op": "is_not_null" })),
+        QueryCondition::IsNotNull {
+            field: "memo".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_text_operators_convert() {
+    assert_eq!(
+        convert_one(json!({ "field": "memo", "op": "starts_with", "value": "Lun" })),
+        QueryCondition::StartsWith {
+            field: "memo".to_string(),
+            value: "Lun".to_string(),
+        }
+    );
+    assert_eq!(
+        convert_one(json!({ "field": "memo", "op": "ends_with", "value": "team" })),
+        QueryCondition::EndsWith {
+            field: "memo".to_string(),
+            value: "team".to_string(),
+        }
+    );
+    assert_eq!(
+        convert_one(json!({ "field": "memo", "op": "icontains", "value": "lunch" })),
+        QueryCondition::IContains {
+            field: "memo".to_string(),
+            value: "lunch".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_invalid_operator_values_are_rejected() {
+    let cases = [
+        (
+            json!({ "field": "amount", "op": "between", "value": [1] }),
+            "INVALID_FILTER_VALUE_TYPE",
+        ),
+        (
+            json!({ "field": "amount", "op": "between", "value": 1 }),
+            "INVALID_FILTER_VALUE_TYPE",
+        ),
+        (
+            json!({ "field": "amount", "op": "between", "value": [1, "x"] }),
+            "FILTER_TYPE_MISMATCH",
+        ),
+        (
+            json!({ "field": "approved", "op": "between", "value": [0, 1] }),
+            "FILTER_TYPE_MISMATCH",
+        ),
+        (
+            json!({ "field": "amount", "op": "not_in", "value": 1 }),
+            "INVALID_FILTER_VALUE_TYPE",
+        ),
+        (
+            json!({ "field": "memo", "op": "is_null", "value": "x" }),
+            "INVALID_FILTER_VALUE_TYPE",
+        ),
+        (
+            json!({ "field": "memo", "op": "eq" }),
+            "INVALID_FILTER_VALUE_TYPE",
+        ),
+        (
+            json!({ "field": "date", "op": "starts_with", "value": "2024" }),
+            "FILTER_TYPE_MISMATCH",
+        ),
+    ];
+
+    for (filter, code) in cases {
+        assert_eq!(convert_error(filter.clone()), code, "{}", filter);
+    }
+}
+
+#[test]
+fn test_range_and_null_sql() {
+    let (sql, params) = build_with(
+        PostgresDialect,
+        json!({ "field": "amount", "op": "between", "value": [10, 20] }),
+    );
+    assert_eq!(
+        sql,
+        "SELECT * FROM \"expenses\" WHERE \"amount\" BETWEEN $1 AND $2"
+    );
+    assert_eq!(params, vec![SqlValue::Integer(10), SqlValue::Integer(20)]);
+
+    let (sql, _) = build_with(
+        PostgresDialect,
+        json!({ "field": "amount", "op": "not_in", "value": [1, 2] }),
+    );
+    assert_eq!(
+        sql,
+        "SELECT * FROM \"expenses\" WHERE \"amount\" NOT IN ($1, $2)"
+    );
+
+    let (sql, params) = build_with(PostgresDialect, json!({ "field": "memo", "op": "is_null" }));
+    assert_eq!(sql, "SELECT * FROM \"expenses\" WHERE \"memo\" IS NULL");
+    assert!(params.is_empty());
+}
+
+#[test]
+fn test_empty_not_in_list_matches_everything() {
+    let (sql, params) = build_with(
+        PostgresDialect,
+        json!({ "field": "amount", "op": "not_in", "value": [] }),
+    );
+
+    assert_eq!(sql, "SELECT * FROM \"expenses\" WHERE 1 = 1");
+    assert!(params.is_empty());
+}
+
+#[test]
+fn test_case_sensitive_match_per_dialect() {
+    let filter = json!({ "field": "memo", "op": "starts_with", "value": "50%_*" });
+
+    let (sql, params) = build_with(PostgresDialect, filter.clone());
+    assert_eq!(
+        sql,
+        "SELECT * FROM \"expenses\" WHERE \"memo\" LIKE $1 ESCAPE '\\'"
+    );
+    assert_eq!(params, vec![text("50\\%\\_*%")]);
+
+    let (sql, params) = build_with(SqliteDialect, filter.clone());
+    assert_eq!(sql, "SELECT * FROM \"expenses\" WHERE \"memo\" GLOB ?");
+    assert_eq!(params, vec![text("50%_[*]*")]);
+
+    let (sql, params) = build_with(MySqlDialect, filter);
+    assert_eq!(
+        sql,
+        "SELECT * FROM `expenses` WHERE CAST(`memo` AS BINARY) LIKE ? ESCAPE '\\\\'"
+    );
+    assert_eq!(params, vec![text("50\\%\\_*%")]);
+}
+
+#[test]
+fn test_icontains_is_case_insensitive_per_dialect() {
+    let filter = json!({ "field": "memo", "op": "icontains", "value": "Lunch" });
+
+    let (sql, params) = build_with(PostgresDialect, filter.clone());
+    assert_eq!(
+        sql,
+        "SELECT * FROM \"expenses\" WHERE \"memo\" ILIKE $1 ESCAPE '\\'"
+    );
+    assert_eq!(params, vec![text("%Lunch%")]);
+
+    let (sql, _) = build_with(SqliteDialect, filter);
+    assert_eq!(
+        sql,
+        "SELECT * FROM \"expenses\" WHERE LOWER(\"memo\") LIKE LOWER(?) ESCAPE '\\'"
+    );
+}
+
+#[test]
+fn test_extended_condition_serialization() {
+    let conditions = [
+        QueryCondition::IContains {
+            field: "memo".to_string(),
+            value: "x".to_string(),
+        },
+        QueryCondition::IsNull {
+            field: "memo".to_string(),
+        },
+        QueryCondition::Between {
+            field: "amount".to_string(),
+            low: 1i64.into(),
+            high: 2i64.into(),
+        },
+    ];
+
+    let json: Vec<serde_json::Value> = conditions
+        .iter()
+        .map(|condition| serde_json::to_value(condition).unwrap())
+        .collect();
+
+    assert_eq!(
+        json,
+        vec![
+            json!({ "op": "icontains", "field": "memo", "value": "x" }),
+            json!({ "op": "is_null", "field": "memo" }),
+            json!({ "op": "between", "field": "amount", "low": 1, "high": 2 }),
+        ]
+    );
+}
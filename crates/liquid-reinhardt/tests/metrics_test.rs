@@ -0,0 +1,185 @@
+// FR-06: Named Metrics / Multi-Field Group By Tests
+
+use liquid_protocol::{Aggregation, AggregationType, DataSource, Metric, Sort, SortDirection};
+use liquid_reinhardt::converter::{DataSourceConverter, QueryMetric};
+use liquid_reinhardt::dialect::MySqlDialect;
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use liquid_reinhardt::sql::SqlBuilder;
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a converter whose `expenses` resource declares typed fields
+fn create_converter() -> DataSourceConverter {
+    DataSourceConverter::with_registry(
+        ResourceRegistry::new().with_resource(
+            ResourceDefinition::new("expenses")
+                .field("id", FieldType::Integer)
+                .field("amount", FieldType::Integer)
+                .field("category", FieldType::String)
+                .field("month", FieldType::String),
+        ),
+    )
+}
+
+fn metric(alias: &str, agg_type: AggregationType, field: &str) -> Metric {
+    Metric {
+        alias: alias.to_string(),
+        agg_type,
+        field: field.to_string(),
+    }
+}
+
+/// Creates an `expenses` DataSource with total/count metrics grouped by category and month
+fn create_data_source() -> DataSource {
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: None,
+        aggregation: None,
+        metrics: Some(vec![
+            metric("total", AggregationType::Sum, "amount"),
+            metric("count", AggregationType::Count, "id"),
+        ]),
+        group_by: Some(vec!["category".to_string(), "month".to_string()]),
+        sort: None,
+        limit: None,
+        page: None,
+    }
+}
+
+fn convert_error(ds: &DataSource) -> String {
+    create_converter()
+        .convert(ds)
+        .unwrap_err()
+        .code()
+        .to_string()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_metrics_convert_to_aggregation() {
+    let query = create_converter().convert(&create_data_source()).unwrap();
+
+    let aggregation = query.aggregation().unwrap();
+    assert_eq!(
+        aggregation.metrics(),
+        [
+            QueryMetric::new(
+                "total".to_string(),
+                AggregationType::Sum,
+                "amount".to_string()
+            ),
+            QueryMetric::new(
+                "count".to_string(),
+                AggregationType::Count,
+                "id".to_string()
+            ),
+        ]
+    );
+    assert_eq!(aggregation.group_by(), ["category", "month"]);
+}
+
+#[test]
+fn test_metrics_build_sql() {
+    let mut ds = create_data_source();
+    ds.sort = Some(
+        Sort {
+            field: "total".to_string(),
+            direction: SortDirection::Desc,
+            nulls: None,
+        }
+        .into(),
+    );
+    let query = create_converter().convert(&ds).unwrap();
+
+    let sql = SqlBuilder::new().build(&query).unwrap();
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT \"category\", \"month\", SUM(\"amount\") AS \"total\", COUNT(\"id\") AS \"count\" \
+         FROM \"expenses\" GROUP BY \"category\", \"month\" ORDER BY \"total\" DESC"
+    );
+}
+
+#[test]
+fn test_metrics_build_sql_per_dialect() {
+    let query = create_converter().convert(&create_data_source()).unwrap();
+
+    let sql = SqlBuilder::with_dialect(MySqlDialect)
+        .build(&query)
+        .unwrap();
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT `category`, `month`, SUM(`amount`) AS `total`, COUNT(`id`) AS `count` \
+         FROM `expenses` GROUP BY `category`, `month`"
+    );
+}
+
+#[test]
+fn test_metrics_without_group_by() {
+    let mut ds = create_data_source();
+    ds.group_by = None;
+    let query = create_converter().convert(&ds).unwrap();
+
+    let sql = SqlBuilder::new().build(&query).unwrap();
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT SUM(\"amount\") AS \"total\", COUNT(\"id\") AS \"count\" FROM \"expenses\""
+    );
+}
+
+#[test]
+fn test_invalid_metrics_are_rejected() {
+    let mut conflicting = create_data_source();
+    conflicting.aggregation = Some(Aggregation {
+        agg_type: AggregationType::Sum,
+        field: "amount".to_string(),
+        by: None,
+        bucket: None,
+    });
+
+    let mut empty = create_data_source();
+    empty.metrics = Some(vec![]);
+
+    let mut duplicate = create_data_source();
+    duplicate.metrics = Some(vec![
+        metric("total", AggregationType::Sum, "amount"),
+        metric("total", AggregationType::Max, "amount"),
+    ]);
+
+    let mut clashes_with_group_by = create_data_source();
+    clashes_with_group_by.metrics = Some(vec![metric("month", AggregationType::Max, "amount")]);
+
+    let mut unknown_field = create_data_source();
+    unknown_field.metrics = Some(vec![metric("total", AggregationType::Sum, "price")]);
+
+    assert_eq!(convert_error(&conflicting), "INVALID_AGGREGATION");
+    assert_eq!(convert_error(&empty), "INVALID_AGGREGATION");
+    assert_eq!(convert_error(&duplicate), "DUPLICATE_METRIC_ALIAS");
+    assert_eq!(
+        convert_error(&clashes_with_group_by),
+        "DUPLICATE_METRIC_ALIAS"
+    );
+    assert_eq!(convert_error(&unknown_field), "UNKNOWN_FIELD");
+}
+
+#[test]
+fn test_sort_by_unknown_alias_is_rejected() {
+    let mut ds = create_data_source();
+    ds.sort = Some(
+        Sort {
+            field: "average".to_string(),
+            direction: SortDirection::Asc,
+            nulls: None,
+        }
+        .into(),
+    );
+
+    assert_eq!(convert_error(&ds), "INVALID_SORT_FIELD");
+}
@@ -0,0 +1,400 @@
+// FR-14: Offset / Signed Cursor Pagination Tests
+
+use chrono::NaiveDate;
+use liquid_protocol::NullsOrder;
+use liquid_protocol::{
+    AggregationType, DataSource, Filter, FilterOperator, FilterValue, Metric, Page, Sort,
+    SortDirection,
+};
+use liquid_reinhardt::converter::{
+    ConvertedQuery, DataSourceConverter, QueryPage, QuerySort, QueryValue,
+};
+use liquid_reinhardt::dialect::SqliteDialect;
+use liquid_reinhardt::pagination::{CursorCodec, PageResult};
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use liquid_reinhardt::security::{CurrentUser, SecurityEnforcer};
+use liquid_reinhardt::sql::{SqlBuilder, SqlValue};
+use serde_json::json;
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+fn sort(field: &str, direction: SortDirection) -> Sort {
+    Sort {
+        field: field.to_string(),
+        direction,
+        nulls: None,
+    }
+}
+
+/// Creates an `expenses` DataSource with 10 rows per page, sorted by month descending then id
+fn create_data_source(page: Option<Page>) -> DataSource {
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: None,
+        aggregation: None,
+        metrics: None,
+        group_by: None,
+        sort: Some(
+            vec![
+                sort("month", SortDirection::Desc),
+                sort("id", SortDirection::Asc),
+            ]
+            .into(),
+        ),
+        limit: Some(10),
+        page,
+    }
+}
+
+/// Converts the DataSource and applies the default RLS policy for the user
+fn enforce(ds: &DataSource, user_id: u64) -> ConvertedQuery {
+    let mut query = DataSourceConverter::new().convert(ds).unwrap();
+    SecurityEnforcer::new()
+        .enforce(&mut query, &CurrentUser::new(user_id, vec![]))
+        .unwrap();
+    query
+}
+
+/// Issues a cursor for the row with the given sort key values
+fn issue_cursor(codec: &CursorCodec, ds: &DataSource, user_id: u64) -> String {
+    codec
+        .encode(
+            &enforce(ds, user_id),
+            &json!({ "id": 42, "month": "2024-02", "amount": 1200 }),
+        )
+        .unwrap()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_offset_page_sql() {
+    let query = DataSourceConverter::new()
+        .convert(&create_data_source(Some(Page::Offset(20))))
+        .unwrap();
+
+    let sql = SqlBuilder::new().build(&query).unwrap();
+
+    assert_eq!(query.page(), Some(&QueryPage::Offset(20)));
+    assert_eq!(
+        sql.sql(),
+        "SELECT * FROM \"expenses\" ORDER BY \"month\" DESC, \"id\" ASC LIMIT 10 OFFSET 20"
+    );
+}
+
+#[test]
+fn test_keyset_page_sql() {
+    let mut query = DataSourceConverter::new()
+        .convert(&create_data_source(None))
+        .unwrap();
+    query.set_page(QueryPage::After(vec![
+        QueryValue::String("2024-02".to_string()),
+        QueryValue::Integer(42),
+    ]));
+
+    let sql = SqlBuilder::new().build(&query).unwrap();
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT * FROM \"expenses\" WHERE ((\"month\" < $1) OR (\"month\" = $2 AND (\"id\" > $3 OR \"id\" IS NULL))) \
+         ORDER BY \"month\" DESC, \"id\" ASC LIMIT 10"
+    );
+    assert_eq!(
+        sql.params(),
+        [
+            SqlValue::Text("2024-02".to_string()),
+            SqlValue::Text("2024-02".to_string()),
+            SqlValue::Integer(42),
+        ]
+    );
+}
+
+#[test]
+fn test_unverified_cursor_is_not_built() {
+    let query = DataSourceConverter::new()
+        .convert(&create_data_source(Some(Page::After("token".to_string()))))
+        .unwrap();
+
+    let err = SqlBuilder::new().build(&query).unwrap_err();
+
+    assert_eq!(err.code(), "UNVERIFIED_CURSOR");
+}
+
+#[test]
+fn test_invalid_pagination_is_rejected() {
+    let mut without_limit = create_data_source(Some(Page::Offset(10)));
+    without_limit.limit = None;
+    let mut unsorted = create_data_source(Some(Page::After("token".to_string())));
+    unsorted.sort = None;
+    let mut aggregated = create_data_source(Some(Page::After("token".to_string())));
+    aggregated.sort = Some(sort("month", SortDirection::Asc).into());
+    aggregated.metrics = Some(vec![Metric {
+        alias: "total".to_string(),
+        agg_type: AggregationType::Sum,
+        field: "amount".to_string(),
+    }]);
+    aggregated.group_by = Some(vec!["month".to_string()]);
+
+    for ds in [without_limit, unsorted, aggregated] {
+        assert_eq!(
+            DataSourceConverter::new().convert(&ds).unwrap_err().code(),
+            "INVALID_PAGINATION"
+        );
+    }
+}
+
+#[test]
+fn test_cursor_roundtrip() {
+    let codec = CursorCodec::new("server-secret");
+    let cursor = issue_cursor(&codec, &create_data_source(None), 1);
+
+    let mut query = enforce(&create_data_source(Some(Page::After(cursor))), 1);
+    codec.resolve(&mut query, None).unwrap();
+
+    assert_eq!(
+        query.page(),
+        Some(&QueryPage::After(vec![
+            QueryValue::String("2024-02".to_string()),
+            QueryValue::Integer(42),
+        ]))
+    );
+}
+
+#[test]
+fn test_tampered_cursor_is_rejected() {
+    let codec = CursorCodec::new("server-secret");
+    let cursor = issue_cursor(&codec, &create_data_source(None), 1);
+    let (_, signature) = cursor.split_once('.').unwrap();
+    // The signature of a real cursor over a different position
+    let forged = format!("WyIyMDk5LTAxIiwwXQ.{}", signature);
+
+    for token in [forged, "garbage".to_string(), format!("{}x", cursor)] {
+        let mut query = enforce(&create_data_source(Some(Page::After(token))), 1);
+        assert_eq!(
+            codec.resolve(&mut query, None).unwrap_err().code(),
+            "INVALID_CURSOR"
+        );
+    }
+}
+
+#[test]
+fn test_cursor_is_bound_to_user_query_and_secret() {
+    let codec = CursorCodec::new("server-secret");
+    let cursor = issue_cursor(&codec, &create_data_source(None), 1);
+
+    // Another user (different RLS conditions)
+    let other_user = enforce(&create_data_source(Some(Page::After(cursor.clone()))), 2);
+    // A different filter
+    let mut filtered = create_data_source(Some(Page::After(cursor.clone())));
+    filtered.filters = Some(vec![Filter {
+        field: "category".to_string(),
+        op: FilterOperator::Eq,
+        value: FilterValue::String("food".to_string()),
+    }
+    .into()]);
+    // A different sort
+    let mut resorted = create_data_source(Some(Page::After(cursor.clone())));
+    resorted.sort = Some(
+        vec![
+            sort("month", SortDirection::Asc),
+            sort("id", SortDirection::Asc),
+        ]
+        .into(),
+    );
+
+    for mut query in [other_user, enforce(&filtered, 1), enforce(&resorted, 1)] {
+        assert_eq!(
+            codec.resolve(&mut query, None).unwrap_err().code(),
+            "INVALID_CURSOR"
+        );
+    }
+
+    let mut query = enforce(&create_data_source(Some(Page::After(cursor))), 1);
+    assert_eq!(
+        CursorCodec::new("other-secret")
+            .resolve(&mut query, None)
+            .unwrap_err()
+            .code(),
+        "INVALID_CURSOR"
+    );
+}
+
+#[test]
+fn test_cursor_carries_null_sort_values() {
+    let codec = CursorCodec::new("server-secret");
+    let cursor = codec
+        .encode(
+            &enforce(&create_data_source(None), 1),
+            &json!({ "id": 42, "month": null }),
+        )
+        .unwrap();
+
+    let mut query = enforce(&create_data_source(Some(Page::After(cursor))), 1);
+    codec.resolve(&mut query, None).unwrap();
+
+    assert_eq!(
+        query.page(),
+        Some(&QueryPage::After(vec![
+            QueryValue::Null,
+            QueryValue::Integer(42)
+        ]))
+    );
+}
+
+#[test]
+fn test_cursor_values_are_typed_by_registry() {
+    let registry = ResourceRegistry::new().with_resource(
+        ResourceDefinition::new("expenses")
+            .field("id", FieldType::Integer)
+            .field("month", FieldType::Date),
+    );
+    let codec = CursorCodec::new("server-secret");
+    let cursor = codec
+        .encode(
+            &enforce(&create_data_source(None), 1),
+            &json!({ "id": 42, "month": "2024-02-01" }),
+        )
+        .unwrap();
+
+    let mut query = enforce(&create_data_source(Some(Page::After(cursor))), 1);
+    codec.resolve(&mut query, Some(&registry)).unwrap();
+
+    assert_eq!(
+        query.page(),
+        Some(&QueryPage::After(vec![
+            QueryValue::Date(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()),
+            QueryValue::Integer(42),
+        ]))
+    );
+}
+
+#[test]
+fn test_paged_sort_ends_with_primary_key() {
+    let mut ds = create_data_source(None);
+    ds.sort = Some(sort("month", SortDirection::Desc).into());
+    let registry = ResourceRegistry::new().with_resource(
+        ResourceDefinition::new("expenses")
+            .field("month", FieldType::String)
+            .with_primary_key("expense_id"),
+    );
+
+    let converter = DataSourceConverter::with_registry(registry);
+
+    assert_eq!(
+        converter.convert(&ds).unwrap().sort(),
+        [
+            QuerySort::new("month".to_string(), SortDirection::Desc),
+            QuerySort::new("expense_id".to_string(), SortDirection::Asc),
+        ]
+    );
+
+    // Unpaged queries keep the requested sort
+    ds.limit = None;
+    assert_eq!(converter.convert(&ds).unwrap().sort().len(), 1);
+}
+
+#[test]
+fn test_limited_sort_without_declared_primary_key_is_unchanged() {
+    let mut ds = create_data_source(None);
+    ds.sort = Some(sort("month", SortDirection::Desc).into());
+    let registry = ResourceRegistry::new()
+        .with_resource(ResourceDefinition::new("expenses").field("month", FieldType::String));
+    let expected = [QuerySort::new("month".to_string(), SortDirection::Desc)];
+
+    // The table may have no `id` column, so nothing is appended to ORDER BY
+    let query = DataSourceConverter::new().convert(&ds).unwrap();
+    assert_eq!(query.sort(), expected);
+    assert_eq!(
+        SqlBuilder::new().build(&query).unwrap().sql(),
+        "SELECT * FROM \"expenses\" ORDER BY \"month\" DESC LIMIT 10"
+    );
+    assert_eq!(
+        DataSourceConverter::with_registry(registry)
+            .convert(&ds)
+            .unwrap()
+            .sort(),
+        expected
+    );
+}
+
+#[test]
+fn test_keyset_condition_follows_nulls_order() {
+    let mut ds = create_data_source(None);
+    ds.sort = Some(
+        vec![
+            Sort {
+                field: "memo".to_string(),
+                direction: SortDirection::Asc,
+                nulls: Some(NullsOrder::Last),
+            },
+            sort("id", SortDirection::Asc),
+        ]
+        .into(),
+    );
+    let mut query = DataSourceConverter::new().convert(&ds).unwrap();
+
+    // A non-null boundary: later values, then every NULL
+    query.set_page(QueryPage::After(vec![
+        QueryValue::String("lunch".to_string()),
+        QueryValue::Integer(7),
+    ]));
+    assert_eq!(
+        SqlBuilder::new().build(&query).unwrap().sql(),
+        "SELECT * FROM \"expenses\" WHERE \
+         (((\"memo\" > $1 OR \"memo\" IS NULL)) OR (\"memo\" = $2 AND (\"id\" > $3 OR \"id\" IS NULL))) \
+         ORDER BY \"memo\" ASC NULLS LAST, \"id\" ASC LIMIT 10"
+    );
+
+    // A NULL boundary: only the remaining NULL rows
+    query.set_page(QueryPage::After(vec![
+        QueryValue::Null,
+        QueryValue::Integer(7),
+    ]));
+    assert_eq!(
+        SqlBuilder::new().build(&query).unwrap().sql(),
+        "SELECT * FROM \"expenses\" WHERE ((\"memo\" IS NULL AND (\"id\" > $1 OR \"id\" IS NULL))) \
+         ORDER BY \"memo\" ASC NULLS LAST, \"id\" ASC LIMIT 10"
+    );
+}
+
+#[test]
+fn test_keyset_condition_uses_dialect_default_nulls() {
+    let mut query = DataSourceConverter::new()
+        .convert(&create_data_source(None))
+        .unwrap();
+    query.set_page(QueryPage::After(vec![
+        QueryValue::Null,
+        QueryValue::Integer(42),
+    ]));
+
+    // SQLite sorts NULL as the smallest value, so NULLs come last in descending order
+    assert_eq!(
+        SqlBuilder::with_dialect(SqliteDialect)
+            .build(&query)
+            .unwrap()
+            .sql(),
+        "SELECT * FROM \"expenses\" WHERE ((\"month\" IS NULL AND \"id\" > ?)) \
+         ORDER BY \"month\" DESC, \"id\" ASC LIMIT 10"
+    );
+    // PostgreSQL sorts NULL as the largest value, so NULLs come first in descending order
+    assert_eq!(
+        SqlBuilder::new().build(&query).unwrap().sql(),
+        "SELECT * FROM \"expenses\" WHERE ((\"month\" IS NOT NULL) OR (\"month\" IS NULL AND (\"id\" > $1 OR \"id\" IS NULL))) \
+         ORDER BY \"month\" DESC, \"id\" ASC LIMIT 10"
+    );
+}
+
+#[test]
+fn test_page_result_serialization() {
+    let page = PageResult::new(vec![json!({ "id": 1 })], Some(10), None);
+
+    assert!(page.has_more());
+    assert_eq!(
+        serde_json::to_value(&page).unwrap(),
+        json!({ "rows": [{ "id": 1 }], "next_offset": 10 })
+    );
+}
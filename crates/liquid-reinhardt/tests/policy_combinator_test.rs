@@ -0,0 +1,341 @@
+// FR-07: Composable Security Policy Tests
+
+use liquid_reinhardt::converter::{ConvertedQuery, QueryCondition, QueryValue};
+use liquid_reinhardt::policy::{PolicyCombinator, PolicyDocument, PolicyEffect};
+use liquid_reinhardt::security::{CurrentUser, SecurityEnforcer, SecurityPolicy};
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a CurrentUser with the given ID and permission strings
+fn create_user(id: u64, permissions: &[&str]) -> CurrentUser {
+    CurrentUser::new(id, permissions.iter().map(|s| s.to_string()).collect())
+}
+
+/// Runs the enforcer against a fresh query and returns the injected conditions
+fn enforce(
+    enforcer: &SecurityEnforcer,
+    resource: &str,
+    user: &CurrentUser,
+) -> Result<Vec<QueryCondition>, String> {
+    let mut query = ConvertedQuery::new(resource.to_string());
+    enforcer
+        .enforce(&mut query, user)
+        .map(|_| query.system_conditions().to_vec())
+        .map_err(|err| err.message().to_string())
+}
+
+/// Eq condition on the given field
+fn eq(field: &str, value: impl Into<QueryValue>) -> QueryCondition {
+    QueryCondition::Eq {
+        field: field.to_string(),
+        value: value.into(),
+    }
+}
+
+/// Policy granting access only to users holding `permission`
+fn requires(permission: &'static str) -> SecurityPolicy {
+    SecurityPolicy::new(format!("requires_{}", permission), move |user, _| {
+        user.has_permission(permission)
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_adding_policy_keeps_existing_one() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource("expenses", SecurityPolicy::custom_field("owner_id"));
+    enforcer.add_policy_for_resource("expenses", requires("audit:read"));
+
+    let user = create_user(5, &["audit:read"]);
+
+    // 所有者ルールが監査ルールで置き換えられない
+    assert_eq!(
+        enforce(&enforcer, "expenses", &user),
+        Ok(vec![eq("owner_id", 5i64)])
+    );
+    assert!(enforce(&enforcer, "expenses", &create_user(5, &[]))
+        .unwrap_err()
+        .contains("requires_audit:read"));
+}
+
+#[test]
+fn test_all_of_combines_conditions() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource("tasks", SecurityPolicy::custom_field("owner_id"));
+    enforcer.add_policy_for_resource("tasks", SecurityPolicy::custom_field("assignee_id"));
+
+    assert_eq!(
+        enforce(&enforcer, "tasks", &create_user(3, &[])),
+        Ok(vec![eq("owner_id", 3i64), eq("assignee_id", 3i64)])
+    );
+}
+
+#[test]
+fn test_any_of_uses_first_granting_policy() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource("reports", requires("admin"));
+    enforcer.add_policy_for_resource("reports", SecurityPolicy::custom_field("author_id"));
+    enforcer.set_combinator("reports", PolicyCombinator::AnyOf);
+
+    // admin はフィルタなし、それ以外は作成者のみ
+    assert_eq!(
+        enforce(&enforcer, "reports", &create_user(1, &["admin"])),
+        Ok(vec![])
+    );
+    assert_eq!(
+        enforce(&enforcer, "reports", &create_user(2, &[])),
+        Ok(vec![eq("author_id", 2i64)])
+    );
+}
+
+#[test]
+fn test_any_of_ors_conditions_of_granting_policies() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource("tasks", SecurityPolicy::custom_field("owner_id"));
+    enforcer.add_policy_for_resource("tasks", SecurityPolicy::custom_field("assignee_id"));
+    enforcer.set_combinator("tasks", PolicyCombinator::AnyOf);
+
+    // 所有者または担当者の行
+    assert_eq!(
+        enforce(&enforcer, "tasks", &create_user(3, &[])),
+        Ok(vec![QueryCondition::Or {
+            conditions: vec![eq("owner_id", 3i64), eq("assignee_id", 3i64)],
+        }])
+    );
+}
+
+#[test]
+fn test_any_of_denies_when_nothing_grants() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource("payroll", requires("hr"));
+    enforcer.add_policy_for_resource("payroll", requires("finance"));
+    enforcer.set_combinator("payroll", PolicyCombinator::AnyOf);
+
+    assert!(enforce(&enforcer, "payroll", &create_user(1, &["finance"])).is_ok());
+    assert_eq!(
+        enforce(&enforcer, "payroll", &create_user(1, &[])),
+        Err("Access denied by policy: requires_hr".to_string())
+    );
+}
+
+#[test]
+fn test_deny_overrides_allow() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource("documents", requires("admin"));
+    enforcer.add_policy_for_resource("documents", requires("reader"));
+    enforcer.add_policy_for_resource(
+        "documents",
+        SecurityPolicy::deny("suspended", |user, _| user.has_permission("suspended")),
+    );
+    enforcer.set_combinator("documents", PolicyCombinator::DenyOverrides);
+
+    assert!(enforce(&enforcer, "documents", &create_user(1, &["reader"])).is_ok());
+    assert_eq!(
+        enforce(
+            &enforcer,
+            "documents",
+            &create_user(1, &["admin", "suspended"])
+        ),
+        Err("Access denied by policy: suspended".to_string())
+    );
+    assert!(enforce(&enforcer, "documents", &create_user(1, &[])).is_err());
+}
+
+#[test]
+fn test_deny_policy_wins_under_any_of() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource("documents", requires("reader"));
+    enforcer.add_policy_for_resource(
+        "documents",
+        SecurityPolicy::deny("suspended", |user, _| user.has_permission("suspended")),
+    );
+    enforcer.set_combinator("documents", PolicyCombinator::AnyOf);
+
+    // 許可ポリシーを満たしても拒否ポリシーは上書きされない
+    assert_eq!(
+        enforce(
+            &enforcer,
+            "documents",
+            &create_user(1, &["reader", "suspended"])
+        ),
+        Err("Access denied by policy: suspended".to_string())
+    );
+    assert!(enforce(&enforcer, "documents", &create_user(1, &["reader"])).is_ok());
+}
+
+#[test]
+fn test_denied_filter_field_wins_under_any_of() {
+    let document = PolicyDocument::from_toml(
+        r#"
+[[policies]]
+name = "salary_guard"
+resource = "employees"
+deny_filter_fields = ["salary"]
+
+[[policies]]
+name = "hr"
+resource = "employees"
+require_permissions = ["hr"]
+
+[combinators]
+employees = "any_of"
+"#,
+    )
+    .unwrap();
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.load_policies(document).unwrap();
+
+    let mut query = ConvertedQuery::new("employees".to_string());
+    query.add_condition(eq("salary", 1000i64));
+    let err = enforcer
+        .enforce(&mut query, &create_user(1, &["hr"]))
+        .unwrap_err();
+
+    assert_eq!(err.code(), "ACCESS_DENIED");
+    assert!(err.message().contains("salary"));
+}
+
+#[test]
+fn test_deny_policy_in_all_of() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource("orders", SecurityPolicy::custom_field("user_id"));
+    enforcer.add_policy_for_resource(
+        "orders",
+        SecurityPolicy::deny("no_guests", |user, _| user.has_permission("guest")),
+    );
+
+    assert_eq!(
+        enforce(&enforcer, "orders", &create_user(8, &[])),
+        Ok(vec![eq("user_id", 8i64)])
+    );
+    assert!(enforce(&enforcer, "orders", &create_user(8, &["guest"])).is_err());
+}
+
+#[test]
+fn test_only_deny_policies_grant_nothing() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource(
+        "secrets",
+        SecurityPolicy::deny("never_matches", |_, _| false),
+    );
+
+    assert_eq!(
+        enforce(&enforcer, "secrets", &create_user(1, &[])),
+        Err("Access denied: no policy grants access to 'secrets'".to_string())
+    );
+}
+
+#[test]
+fn test_default_policies_apply_to_every_resource() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_default_policy(requires("login"));
+    enforcer.add_policy_for_resource("projects", SecurityPolicy::custom_field("member_id"));
+
+    let user = create_user(4, &["login"]);
+
+    // 個別ポリシーのないリソースは user_id のデフォルトポリシーも適用される
+    assert_eq!(
+        enforce(&enforcer, "expenses", &user),
+        Ok(vec![eq("user_id", 4i64)])
+    );
+    assert_eq!(
+        enforce(&enforcer, "projects", &user),
+        Ok(vec![eq("member_id", 4i64)])
+    );
+    assert!(enforce(&enforcer, "expenses", &create_user(4, &[])).is_err());
+    assert!(enforce(&enforcer, "projects", &create_user(4, &[])).is_err());
+}
+
+#[test]
+fn test_wildcard_resource_and_default_combinator() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource("*", requires("staff"));
+    enforcer.add_policy_for_resource("wiki", SecurityPolicy::custom_field("author_id"));
+    enforcer.set_combinator("*", PolicyCombinator::AnyOf);
+
+    assert_eq!(enforcer.combinator("wiki"), PolicyCombinator::AnyOf);
+    assert_eq!(
+        enforce(&enforcer, "wiki", &create_user(6, &["staff"])),
+        Ok(vec![])
+    );
+    assert_eq!(
+        enforce(&enforcer, "wiki", &create_user(6, &[])),
+        Ok(vec![eq("author_id", 6i64)])
+    );
+}
+
+#[test]
+fn test_declarative_combinators_and_wildcard() {
+    let source = r#"
+[[policies]]
+name = "suspended"
+resource = "*"
+effect = "deny"
+require_permissions = ["suspended"]
+
+[[policies]]
+name = "managers"
+resource = "expenses"
+require_permissions = ["manager"]
+
+[[policies]]
+name = "owner"
+resource = "expenses"
+inject = [{ field = "owner_id", from = "user.id" }]
+
+[combinators]
+expenses = "deny_overrides"
+"#;
+    let document = PolicyDocument::from_toml(source).unwrap();
+    assert_eq!(document.policies[0].effect, PolicyEffect::Deny);
+    assert_eq!(
+        PolicyDocument::from_toml(&document.to_toml().unwrap()).unwrap(),
+        document
+    );
+
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.load_policies(document).unwrap();
+
+    assert_eq!(
+        enforcer.combinator("expenses"),
+        PolicyCombinator::DenyOverrides
+    );
+    assert_eq!(
+        enforce(&enforcer, "expenses", &create_user(2, &["manager"])),
+        Ok(vec![])
+    );
+    assert_eq!(
+        enforce(&enforcer, "expenses", &create_user(2, &[])),
+        Ok(vec![eq("owner_id", 2i64)])
+    );
+    assert_eq!(
+        enforce(
+            &enforcer,
+            "expenses",
+            &create_user(2, &["manager", "suspended"])
+        ),
+        Err("Access denied by policy: suspended (denied by rule)".to_string())
+    );
+    // 全リソースの拒否ルールは個別ポリシーのないリソースにも適用される
+    assert!(enforce(&enforcer, "orders", &create_user(2, &["suspended"])).is_err());
+}
+
+#[test]
+fn test_deny_rule_cannot_inject() {
+    let source = r#"
+[[policies]]
+name = "bad"
+resource = "expenses"
+effect = "deny"
+inject = [{ field = "owner_id", from = "user.id" }]
+"#;
+
+    let err = PolicyDocument::from_toml(source).unwrap_err();
+
+    assert_eq!(err.code(), "INVALID_POLICY");
+}
@@ -0,0 +1,360 @@
+// FR-11: Declarative RLS Policy Tests
+
+use liquid_reinhardt::converter::{ConvertedQuery, QueryCondition, QueryValue};
+use liquid_reinhardt::policy::{InjectOperator, PolicyDocument, PolicyValue};
+use liquid_reinhardt::security::{CurrentUser, SecurityEnforcer, SecurityPolicy};
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+const EXPENSES_POLICY: &str = r#"
+[[policies]]
+name = "expenses_owner"
+resource = "expenses"
+require_permissions = ["read:expenses"]
+deny_filter_fields = ["salary"]
+inject = [
+    { field = "owner_id", from = "user.id" },
+    { field = "archived", value = false },
+]
+
+[[policies]]
+name = "public_projects"
+resource = "projects"
+inject = [{ field = "visibility", op = "in", value = ["public", "internal"] }]
+"#;
+
+/// Creates a CurrentUser with the given ID and permission strings
+fn create_user(id: u64, permissions: &[&str]) -> CurrentUser {
+    CurrentUser::new(id, permissions.iter().map(|s| s.to_string()).collect())
+}
+
+/// Creates a SecurityEnforcer loaded with `EXPENSES_POLICY`
+fn create_enforcer() -> SecurityEnforcer {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.load_policies_toml(EXPENSES_POLICY).unwrap();
+    enforcer
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_load_toml_document() {
+    let document = PolicyDocument::from_toml(EXPENSES_POLICY).unwrap();
+
+    assert_eq!(document.policies.len(), 2);
+    let rule = &document.policies[0];
+    assert_eq!(rule.name, "expenses_owner");
+    assert_eq!(rule.require_permissions, vec!["read:expenses"]);
+    assert_eq!(rule.inject[0].op, InjectOperator::Eq);
+    assert_eq!(rule.inject[0].from.as_deref(), Some("user.id"));
+    assert_eq!(rule.inject[1].value, Some(PolicyValue::Boolean(false)));
+}
+
+#[test]
+fn test_load_json_document_matches_toml() {
+    let json = r#"{
+        "policies": [
+            {
+                "name": "expenses_owner",
+                "resource": "expenses",
+                "require_permissions": ["read:expenses"],
+                "deny_filter_fields": ["salary"],
+                "inject": [
+                    { "field": "owner_id", "from": "user.id" },
+                    { "field": "archived", "value": false }
+                ]
+            },
+            {
+                "name": "public_projects",
+                "resource": "projects",
+                "inject": [{ "field": "visibility", "op": "in", "value": ["public", "internal"] }]
+            }
+        ]
+    }"#;
+
+    assert_eq!(
+        PolicyDocument::from_json(json).unwrap(),
+        PolicyDocument::from_toml(EXPENSES_POLICY).unwrap()
+    );
+}
+
+#[test]
+fn test_declarative_policy_injects_conditions() {
+    let enforcer = create_enforcer();
+    let user = create_user(42, &["read:expenses"]);
+    let mut query = ConvertedQuery::new("expenses".to_string());
+
+    enforcer.enforce(&mut query, &user).unwrap();
+
+    assert_eq!(
+        query.system_conditions(),
+        &[
+            QueryCondition::Eq {
+                field: "owner_id".to_string(),
+                value: QueryValue::Integer(42),
+            },
+            QueryCondition::Eq {
+                field: "archived".to_string(),
+                value: QueryValue::Boolean(false),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_declarative_policy_in_operator() {
+    let enforcer = create_enforcer();
+    let user = create_user(1, &[]);
+    let mut query = ConvertedQuery::new("projects".to_string());
+
+    enforcer.enforce(&mut query, &user).unwrap();
+
+    assert_eq!(
+        query.system_conditions(),
+        &[QueryCondition::In {
+            field: "visibility".to_string(),
+            values: vec!["public".into(), "internal".into()],
+        }]
+    );
+}
+
+#[test]
+fn test_missing_permission_is_denied() {
+    let enforcer = create_enforcer();
+    let user = create_user(42, &[]);
+    let mut query = ConvertedQuery::new("expenses".to_string());
+
+    let err = enforcer.enforce(&mut query, &user).unwrap_err();
+
+    assert_eq!(err.code(), "ACCESS_DENIED");
+    assert!(err.message().contains("expenses_owner"));
+    assert!(err.message().contains("read:expenses"));
+    assert!(query.conditions().is_empty());
+}
+
+#[test]
+fn test_denied_filter_field_is_rejected() {
+    let enforcer = create_enforcer();
+    let user = create_user(42, &["read:expenses"]);
+    let mut query = ConvertedQuery::new("expenses".to_string());
+    query.add_condition(QueryCondition::Gt {
+        field: "salary".to_string(),
+        value: QueryValue::Integer(100000),
+    });
+
+    let err = enforcer.enforce(&mut query, &user).unwrap_err();
+
+    assert_eq!(err.code(), "ACCESS_DENIED");
+    assert!(err.message().contains("salary"));
+}
+
+#[test]
+fn test_resources_without_declarative_policy_use_default() {
+    let enforcer = create_enforcer();
+    let user = create_user(7, &[]);
+    let mut query = ConvertedQuery::new("orders".to_string());
+
+    enforcer.enforce(&mut query, &user).unwrap();
+
+    assert_eq!(
+        query.system_conditions(),
+        &[QueryCondition::Eq {
+            field: "user_id".to_string(),
+            value: QueryValue::Integer(7),
+        }]
+    );
+}
+
+#[test]
+fn test_security_policy_from_rule() {
+    let document = PolicyDocument::from_toml(EXPENSES_POLICY).unwrap();
+    let rule = document.policies[0].clone();
+
+    let policy = SecurityPolicy::from_rule(rule.clone()).unwrap();
+
+    assert_eq!(policy.name(), "expenses_owner");
+    assert_eq!(policy.rule(), Some(&rule));
+    assert_eq!(SecurityPolicy::new("closure", |_, _| true).rule(), None);
+}
+
+#[test]
+fn test_invalid_policies_are_rejected() {
+    let cases = [
+        // 未知のキー
+        "[[policies]]\nname = \"p\"\nresource = \"r\"\nallow = true\n",
+        // from と value の両方
+        "[[policies]]\nname = \"p\"\nresource = \"r\"\ninject = [{ field = \"a\", from = \"user.id\", value = 1 }]\n",
+        // from と value のどちらもない
+        "[[policies]]\nname = \"p\"\nresource = \"r\"\ninject = [{ field = \"a\" }]\n",
+        // user.* 以外の参照
+        "[[policies]]\nname = \"p\"\nresource = \"r\"\ninject = [{ field = \"a\", from = \"env.HOME\" }]\n",
+        // 未知の演算子
+        "[[policies]]\nname = \"p\"\nresource = \"r\"\ninject = [{ field = \"a\", op = \"like\", value = \"x\" }]\n",
+        // eq にリスト値
+        "[[policies]]\nname = \"p\"\nresource = \"r\"\ninject = [{ field = \"a\", value = [1, 2] }]\n",
+        // リソース名なし
+        "[[policies]]\nname = \"p\"\nresource = \"\"\n",
+    ];
+
+    for source in cases {
+        let err = PolicyDocument::from_toml(source).unwrap_err();
+        assert_eq!(err.code(), "INVALID_POLICY", "{}", source);
+
+        let mut enforcer = SecurityEnforcer::new();
+        assert!(enforcer.load_policies_toml(source).is_err());
+    }
+}
+
+#[test]
+fn test_unresolvable_user_reference_is_denied() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer
+        .load_policies_toml(
+            "[[policies]]\nname = \"by_tenant\"\nresource = \"expenses\"\ninject = [{ field = \"tenant_id\", from = \"user.tenant_id\" }]\n",
+        )
+        .unwrap();
+    let mut query = ConvertedQuery::new("expenses".to_string());
+
+    let err = enforcer
+        .enforce(&mut query, &create_user(1, &[]))
+        .unwrap_err();
+
+    assert_eq!(err.code(), "ACCESS_DENIED");
+    assert!(err.message().contains("tenant_id"));
+    assert!(query.conditions().is_empty());
+}
+
+const TENANT_POLICY: &str = r#"
+[[policies]]
+name = "org_scope"
+resource = "invoices"
+inject = [
+    { field = "org_id", from = "user.org_id" },
+    { field = "team_id", op = "in", from = "user.team_ids" },
+    { field = "region", op = "neq", value = "restricted" },
+]
+
+[[policies]]
+name = "role_scope"
+resource = "reports"
+inject = [{ field = "audience", op = "in", from = "user.roles" }]
+"#;
+
+/// Creates a B2B user scoped to organization 10 and teams 3, 4
+fn create_tenant_user() -> CurrentUser {
+    create_user(42, &[])
+        .with_roles(vec!["manager".to_string(), "auditor".to_string()])
+        .with_attribute("org_id", 10i64)
+        .with_attribute("team_ids", vec![3i64, 4])
+        .with_attribute("region", "eu")
+}
+
+#[test]
+fn test_inject_user_attributes() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.load_policies_toml(TENANT_POLICY).unwrap();
+    let mut query = ConvertedQuery::new("invoices".to_string());
+
+    enforcer.enforce(&mut query, &create_tenant_user()).unwrap();
+
+    assert_eq!(
+        query.system_conditions(),
+        &[
+            QueryCondition::Eq {
+                field: "org_id".to_string(),
+                value: QueryValue::Integer(10),
+            },
+            QueryCondition::In {
+                field: "team_id".to_string(),
+                values: vec![QueryValue::Integer(3), QueryValue::Integer(4)],
+            },
+            QueryCondition::Neq {
+                field: "region".to_string(),
+                value: "restricted".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_inject_user_roles() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.load_policies_toml(TENANT_POLICY).unwrap();
+    let mut query = ConvertedQuery::new("reports".to_string());
+
+    enforcer.enforce(&mut query, &create_tenant_user()).unwrap();
+
+    assert_eq!(
+        query.system_conditions(),
+        &[QueryCondition::In {
+            field: "audience".to_string(),
+            values: vec!["manager".into(), "auditor".into()],
+        }]
+    );
+}
+
+#[test]
+fn test_missing_tenant_attribute_is_denied() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.load_policies_toml(TENANT_POLICY).unwrap();
+    let mut query = ConvertedQuery::new("invoices".to_string());
+
+    // org_id / team_ids を持たないユーザー
+    let err = enforcer
+        .enforce(&mut query, &create_user(42, &[]))
+        .unwrap_err();
+
+    assert_eq!(err.code(), "ACCESS_DENIED");
+    assert!(err.message().contains("org_id"));
+    assert!(query.conditions().is_empty());
+}
+
+#[test]
+fn test_list_attribute_with_eq_is_denied() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer
+        .load_policies_toml(
+            "[[policies]]\nname = \"teams\"\nresource = \"tasks\"\ninject = [{ field = \"team_id\", from = \"user.team_ids\" }]\n",
+        )
+        .unwrap();
+    let mut query = ConvertedQuery::new("tasks".to_string());
+
+    let err = enforcer
+        .enforce(&mut query, &create_tenant_user())
+        .unwrap_err();
+
+    assert_eq!(err.code(), "ACCESS_DENIED");
+    assert!(err.message().contains("team_id"));
+}
+
+#[test]
+fn test_toml_roundtrip() {
+    let document = PolicyDocument::from_toml(EXPENSES_POLICY).unwrap();
+
+    let serialized = document.to_toml().unwrap();
+
+    assert_eq!(PolicyDocument::from_toml(&serialized).unwrap(), document);
+}
+
+#[test]
+fn test_load_from_path() {
+    let dir = std::env::temp_dir().join(format!("liquid-policy-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let toml_path = dir.join("policies.toml");
+    let text_path = dir.join("policies.txt");
+    std::fs::write(&toml_path, EXPENSES_POLICY).unwrap();
+    std::fs::write(&text_path, EXPENSES_POLICY).unwrap();
+
+    let document = PolicyDocument::from_path(&toml_path).unwrap();
+    let unsupported = PolicyDocument::from_path(&text_path).unwrap_err();
+    let missing = PolicyDocument::from_path(dir.join("missing.toml")).unwrap_err();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(document.policies.len(), 2);
+    assert_eq!(unsupported.code(), "INVALID_POLICY");
+    assert_eq!(missing.code(), "INVALID_POLICY");
+}
@@ -0,0 +1,156 @@
+use liquid_protocol::{
+    Aggregation, AggregationType, DataSource, Filter, FilterOperator, FilterValue, Sort,
+    SortDirection, SortKeys,
+};
+use liquid_reinhardt::converter::DataSourceConverter;
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+
+// FR-10: Resource Registry Tests
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a registry with a single `expenses` resource
+fn create_registry() -> ResourceRegistry {
+    ResourceRegistry::new().with_resource(
+        ResourceDefinition::new("expenses")
+            .field("id", FieldType::Integer)
+            .field("category", FieldType::String)
+            .field("amount", FieldType::Float)
+            .field("month", FieldType::String),
+    )
+}
+
+/// Creates a converter restricted by the test registry
+fn create_converter() -> DataSourceConverter {
+    DataSourceConverter::with_registry(create_registry())
+}
+
+/// Creates a simple DataSource with only resource name
+fn create_simple_data_source(resource: &str) -> DataSource {
+    DataSource {
+        resource: resource.to_string(),
+        filters: None,
+        aggregation: None,
+        metrics: None,
+        group_by: None,
+        sort: None,
+        limit: None,
+        page: None,
+    }
+}
+
+fn create_filter(field: &str) -> Filter {
+    Filter {
+        field: field.to_string(),
+        op: FilterOperator::Eq,
+        value: FilterValue::String("x".to_string()),
+    }
+}
+
+fn create_sort(field: &str) -> SortKeys {
+    Sort {
+        field: field.to_string(),
+        direction: SortDirection::Asc,
+        nulls: None,
+    }
+    .into()
+}
+
+fn sum_by(field: &str, by: Option<&str>) -> Aggregation {
+    Aggregation {
+        agg_type: AggregationType::Sum,
+        field: field.to_string(),
+        by: by.map(|b| b.to_string()),
+        bucket: None,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_registered_resource_and_fields_are_accepted() {
+    let mut ds = create_simple_data_source("expenses");
+    ds.filters = Some(vec![create_filter("category").into()]);
+    ds.aggregation = Some(sum_by("amount", Some("month")));
+    ds.sort = Some(create_sort("month"));
+
+    let query = create_converter().convert(&ds).unwrap();
+
+    assert_eq!(query.resource(), "expenses");
+    assert_eq!(query.conditions().len(), 1);
+}
+
+#[test]
+fn test_unknown_resource_is_rejected() {
+    let ds = create_simple_data_source("admin_users");
+
+    let err = create_converter().convert(&ds).unwrap_err();
+
+    assert_eq!(err.code(), "UNKNOWN_RESOURCE");
+}
+
+#[test]
+fn test_unknown_filter_field_is_rejected() {
+    let mut ds = create_simple_data_source("expenses");
+    ds.filters = Some(vec![create_filter("password_hash").into()]);
+
+    let err = create_converter().convert(&ds).unwrap_err();
+
+    assert_eq!(err.code(), "UNKNOWN_FIELD");
+    assert!(err.message().contains("password_hash"));
+}
+
+#[test]
+fn test_unknown_aggregation_field_is_rejected() {
+    let mut ds = create_simple_data_source("expenses");
+    ds.aggregation = Some(sum_by("salary", None));
+
+    let err = create_converter().convert(&ds).unwrap_err();
+
+    assert_eq!(err.code(), "UNKNOWN_FIELD");
+}
+
+#[test]
+fn test_unknown_group_by_field_is_rejected() {
+    let mut ds = create_simple_data_source("expenses");
+    ds.aggregation = Some(sum_by("amount", Some("department")));
+
+    let err = create_converter().convert(&ds).unwrap_err();
+
+    assert_eq!(err.code(), "UNKNOWN_FIELD");
+}
+
+#[test]
+fn test_unknown_sort_field_is_rejected() {
+    let mut ds = create_simple_data_source("expenses");
+    ds.sort = Some(create_sort("created_by"));
+
+    let err = create_converter().convert(&ds).unwrap_err();
+
+    assert_eq!(err.code(), "UNKNOWN_FIELD");
+}
+
+#[test]
+fn test_sort_by_aggregation_alias_is_accepted() {
+    let mut ds = create_simple_data_source("expenses");
+    ds.aggregation = Some(sum_by("amount", Some("category")));
+    ds.sort = Some(create_sort("amount_sum"));
+
+    let result = create_converter().convert(&ds);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_converter_without_registry_accepts_anything() {
+    let mut ds = create_simple_data_source("admin_users");
+    ds.filters = Some(vec![create_filter("password_hash").into()]);
+
+    let result = DataSourceConverter::new().convert(&ds);
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,216 @@
+// FR-06: Relative Date Filter Tests
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use liquid_protocol::{DataSource, Filter, FilterOperator, FilterValue, RelativeDate};
+use liquid_reinhardt::clock::{start_of_day, FixedClock, Tz};
+use liquid_reinhardt::converter::{DataSourceConverter, QueryCondition, QueryValue};
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use liquid_reinhardt::sql::{SqlBuilder, SqlValue};
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a converter with a fixed clock whose `expenses` resource declares typed fields
+fn create_converter(now: DateTime<Utc>, timezone: Tz) -> DataSourceConverter {
+    DataSourceConverter::with_registry(
+        ResourceRegistry::new().with_resource(
+            ResourceDefinition::new("expenses")
+                .field("date", FieldType::Date)
+                .field("created_at", FieldType::DateTime)
+                .field("amount", FieldType::Integer)
+                .field("memo", FieldType::String),
+        ),
+    )
+    .with_clock(FixedClock::new(now))
+    .with_timezone(timezone)
+}
+
+/// Creates an `expenses` DataSource with a single relative date filter
+fn create_data_source(field: &str, op: FilterOperator, relative: RelativeDate) -> DataSource {
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: Some(vec![Filter {
+            field: field.to_string(),
+            op,
+            value: FilterValue::Relative(relative),
+        }
+        .into()]),
+        aggregation: None,
+        metrics: None,
+        group_by: None,
+        sort: None,
+        limit: None,
+        page: None,
+    }
+}
+
+/// Resolves a relative date on the `date` field and returns the bound
+fn resolve(now: DateTime<Utc>, timezone: Tz, relative: RelativeDate) -> QueryValue {
+    let ds = create_data_source("date", FilterOperator::Gte, relative);
+    let query = create_converter(now, timezone).convert(&ds).unwrap();
+    match &query.conditions()[0] {
+        QueryCondition::Gte { value, .. } => value.clone(),
+        other => panic!("unexpected condition: {:?}", other),
+    }
+}
+
+fn utc(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+}
+
+fn date(y: i32, m: u32, d: u32) -> QueryValue {
+    QueryValue::Date(NaiveDate::from_ymd_opt(y, m, d).unwrap())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_relative_dates_resolve_against_clock() {
+    // 2024-05-15 (Wednesday)
+    let now = utc(2024, 5, 15, 12);
+
+    let cases = [
+        (RelativeDate::Today, date(2024, 5, 15)),
+        (RelativeDate::Yesterday, date(2024, 5, 14)),
+        (RelativeDate::StartOfWeek, date(2024, 5, 13)),
+        (RelativeDate::StartOfMonth, date(2024, 5, 1)),
+        (RelativeDate::StartOfQuarter, date(2024, 4, 1)),
+        (RelativeDate::StartOfYear, date(2024, 1, 1)),
+        (RelativeDate::LastNDays { n: 30 }, date(2024, 4, 15)),
+        (RelativeDate::LastNMonths { n: 3 }, date(2024, 2, 15)),
+    ];
+
+    for (relative, expected) in cases {
+        assert_eq!(resolve(now, Tz::UTC, relative), expected, "{:?}", relative);
+    }
+}
+
+#[test]
+fn test_last_n_months_clamps_to_month_end() {
+    let value = resolve(
+        utc(2024, 5, 31, 12),
+        Tz::UTC,
+        RelativeDate::LastNMonths { n: 3 },
+    );
+
+    assert_eq!(value, date(2024, 2, 29));
+}
+
+#[test]
+fn test_timezone_decides_the_current_day() {
+    // 2024-05-31 20:00 UTC is already 2024-06-01 in Tokyo
+    let now = utc(2024, 5, 31, 20);
+
+    assert_eq!(
+        resolve(now, Tz::UTC, RelativeDate::StartOfMonth),
+        date(2024, 5, 1)
+    );
+    assert_eq!(
+        resolve(now, chrono_tz::Asia::Tokyo, RelativeDate::StartOfMonth),
+        date(2024, 6, 1)
+    );
+}
+
+#[test]
+fn test_datetime_field_uses_local_midnight() {
+    let ds = create_data_source(
+        "created_at",
+        FilterOperator::Gte,
+        RelativeDate::StartOfMonth,
+    );
+
+    let query = create_converter(utc(2024, 5, 15, 12), chrono_tz::Asia::Tokyo)
+        .convert(&ds)
+        .unwrap();
+
+    assert_eq!(
+        query.conditions()[0],
+        QueryCondition::Gte {
+            field: "created_at".to_string(),
+            value: QueryValue::DateTime(utc(2024, 4, 30, 15)),
+        }
+    );
+}
+
+#[test]
+fn test_start_of_day_skips_missing_midnight() {
+    // Santiago moves clocks from 00:00 to 01:00 on 2024-09-08
+    let day = NaiveDate::from_ymd_opt(2024, 9, 8).unwrap();
+
+    assert_eq!(
+        start_of_day(day, chrono_tz::America::Santiago),
+        utc(2024, 9, 8, 4)
+    );
+}
+
+#[test]
+fn test_saved_data_source_follows_the_clock() {
+    let ds = create_data_source("date", FilterOperator::Gte, RelativeDate::StartOfMonth);
+
+    let may = create_converter(utc(2024, 5, 15, 12), Tz::UTC)
+        .convert(&ds)
+        .unwrap();
+    let june = create_converter(utc(2024, 6, 15, 12), Tz::UTC)
+        .convert(&ds)
+        .unwrap();
+
+    assert_ne!(may.conditions(), june.conditions());
+}
+
+#[test]
+fn test_relative_date_on_non_date_field_is_rejected() {
+    let converter = create_converter(utc(2024, 5, 15, 12), Tz::UTC);
+
+    for (field, op) in [
+        ("amount", FilterOperator::Gte),
+        ("memo", FilterOperator::Eq),
+        ("memo", FilterOperator::Contains),
+    ] {
+        let ds = create_data_source(field, op, RelativeDate::Today);
+        let err = converter.convert(&ds).unwrap_err();
+        assert_eq!(err.code(), "FILTER_TYPE_MISMATCH", "{}", field);
+    }
+}
+
+#[test]
+fn test_untyped_field_resolves_to_date() {
+    let ds = create_data_source("date", FilterOperator::Lt, RelativeDate::Today);
+
+    let query = DataSourceConverter::new()
+        .with_clock(FixedClock::new(utc(2024, 5, 15, 12)))
+        .convert(&ds)
+        .unwrap();
+
+    assert_eq!(
+        query.conditions()[0],
+        QueryCondition::Lt {
+            field: "date".to_string(),
+            value: date(2024, 5, 15),
+        }
+    );
+}
+
+#[test]
+fn test_resolved_bound_is_bound_as_parameter() {
+    let ds = create_data_source(
+        "date",
+        FilterOperator::Gte,
+        RelativeDate::LastNDays { n: 30 },
+    );
+    let query = create_converter(utc(2024, 5, 15, 12), Tz::UTC)
+        .convert(&ds)
+        .unwrap();
+
+    let sql = SqlBuilder::new().build(&query).unwrap();
+
+    assert_eq!(sql.sql(), "SELECT * FROM \"expenses\" WHERE \"date\" >= $1");
+    assert_eq!(
+        sql.params(),
+        &[SqlValue::Date(
+            NaiveDate::from_ymd_opt(2024, 4, 15).unwrap()
+        )]
+    );
+}
@@ -1,7 +1,7 @@
-use liquid_reinhardt::security::{CurrentUser, SecurityEnforcer, SecurityPolicy};
-use liquid_reinhardt::converter::{ConvertedQuery, QueryCondition};
+use liquid_reinhardt::security::{CurrentUser, SecurityEnforcer, SecurityPolicy, UserAttribute};
+use liquid_reinhardt::converter::{ConvertedQuery, QueryCondition, QueryValue};
 
-/// FR-07: Row-Level Security Tests (TDD Red Phase)
+// FR-07: Row-Level Security Tests (TDD Red Phase)
 
 // ============================================================================
 // Test Helper Functions
@@ -26,11 +26,16 @@ fn create_query(resource: &str) -> ConvertedQuery {
 }
 
 /// Asserts that a QueryCondition is an Eq condition with the expected field and value
-fn assert_eq_condition(condition: &QueryCondition, expected_field: &str, expected_value: &str) {
+fn assert_eq_condition(
+    condition: &QueryCondition,
+    expected_field: &str,
+    expected_value: impl Into<QueryValue>,
+) {
+    let expected_value = expected_value.into();
     match condition {
         QueryCondition::Eq { field, value } => {
             assert_eq!(field, expected_field);
-            assert_eq!(value, expected_value);
+            assert_eq!(value, &expected_value);
         }
         _ => panic!("Expected Eq condition for {}", expected_field),
     }
@@ -62,10 +67,11 @@ fn test_enforce_default_policy_adds_user_id_filter() {
     assert!(result.is_ok());
 
     // デフォルトポリシー: WHERE user_id = current_user.id
-    let conditions = query.conditions();
+    assert!(query.conditions().is_empty());
+    let conditions = query.system_conditions();
     assert_eq!(conditions.len(), 1);
 
-    assert_eq_condition(&conditions[0], "user_id", "42");
+    assert_eq_condition(&conditions[0], "user_id", 42i64);
 }
 
 #[test]
@@ -76,21 +82,21 @@ fn test_enforce_preserves_existing_filters() {
     let mut query = create_query("expenses");
     query.add_condition(QueryCondition::Eq {
         field: "category".to_string(),
-        value: "food".to_string(),
+        value: "food".into(),
     });
 
     let result = enforcer.enforce(&mut query, &user);
     assert!(result.is_ok());
 
-    // 既存のフィルタ + RLSフィルタ
+    // 既存のフィルタはユーザー条件のまま
     let conditions = query.conditions();
-    assert_eq!(conditions.len(), 2);
-
-    // 最初の条件は既存のまま
+    assert_eq!(conditions.len(), 1);
     assert_eq_condition(&conditions[0], "category", "food");
 
-    // 2番目の条件はRLS
-    assert_eq_condition(&conditions[1], "user_id", "100");
+    // RLSフィルタはシステム条件
+    let system_conditions = query.system_conditions();
+    assert_eq!(system_conditions.len(), 1);
+    assert_eq_condition(&system_conditions[0], "user_id", 100i64);
 }
 
 #[test]
@@ -150,10 +156,10 @@ fn test_policy_with_custom_field() {
 
     assert!(result.is_ok());
 
-    let conditions = query.conditions();
+    let conditions = query.system_conditions();
     assert_eq!(conditions.len(), 1);
 
-    assert_eq_condition(&conditions[0], "owner_id", "200");
+    assert_eq_condition(&conditions[0], "owner_id", 200i64);
 }
 
 #[test]
@@ -168,7 +174,7 @@ fn test_enforce_multiple_resources() {
         let result = enforcer.enforce(&mut query, &user);
 
         assert!(result.is_ok());
-        assert_eq!(query.conditions().len(), 1);
+        assert_eq!(query.system_conditions().len(), 1);
     }
 }
 
@@ -196,12 +202,52 @@ fn test_enforce_idempotency() {
 
     // 1回目
     enforcer.enforce(&mut query, &user).unwrap();
-    let conditions_1 = query.conditions().len();
+    let conditions_1 = query.system_conditions().len();
 
     // 2回目（既にRLSフィルタがある状態）
     enforcer.enforce(&mut query, &user).unwrap();
-    let conditions_2 = query.conditions().len();
+    let conditions_2 = query.system_conditions().len();
 
     // 冪等性確認: 2回目でも追加されない（実装次第で調整必要）
     assert_eq!(conditions_2, conditions_1 * 2);  // この実装では累積
 }
+
+#[test]
+fn test_current_user_roles_and_attributes() {
+    let user = create_user(1, &[])
+        .with_roles(vec!["admin".to_string()])
+        .with_attribute("org_id", 10i64)
+        .with_attribute("team_ids", vec![3i64, 4]);
+
+    assert!(user.has_role("admin"));
+    assert!(!user.has_role("viewer"));
+    assert_eq!(
+        user.attribute("org_id"),
+        Some(&UserAttribute::Single(QueryValue::Integer(10)))
+    );
+    assert_eq!(
+        user.attribute("team_ids").map(UserAttribute::values),
+        Some(vec![QueryValue::Integer(3), QueryValue::Integer(4)])
+    );
+    assert_eq!(user.attribute("region"), None);
+    assert_eq!(user.attributes().len(), 2);
+}
+
+#[test]
+fn test_custom_policy_can_use_attributes() {
+    let mut enforcer = create_enforcer();
+    let policy = SecurityPolicy::new("eu_only", |user, _query| {
+        user.attribute("region") == Some(&UserAttribute::from("eu"))
+    });
+    enforcer.add_policy_for_resource("customers", policy);
+
+    let eu_user = create_user(1, &[]).with_attribute("region", "eu");
+    let us_user = create_user(2, &[]).with_attribute("region", "us");
+
+    assert!(enforcer
+        .enforce(&mut create_query("customers"), &eu_user)
+        .is_ok());
+    assert!(enforcer
+        .enforce(&mut create_query("customers"), &us_user)
+        .is_err());
+}
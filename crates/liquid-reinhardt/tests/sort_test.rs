@@ -0,0 +1,184 @@
+// FR-06: Multi-Key Sort Tests
+
+use liquid_protocol::{
+    AggregationType, DataSource, Metric, NullsOrder, Sort, SortDirection, SortKeys,
+};
+use liquid_reinhardt::converter::{DataSourceConverter, QuerySort};
+use liquid_reinhardt::dialect::{Dialect, MySqlDialect, PostgresDialect, SqliteDialect};
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use liquid_reinhardt::sql::SqlBuilder;
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a converter whose `expenses` resource declares typed fields
+fn create_converter() -> DataSourceConverter {
+    DataSourceConverter::with_registry(
+        ResourceRegistry::new().with_resource(
+            ResourceDefinition::new("expenses")
+                .field("amount", FieldType::Integer)
+                .field("category", FieldType::String)
+                .field("memo", FieldType::String),
+        ),
+    )
+}
+
+fn sort(field: &str, direction: SortDirection, nulls: Option<NullsOrder>) -> Sort {
+    Sort {
+        field: field.to_string(),
+        direction,
+        nulls,
+    }
+}
+
+/// Creates an `expenses` DataSource sorted by category, then amount descending (nulls last)
+fn create_data_source() -> DataSource {
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: None,
+        aggregation: None,
+        metrics: None,
+        group_by: None,
+        sort: Some(
+            vec![
+                sort("category", SortDirection::Asc, None),
+                sort("amount", SortDirection::Desc, Some(NullsOrder::Last)),
+            ]
+            .into(),
+        ),
+        limit: None,
+        page: None,
+    }
+}
+
+/// Creates a per-category total sorted by the given keys
+fn create_aggregated_source(sort: SortKeys) -> DataSource {
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: None,
+        aggregation: None,
+        metrics: Some(vec![Metric {
+            alias: "total".to_string(),
+            agg_type: AggregationType::Sum,
+            field: "amount".to_string(),
+        }]),
+        group_by: Some(vec!["category".to_string()]),
+        sort: Some(sort),
+        limit: None,
+        page: None,
+    }
+}
+
+/// Builds the SQL for the multi-key DataSource with the given dialect
+fn build_with(dialect: impl Dialect + 'static) -> String {
+    let query = create_converter().convert(&create_data_source()).unwrap();
+    SqlBuilder::with_dialect(dialect)
+        .build(&query)
+        .unwrap()
+        .sql()
+        .to_string()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_sort_keys_convert_in_order() {
+    let query = create_converter().convert(&create_data_source()).unwrap();
+
+    assert_eq!(
+        query.sort(),
+        [
+            QuerySort::new("category".to_string(), SortDirection::Asc),
+            QuerySort::new("amount".to_string(), SortDirection::Desc).with_nulls(NullsOrder::Last),
+        ]
+    );
+}
+
+#[test]
+fn test_postgres_and_sqlite_order_by() {
+    let order_by = "ORDER BY \"category\" ASC, \"amount\" DESC NULLS LAST";
+
+    assert_eq!(
+        build_with(PostgresDialect),
+        format!("SELECT * FROM \"expenses\" {}", order_by)
+    );
+    assert_eq!(
+        build_with(SqliteDialect),
+        format!("SELECT * FROM \"expenses\" {}", order_by)
+    );
+}
+
+#[test]
+fn test_mysql_emulates_nulls_order() {
+    assert_eq!(
+        build_with(MySqlDialect),
+        "SELECT * FROM `expenses` ORDER BY `category` ASC, `amount` IS NULL ASC, `amount` DESC"
+    );
+}
+
+#[test]
+fn test_aggregated_sort_uses_result_columns() {
+    let keys = vec![
+        sort("total", SortDirection::Desc, Some(NullsOrder::First)),
+        sort("category", SortDirection::Asc, None),
+    ];
+    let query = create_converter()
+        .convert(&create_aggregated_source(keys.into()))
+        .unwrap();
+
+    let sql = SqlBuilder::new().build(&query).unwrap();
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT \"category\", SUM(\"amount\") AS \"total\" FROM \"expenses\" \
+         GROUP BY \"category\" ORDER BY \"total\" DESC NULLS FIRST, \"category\" ASC"
+    );
+}
+
+#[test]
+fn test_aggregated_sort_outside_result_is_rejected() {
+    let converter = create_converter();
+    let cases = [
+        // A registered field that is neither grouped nor aggregated
+        create_aggregated_source(sort("memo", SortDirection::Asc, None).into()),
+        // A later key of a list
+        create_aggregated_source(
+            vec![
+                sort("category", SortDirection::Asc, None),
+                sort("amount", SortDirection::Desc, None),
+            ]
+            .into(),
+        ),
+    ];
+
+    // Checked with and without a registry
+    for ds in cases {
+        assert_eq!(
+            converter.convert(&ds).unwrap_err().code(),
+            "INVALID_SORT_FIELD"
+        );
+        assert_eq!(
+            DataSourceConverter::new().convert(&ds).unwrap_err().code(),
+            "INVALID_SORT_FIELD"
+        );
+    }
+}
+
+#[test]
+fn test_unknown_sort_key_is_rejected() {
+    let mut ds = create_data_source();
+    ds.sort = Some(
+        vec![
+            sort("category", SortDirection::Asc, None),
+            sort("created_by", SortDirection::Asc, None),
+        ]
+        .into(),
+    );
+
+    let err = create_converter().convert(&ds).unwrap_err();
+
+    assert_eq!(err.code(), "UNKNOWN_FIELD");
+}
@@ -0,0 +1,354 @@
+use liquid_protocol::{AggregationType, SortDirection};
+use liquid_reinhardt::converter::{
+    ConvertedQuery, QueryAggregation, QueryCondition, QuerySort, QueryValue,
+};
+use liquid_reinhardt::dialect::{Dialect, MySqlDialect, PostgresDialect, SqliteDialect};
+use liquid_reinhardt::sql::{SqlBuilder, SqlQuery, SqlValue};
+
+// FR-08: Parameterized SQL Generation Tests
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a ConvertedQuery with the given conditions
+fn create_query(resource: &str, conditions: Vec<QueryCondition>) -> ConvertedQuery {
+    let mut query = ConvertedQuery::new(resource.to_string());
+    for condition in conditions {
+        query.add_condition(condition);
+    }
+    query
+}
+
+/// Builds SQL for a query and unwraps the result
+fn build(query: &ConvertedQuery) -> SqlQuery {
+    SqlBuilder::new().build(query).unwrap()
+}
+
+/// Builds SQL for a query using the given dialect
+fn build_with(dialect: impl Dialect + 'static, query: &ConvertedQuery) -> SqlQuery {
+    SqlBuilder::with_dialect(dialect).build(query).unwrap()
+}
+
+/// Creates the query used by the dialect comparison tests
+fn create_dialect_query() -> ConvertedQuery {
+    let mut query = create_query(
+        "expenses",
+        vec![
+            QueryCondition::Eq {
+                field: "user_id".to_string(),
+                value: "7".into(),
+            },
+            QueryCondition::IContains {
+                field: "memo".to_string(),
+                value: "Lunch".into(),
+            },
+        ],
+    );
+    query.add_sort(QuerySort::new("amount".to_string(), SortDirection::Desc));
+    query.set_limit(5);
+    query
+}
+
+fn text(value: &str) -> SqlValue {
+    SqlValue::Text(value.to_string())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_build_resource_only() {
+    let query = create_query("users", vec![]);
+    let sql = build(&query);
+
+    assert_eq!(sql.sql(), "SELECT * FROM \"users\"");
+    assert!(sql.params().is_empty());
+}
+
+#[test]
+fn test_build_all_condition_variants() {
+    let query = create_query(
+        "expenses",
+        vec![
+            QueryCondition::Eq {
+                field: "category".to_string(),
+                value: "food".into(),
+            },
+            QueryCondition::Neq {
+                field: "status".to_string(),
+                value: "void".into(),
+            },
+            QueryCondition::Gt {
+                field: "amount".to_string(),
+                value: QueryValue::Float(10.0),
+            },
+            QueryCondition::Gte {
+                field: "amount".to_string(),
+                value: QueryValue::Float(20.0),
+            },
+            QueryCondition::Lt {
+                field: "amount".to_string(),
+                value: QueryValue::Float(30.0),
+            },
+            QueryCondition::Lte {
+                field: "amount".to_string(),
+                value: QueryValue::Float(40.0),
+            },
+            QueryCondition::In {
+                field: "month".to_string(),
+                values: vec!["1".into(), "2".into()],
+            },
+            QueryCondition::IContains {
+                field: "memo".to_string(),
+                value: "lunch".into(),
+            },
+        ],
+    );
+    let sql = build(&query);
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT * FROM \"expenses\" WHERE \"category\" = $1 AND \"status\" <> $2 \
+         AND \"amount\" > $3 AND \"amount\" >= $4 AND \"amount\" < $5 AND \"amount\" <= $6 \
+         AND \"month\" IN ($7, $8) AND \"memo\" ILIKE $9 ESCAPE '\\'"
+    );
+    assert_eq!(
+        sql.params(),
+        &[
+            text("food"),
+            text("void"),
+            SqlValue::Real(10.0),
+            SqlValue::Real(20.0),
+            SqlValue::Real(30.0),
+            SqlValue::Real(40.0),
+            text("1"),
+            text("2"),
+            text("%lunch%"),
+        ]
+    );
+}
+
+#[test]
+fn test_build_with_limit() {
+    let mut query = create_query("posts", vec![]);
+    query.set_limit(10);
+    let sql = build(&query);
+
+    assert_eq!(sql.sql(), "SELECT * FROM \"posts\" LIMIT 10");
+}
+
+#[test]
+fn test_build_with_aggregation_and_group_by() {
+    let mut query = create_query(
+        "expenses",
+        vec![QueryCondition::Eq {
+            field: "user_id".to_string(),
+            value: "1".into(),
+        }],
+    );
+    query.set_aggregation(QueryAggregation::new(
+        AggregationType::Sum,
+        "amount".to_string(),
+        Some("month".to_string()),
+    ));
+    query.add_sort(QuerySort::new("month".to_string(), SortDirection::Asc));
+    query.set_limit(12);
+    let sql = build(&query);
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT \"month\", SUM(\"amount\") AS \"amount_sum\" FROM \"expenses\" \
+         WHERE \"user_id\" = $1 GROUP BY \"month\" ORDER BY \"month\" ASC LIMIT 12"
+    );
+    assert_eq!(sql.params(), &[text("1")]);
+}
+
+#[test]
+fn test_build_with_aggregation_without_group_by() {
+    let mut query = create_query("orders", vec![]);
+    query.set_aggregation(QueryAggregation::new(
+        AggregationType::Count,
+        "id".to_string(),
+        None,
+    ));
+    let sql = build(&query);
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT COUNT(\"id\") AS \"id_count\" FROM \"orders\""
+    );
+}
+
+#[test]
+fn test_build_aggregation_functions() {
+    let cases = [
+        (AggregationType::Sum, "SUM(\"amount\") AS \"amount_sum\""),
+        (AggregationType::Avg, "AVG(\"amount\") AS \"amount_avg\""),
+        (
+            AggregationType::Count,
+            "COUNT(\"amount\") AS \"amount_count\"",
+        ),
+        (AggregationType::Min, "MIN(\"amount\") AS \"amount_min\""),
+        (AggregationType::Max, "MAX(\"amount\") AS \"amount_max\""),
+    ];
+
+    for (agg_type, expected) in cases {
+        let mut query = create_query("expenses", vec![]);
+        query.set_aggregation(QueryAggregation::new(agg_type, "amount".to_string(), None));
+        let sql = build(&query);
+        assert_eq!(sql.sql(), format!("SELECT {} FROM \"expenses\"", expected));
+    }
+}
+
+#[test]
+fn test_build_with_sort_desc() {
+    let mut query = create_query("posts", vec![]);
+    query.add_sort(QuerySort::new(
+        "created_at".to_string(),
+        SortDirection::Desc,
+    ));
+    let sql = build(&query);
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT * FROM \"posts\" ORDER BY \"created_at\" DESC"
+    );
+}
+
+#[test]
+fn test_injection_attempt_is_bound_not_interpolated() {
+    let payload = "1 OR 1=1; DROP TABLE users;";
+    let query = create_query(
+        "users",
+        vec![QueryCondition::Eq {
+            field: "id".to_string(),
+            value: payload.into(),
+        }],
+    );
+    let sql = build(&query);
+
+    assert_eq!(sql.sql(), "SELECT * FROM \"users\" WHERE \"id\" = $1");
+    assert!(!sql.sql().contains("DROP"));
+    assert_eq!(sql.params(), &[text(payload)]);
+}
+
+#[test]
+fn test_contains_escapes_like_wildcards() {
+    let query = create_query(
+        "products",
+        vec![QueryCondition::Contains {
+            field: "name".to_string(),
+            value: "100%_pure".into(),
+        }],
+    );
+    let sql = build(&query);
+
+    assert_eq!(sql.params(), &[text("%100\\%\\_pure%")]);
+}
+
+#[test]
+fn test_empty_in_list_matches_nothing() {
+    let query = create_query(
+        "orders",
+        vec![QueryCondition::In {
+            field: "status".to_string(),
+            values: vec![],
+        }],
+    );
+    let sql = build(&query);
+
+    assert_eq!(sql.sql(), "SELECT * FROM \"orders\" WHERE 1 = 0");
+    assert!(sql.params().is_empty());
+}
+
+#[test]
+fn test_error_on_invalid_field_identifier() {
+    let query = create_query(
+        "users",
+        vec![QueryCondition::Eq {
+            field: "id = 1 OR 1".to_string(),
+            value: "1".into(),
+        }],
+    );
+    let result = SqlBuilder::new().build(&query);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), "INVALID_IDENTIFIER");
+}
+
+#[test]
+fn test_error_on_invalid_resource_identifier() {
+    let query = create_query("users; DROP TABLE users", vec![]);
+    let result = SqlBuilder::new().build(&query);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), "INVALID_IDENTIFIER");
+}
+
+#[test]
+fn test_postgres_dialect() {
+    let sql = build_with(PostgresDialect, &create_dialect_query());
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT * FROM \"expenses\" WHERE \"user_id\" = $1 AND \"memo\" ILIKE $2 ESCAPE '\\' \
+         ORDER BY \"amount\" DESC LIMIT 5"
+    );
+    assert_eq!(sql.params(), &[text("7"), text("%Lunch%")]);
+}
+
+#[test]
+fn test_sqlite_dialect() {
+    let sql = build_with(SqliteDialect, &create_dialect_query());
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT * FROM \"expenses\" WHERE \"user_id\" = ? \
+         AND LOWER(\"memo\") LIKE LOWER(?) ESCAPE '\\' ORDER BY \"amount\" DESC LIMIT 5"
+    );
+    assert_eq!(sql.params(), &[text("7"), text("%Lunch%")]);
+}
+
+#[test]
+fn test_mysql_dialect() {
+    let sql = build_with(MySqlDialect, &create_dialect_query());
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT * FROM `expenses` WHERE `user_id` = ? \
+         AND LOWER(`memo`) LIKE LOWER(?) ESCAPE '\\\\' ORDER BY `amount` DESC LIMIT 5"
+    );
+    assert_eq!(sql.params(), &[text("7"), text("%Lunch%")]);
+}
+
+#[test]
+fn test_mysql_dialect_quotes_aggregation() {
+    let mut query = create_query("expenses", vec![]);
+    query.set_aggregation(QueryAggregation::new(
+        AggregationType::Avg,
+        "amount".to_string(),
+        Some("category".to_string()),
+    ));
+    let sql = build_with(MySqlDialect, &query);
+
+    assert_eq!(
+        sql.sql(),
+        "SELECT `category`, AVG(`amount`) AS `amount_avg` FROM `expenses` GROUP BY `category`"
+    );
+}
+
+#[test]
+fn test_dialect_rejects_invalid_identifier() {
+    let query = create_query("expenses`; DROP TABLE x", vec![]);
+
+    for builder in [
+        SqlBuilder::with_dialect(SqliteDialect),
+        SqlBuilder::with_dialect(PostgresDialect),
+        SqlBuilder::with_dialect(MySqlDialect),
+    ] {
+        let err = builder.build(&query).unwrap_err();
+        assert_eq!(err.code(), "INVALID_IDENTIFIER");
+    }
+}
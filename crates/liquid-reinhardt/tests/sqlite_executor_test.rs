@@ -0,0 +1,818 @@
+#![cfg(feature = "sqlite")]
+
+use liquid_protocol::{
+    Aggregation, AggregationType, DataSource, Filter, FilterNode, FilterOperator, FilterValue,
+    LiquidViewSchema, Metric, NullsOrder, Page, Sort, SortDirection, TimeBucket, TimeUnit,
+};
+use liquid_reinhardt::converter::{ConvertedQuery, DataSourceConverter, QueryCondition};
+use liquid_reinhardt::field_security::{FieldMask, FieldPolicy};
+use liquid_reinhardt::pagination::CursorCodec;
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use liquid_reinhardt::security::{CurrentUser, SecurityEnforcer, SecurityPolicy};
+use liquid_reinhardt::sqlite::SqliteExecutor;
+use serde_json::{json, Value};
+
+// FR-09: Embedded SQLite Executor Tests
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates an in-memory executor with a seeded `expenses` table
+fn create_executor(enforcer: SecurityEnforcer) -> SqliteExecutor {
+    let executor = SqliteExecutor::open_in_memory(enforcer).unwrap();
+    executor
+        .connection()
+        .execute_batch(
+            "CREATE TABLE expenses (
+                id INTEGER PRIMARY KEY,
+                user_id INTEGER NOT NULL,
+                category TEXT NOT NULL,
+                memo TEXT,
+                amount REAL NOT NULL,
+                month TEXT NOT NULL
+            );
+            INSERT INTO expenses (user_id, category, memo, amount, month) VALUES
+                (1, 'food', 'Lunch with team', 1200, '2024-01'),
+                (1, 'food', 'Dinner', 3000, '2024-02'),
+                (1, 'transport', 'Train', 500, '2024-02'),
+                (2, 'food', 'Lunch', 9999, '2024-01');",
+        )
+        .unwrap();
+    executor
+}
+
+/// Creates a seeded executor that issues cursors, with `expenses` keyed by `id`
+fn create_cursor_executor() -> SqliteExecutor {
+    let expenses = ResourceDefinition::new("expenses")
+        .field("id", FieldType::Integer)
+        .field("user_id", FieldType::Integer)
+        .field("category", FieldType::String)
+        .field("memo", FieldType::String)
+        .field("amount", FieldType::Float)
+        .field("month", FieldType::String)
+        .with_primary_key("id");
+    create_executor(SecurityEnforcer::new())
+        .with_converter(DataSourceConverter::with_registry(
+            ResourceRegistry::new().with_resource(expenses),
+        ))
+        .with_cursor_codec(CursorCodec::new("server-secret"))
+}
+
+fn create_user(id: u64) -> CurrentUser {
+    CurrentUser::new(id, vec![])
+}
+
+/// Creates a DataSource for `expenses` with the given filters and all other fields as None
+fn create_expenses_source(filters: Vec<Filter>) -> DataSource {
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: if filters.is_empty() {
+            None
+        } else {
+            Some(filters.into_iter().map(FilterNode::from).collect())
+        },
+        aggregation: None,
+        metrics: None,
+        group_by: None,
+        sort: None,
+        limit: None,
+        page: None,
+    }
+}
+
+/// Creates an `expenses` DataSource paged by two rows, sorted by amount descending then id
+fn create_paged_source(page: Option<Page>) -> DataSource {
+    let mut ds = create_expenses_source(vec![]);
+    ds.sort = Some(
+        vec![
+            Sort {
+                field: "amount".to_string(),
+                direction: SortDirection::Desc,
+                nulls: None,
+            },
+            Sort {
+                field: "id".to_string(),
+                direction: SortDirection::Asc,
+                nulls: None,
+            },
+        ]
+        .into(),
+    );
+    ds.limit = Some(2);
+    ds.page = page;
+    ds
+}
+
+/// Follows `next_cursor` from the first page as user 1 and returns the ids of each page
+fn cursor_pages(executor: &SqliteExecutor, mut ds: DataSource) -> Vec<Vec<i64>> {
+    let mut pages = Vec::new();
+    loop {
+        let page = executor
+            .execute_data_source_page(&ds, &create_user(1))
+            .unwrap();
+        pages.push(ids(page.rows()));
+        match page.next_cursor() {
+            Some(cursor) => ds.page = Some(Page::After(cursor.to_string())),
+            None => return pages,
+        }
+    }
+}
+
+fn ids(rows: &[Value]) -> Vec<i64> {
+    rows.iter().map(|row| row["id"].as_i64().unwrap()).collect()
+}
+
+/// Runs a single raw JSON filter against `expenses` as user 1 and returns the matching ids
+fn filter_ids(executor: &SqliteExecutor, filter: Value) -> Vec<i64> {
+    let mut ds = create_expenses_source(vec![]);
+    ds.filters = Some(vec![serde_json::from_value(filter).unwrap()]);
+    ids(&executor.execute_data_source(&ds, &create_user(1)).unwrap())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_execute_applies_row_level_security() {
+    let executor = create_executor(SecurityEnforcer::new());
+    let query = ConvertedQuery::new("expenses".to_string());
+
+    let rows = executor.execute(&query, &create_user(1)).unwrap();
+
+    assert_eq!(ids(&rows), vec![1, 2, 3]);
+    assert!(rows.iter().all(|row| row["user_id"] == json!(1)));
+}
+
+#[test]
+fn test_execute_returns_json_objects() {
+    let executor = create_executor(SecurityEnforcer::new());
+    let mut query = ConvertedQuery::new("expenses".to_string());
+    query.add_condition(QueryCondition::Eq {
+        field: "category".to_string(),
+        value: "transport".into(),
+    });
+
+    let rows = executor.execute(&query, &create_user(1)).unwrap();
+
+    assert_eq!(
+        rows,
+        vec![json!({
+            "id": 3,
+            "user_id": 1,
+            "category": "transport",
+            "memo": "Train",
+            "amount": 500.0,
+            "month": "2024-02"
+        })]
+    );
+}
+
+#[test]
+fn test_execute_data_source_with_aggregation() {
+    let executor = create_executor(SecurityEnforcer::new());
+    let mut ds = create_expenses_source(vec![]);
+    ds.aggregation = Some(Aggregation {
+        agg_type: AggregationType::Sum,
+        field: "amount".to_string(),
+        by: Some("month".to_string()),
+        bucket: None,
+    });
+    ds.sort = Some(
+        Sort {
+            field: "month".to_string(),
+            direction: SortDirection::Asc,
+            nulls: None,
+        }
+        .into(),
+    );
+
+    let rows = executor.execute_data_source(&ds, &create_user(1)).unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            json!({"month": "2024-01", "amount_sum": 1200.0}),
+            json!({"month": "2024-02", "amount_sum": 3500.0}),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_data_source_with_metrics() {
+    let executor = create_executor(SecurityEnforcer::new());
+    let mut ds = create_expenses_source(vec![]);
+    ds.metrics = Some(vec![
+        Metric {
+            alias: "total".to_string(),
+            agg_type: AggregationType::Sum,
+            field: "amount".to_string(),
+        },
+        Metric {
+            alias: "count".to_string(),
+            agg_type: AggregationType::Count,
+            field: "id".to_string(),
+        },
+    ]);
+    ds.group_by = Some(vec!["category".to_string(), "month".to_string()]);
+    ds.sort = Some(
+        Sort {
+            field: "total".to_string(),
+            direction: SortDirection::Desc,
+            nulls: None,
+        }
+        .into(),
+    );
+
+    let rows = executor.execute_data_source(&ds, &create_user(1)).unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            json!({"category": "food", "month": "2024-02", "total": 3000.0, "count": 1}),
+            json!({"category": "food", "month": "2024-01", "total": 1200.0, "count": 1}),
+            json!({"category": "transport", "month": "2024-02", "total": 500.0, "count": 1}),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_data_source_with_distinct_and_percentiles() {
+    let executor = create_executor(SecurityEnforcer::new());
+    let metric = |alias: &str, agg_type, field: &str| Metric {
+        alias: alias.to_string(),
+        agg_type,
+        field: field.to_string(),
+    };
+    let mut ds = create_expenses_source(vec![]);
+    ds.metrics = Some(vec![
+        metric("categories", AggregationType::CountDistinct, "category"),
+        metric("median", AggregationType::Median, "amount"),
+        metric("p90", AggregationType::Percentile(90.0), "amount"),
+    ]);
+
+    let rows = executor.execute_data_source(&ds, &create_user(1)).unwrap();
+
+    assert_eq!(
+        rows,
+        vec![json!({"categories": 2, "median": 1200.0, "p90": 2640.0})]
+    );
+}
+
+#[test]
+fn test_execute_data_source_with_multi_key_sort() {
+    let executor = create_executor(SecurityEnforcer::new());
+    executor
+        .connection()
+        .execute_batch(
+            "CREATE TABLE tasks (user_id INTEGER NOT NULL, status TEXT NOT NULL, due TEXT);
+            INSERT INTO tasks (user_id, status, due) VALUES
+                (1, 'open', '2024-03-01'),
+                (1, 'done', NULL),
+                (1, 'open', NULL),
+                (1, 'done', '2024-01-15');",
+        )
+        .unwrap();
+    let sort = |field: &str, direction, nulls| Sort {
+        field: field.to_string(),
+        direction,
+        nulls,
+    };
+    let ds = DataSource {
+        resource: "tasks".to_string(),
+        filters: None,
+        aggregation: None,
+        metrics: None,
+        group_by: None,
+        sort: Some(
+            vec![
+                sort("status", SortDirection::Desc, None),
+                sort("due", SortDirection::Asc, Some(NullsOrder::Last)),
+            ]
+            .into(),
+        ),
+        limit: None,
+        page: None,
+    };
+
+    let rows = executor.execute_data_source(&ds, &create_user(1)).unwrap();
+    let keys: Vec<(&str, Option<&str>)> = rows
+        .iter()
+        .map(|row| (row["status"].as_str().unwrap(), row["due"].as_str()))
+        .collect();
+
+    assert_eq!(
+        keys,
+        vec![
+            ("open", Some("2024-03-01")),
+            ("open", None),
+            ("done", Some("2024-01-15")),
+            ("done", None),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_data_source_with_time_buckets() {
+    let executor = create_executor(SecurityEnforcer::new());
+    executor
+        .connection()
+        .execute_batch(
+            "CREATE TABLE payments (user_id INTEGER NOT NULL, paid_at TEXT NOT NULL, amount REAL);
+            INSERT INTO payments (user_id, paid_at, amount) VALUES
+                (1, '2024-01-03 09:00:00', 100),
+                (1, '2024-01-07 23:30:00', 50),
+                (1, '2024-01-22 12:00:00', 25),
+                (1, '2024-05-01 08:00:00', 10),
+                (2, '2024-03-01 08:00:00', 999);",
+        )
+        .unwrap();
+    let bucketed = |unit, zero_fill| DataSource {
+        resource: "payments".to_string(),
+        filters: None,
+        aggregation: Some(Aggregation {
+            agg_type: AggregationType::Sum,
+            field: "amount".to_string(),
+            by: Some("paid_at".to_string()),
+            bucket: Some(TimeBucket {
+                unit,
+                timezone: None,
+                zero_fill,
+            }),
+        }),
+        metrics: None,
+        group_by: None,
+        sort: None,
+        limit: None,
+        page: None,
+    };
+
+    let weeks = executor
+        .execute_data_source(&bucketed(TimeUnit::Week, false), &create_user(1))
+        .unwrap();
+    let months = executor
+        .execute_data_source(&bucketed(TimeUnit::Month, true), &create_user(1))
+        .unwrap();
+    let quarters = executor
+        .execute_data_source(&bucketed(TimeUnit::Quarter, false), &create_user(1))
+        .unwrap();
+
+    assert_eq!(
+        weeks,
+        vec![
+            json!({"paid_at": "2024-01-01", "amount_sum": 150.0}),
+            json!({"paid_at": "2024-01-22", "amount_sum": 25.0}),
+            json!({"paid_at": "2024-04-29", "amount_sum": 10.0}),
+        ]
+    );
+    assert_eq!(
+        months,
+        vec![
+            json!({"paid_at": "2024-01-01", "amount_sum": 175.0}),
+            json!({"paid_at": "2024-02-01", "amount_sum": 0}),
+            json!({"paid_at": "2024-03-01", "amount_sum": 0}),
+            json!({"paid_at": "2024-04-01", "amount_sum": 0}),
+            json!({"paid_at": "2024-05-01", "amount_sum": 10.0}),
+        ]
+    );
+    assert_eq!(
+        quarters,
+        vec![
+            json!({"paid_at": "2024-01-01", "amount_sum": 175.0}),
+            json!({"paid_at": "2024-04-01", "amount_sum": 10.0}),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_data_source_with_filters_and_limit() {
+    let executor = create_executor(SecurityEnforcer::new());
+    let mut ds = create_expenses_source(vec![
+        Filter {
+            field: "memo".to_string(),
+            op: FilterOperator::IContains,
+            value: FilterValue::String("lunch".to_string()),
+        },
+        Filter {
+            field: "amount".to_string(),
+            op: FilterOperator::Gte,
+            value: FilterValue::Number(1000.0),
+        },
+    ]);
+    ds.limit = Some(10);
+
+    let rows = executor.execute_data_source(&ds, &create_user(1)).unwrap();
+
+    assert_eq!(ids(&rows), vec![1]);
+}
+
+#[test]
+fn test_filter_groups_stay_within_row_level_security() {
+    let executor = create_executor(SecurityEnforcer::new());
+    let mut ds = create_expenses_source(vec![]);
+    ds.filters = Some(
+        serde_json::from_value(json!([
+            {
+                "or": [
+                    { "field": "category", "op": "eq", "value": "food" },
+                    { "field": "category", "op": "eq", "value": "transport" }
+                ]
+            },
+            { "not": { "field": "memo", "op": "icontains", "value": "dinner" } }
+        ]))
+        .unwrap(),
+    );
+
+    let rows = executor.execute_data_source(&ds, &create_user(1)).unwrap();
+
+    // OR は user_id 条件の外に広がらない (id 4 は他ユーザー)
+    assert_eq!(ids(&rows), vec![1, 3]);
+}
+
+#[test]
+fn test_text_operators_respect_case() {
+    let executor = create_executor(SecurityEnforcer::new());
+
+    let cases = [
+        (
+            json!({ "field": "memo", "op": "contains", "value": "Lunch" }),
+            vec![1],
+        ),
+        (
+            json!({ "field": "memo", "op": "contains", "value": "lunch" }),
+            vec![],
+        ),
+        (
+            json!({ "field": "memo", "op": "icontains", "value": "lunch" }),
+            vec![1],
+        ),
+        (
+            json!({ "field": "memo", "op": "starts_with", "value": "Din" }),
+            vec![2],
+        ),
+        (
+            json!({ "field": "memo", "op": "starts_with", "value": "din" }),
+            vec![],
+        ),
+        (
+            json!({ "field": "memo", "op": "ends_with", "value": "team" }),
+            vec![1],
+        ),
+        (
+            json!({ "field": "memo", "op": "contains", "value": "*" }),
+            vec![],
+        ),
+    ];
+
+    for (filter, expected) in cases {
+        assert_eq!(
+            filter_ids(&executor, filter.clone()),
+            expected,
+            "{}",
+            filter
+        );
+    }
+}
+
+#[test]
+fn test_range_exclusion_and_null_operators() {
+    let executor = create_executor(SecurityEnforcer::new());
+    executor
+        .connection()
+        .execute_batch(
+            "INSERT INTO expenses (user_id, category, memo, amount, month)
+             VALUES (1, 'misc', NULL, 100, '2024-03');",
+        )
+        .unwrap();
+
+    let cases = [
+        (
+            json!({ "field": "amount", "op": "between", "value": [500, 1200] }),
+            vec![1, 3],
+        ),
+        (
+            json!({ "field": "category", "op": "not_in", "value": ["food"] }),
+            vec![3, 5],
+        ),
+        (json!({ "field": "memo", "op": "is_null" }), vec![5]),
+        (
+            json!({ "field": "memo", "op": "is_not_null" }),
+            vec![1, 2, 3],
+        ),
+    ];
+
+    for (filter, expected) in cases {
+        assert_eq!(
+            filter_ids(&executor, filter.clone()),
+            expected,
+            "{}",
+            filter
+        );
+    }
+}
+
+#[test]
+fn test_execute_schema_end_to_end() {
+    let executor = create_executor(SecurityEnforcer::new());
+    let schema: LiquidViewSchema = serde_json::from_value(json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [
+                { "type": "chart", "variant": "bar", "data_source": "by_category" }
+            ]
+        },
+        "data_sources": {
+            "by_category": {
+                "resource": "expenses",
+                "aggregation": { "type": "count", "field": "id", "by": "category" },
+                "sort": { "field": "category", "direction": "asc" }
+            }
+        }
+    }))
+    .unwrap();
+
+    let results = executor.execute_schema(&schema, &create_user(1)).unwrap();
+
+    assert_eq!(
+        results["by_category"],
+        vec![
+            json!({"category": "food", "id_count": 2}),
+            json!({"category": "transport", "id_count": 1}),
+        ]
+    );
+}
+
+#[test]
+fn test_typed_date_filter_with_registry() {
+    let executor = create_executor(SecurityEnforcer::new()).with_converter(
+        DataSourceConverter::with_registry(
+            ResourceRegistry::new().with_resource(
+                ResourceDefinition::new("expenses")
+                    .field("id", FieldType::Integer)
+                    .field("user_id", FieldType::Integer)
+                    .field("spent_on", FieldType::Date),
+            ),
+        ),
+    );
+    executor
+        .connection()
+        .execute_batch(
+            "ALTER TABLE expenses ADD COLUMN spent_on TEXT;
+            UPDATE expenses SET spent_on = month || '-15';",
+        )
+        .unwrap();
+    let ds = create_expenses_source(vec![Filter {
+        field: "spent_on".to_string(),
+        op: FilterOperator::Gte,
+        value: FilterValue::String("2024-02-01".to_string()),
+    }]);
+
+    let rows = executor.execute_data_source(&ds, &create_user(1)).unwrap();
+
+    assert_eq!(ids(&rows), vec![2, 3]);
+}
+
+#[test]
+fn test_injection_payload_is_treated_as_value() {
+    let executor = create_executor(SecurityEnforcer::new());
+    let ds = create_expenses_source(vec![Filter {
+        field: "category".to_string(),
+        op: FilterOperator::Eq,
+        value: FilterValue::String("food' OR '1'='1".to_string()),
+    }]);
+
+    let rows = executor.execute_data_source(&ds, &create_user(1)).unwrap();
+    assert!(rows.is_empty());
+
+    // テーブルは無傷
+    let all = executor
+        .execute(
+            &ConvertedQuery::new("expenses".to_string()),
+            &create_user(2),
+        )
+        .unwrap();
+    assert_eq!(all.len(), 1);
+}
+
+#[test]
+fn test_access_denied_policy_prevents_execution() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_policy_for_resource(
+        "expenses",
+        SecurityPolicy::new("admin_only", |user, _| user.has_permission("admin")),
+    );
+    let executor = create_executor(enforcer);
+
+    let result = executor.execute(
+        &ConvertedQuery::new("expenses".to_string()),
+        &create_user(1),
+    );
+
+    assert_eq!(result.unwrap_err().code(), "ACCESS_DENIED");
+}
+
+#[test]
+fn test_error_on_unknown_table() {
+    let executor = create_executor(SecurityEnforcer::new());
+
+    let result = executor.execute(&ConvertedQuery::new("missing".to_string()), &create_user(1));
+
+    assert_eq!(result.unwrap_err().code(), "EXECUTION_ERROR");
+}
+
+#[test]
+fn test_execute_masks_and_hides_fields() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.set_field_policy(
+        "expenses",
+        "memo",
+        FieldPolicy::new().require_read("memo:read"),
+    );
+    enforcer.set_field_policy(
+        "expenses",
+        "category",
+        FieldPolicy::new().masked(FieldMask::ShowLast(2)),
+    );
+    let executor = create_executor(enforcer);
+
+    let rows = executor
+        .execute(
+            &ConvertedQuery::new("expenses".to_string()),
+            &create_user(1),
+        )
+        .unwrap();
+
+    assert_eq!(
+        rows[0],
+        json!({ "id": 1, "user_id": 1, "category": "**od", "amount": 1200.0, "month": "2024-01" })
+    );
+}
+
+#[test]
+fn test_execute_schema_checks_table_columns() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.set_field_policy(
+        "expenses",
+        "memo",
+        FieldPolicy::new().require_read("memo:read"),
+    );
+    let executor = create_executor(enforcer);
+    let schema: LiquidViewSchema = serde_json::from_value(json!({
+        "version": "1.0",
+        "layout": {
+            "type": "grid",
+            "props": { "columns": 1 },
+            "children": [{ "type": "table", "data_source": "expenses", "columns": ["memo"] }]
+        },
+        "data_sources": { "expenses": { "resource": "expenses" } }
+    }))
+    .unwrap();
+
+    let result = executor.execute_schema(&schema, &create_user(1));
+
+    assert_eq!(result.unwrap_err().code(), "FIELD_READ_DENIED");
+}
+
+#[test]
+fn test_execute_offset_pages() {
+    let executor = create_executor(SecurityEnforcer::new());
+    let user = create_user(1);
+
+    let first = executor
+        .execute_data_source_page(&create_paged_source(None), &user)
+        .unwrap();
+    let second = executor
+        .execute_data_source_page(&create_paged_source(Some(Page::Offset(2))), &user)
+        .unwrap();
+
+    assert_eq!(ids(first.rows()), vec![2, 1]);
+    assert_eq!(first.next_offset(), Some(2));
+    assert_eq!(first.next_cursor(), None);
+    assert_eq!(ids(second.rows()), vec![3]);
+    assert!(!second.has_more());
+}
+
+#[test]
+fn test_execute_cursor_pages_within_row_level_security() {
+    let executor = create_executor(SecurityEnforcer::new())
+        .with_cursor_codec(CursorCodec::new("server-secret"));
+    let user = create_user(1);
+
+    let first = executor
+        .execute_data_source_page(&create_paged_source(None), &user)
+        .unwrap();
+    let cursor = first.next_cursor().unwrap().to_string();
+    let second = executor
+        .execute_data_source_page(
+            &create_paged_source(Some(Page::After(cursor.clone()))),
+            &user,
+        )
+        .unwrap();
+
+    assert_eq!(ids(first.rows()), vec![2, 1]);
+    assert_eq!(ids(second.rows()), vec![3]);
+    assert_eq!(second.next_offset(), None);
+    assert!(!second.has_more());
+
+    // User 2 cannot resume user 1's query
+    let result = executor.execute_data_source_page(
+        &create_paged_source(Some(Page::After(cursor))),
+        &create_user(2),
+    );
+    assert_eq!(result.unwrap_err().code(), "INVALID_CURSOR");
+}
+
+#[test]
+fn test_cursor_requires_codec_and_visible_sort_keys() {
+    let ds = create_paged_source(Some(Page::After("token".to_string())));
+    let result = create_executor(SecurityEnforcer::new()).execute_data_source(&ds, &create_user(1));
+    assert_eq!(result.unwrap_err().code(), "INVALID_CURSOR");
+
+    // A cursor over a masked sort key would reveal the raw value, so the sort is rejected
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.set_field_policy(
+        "expenses",
+        "amount",
+        FieldPolicy::new().masked(FieldMask::Redact),
+    );
+    let result = create_executor(enforcer)
+        .execute_data_source_page(&create_paged_source(None), &create_user(1));
+    assert_eq!(result.unwrap_err().code(), "FIELD_SORT_DENIED");
+}
+
+#[test]
+fn test_cursor_pages_do_not_skip_duplicate_sort_values() {
+    let executor = create_cursor_executor();
+    executor
+        .connection()
+        .execute_batch(
+            "INSERT INTO expenses (user_id, category, memo, amount, month) VALUES
+                (1, 'food', 'Snack', 1200, '2024-03'),
+                (1, 'food', 'Coffee', 1200, '2024-03');",
+        )
+        .unwrap();
+    let mut ds = create_paged_source(None);
+    ds.sort = Some(
+        Sort {
+            field: "amount".to_string(),
+            direction: SortDirection::Desc,
+            nulls: None,
+        }
+        .into(),
+    );
+
+    // The first page ends inside the run of 1200s; the id tiebreaker resumes after it
+    assert_eq!(
+        cursor_pages(&executor, ds),
+        vec![vec![2, 1], vec![5, 6], vec![3]]
+    );
+}
+
+#[test]
+fn test_cursor_pages_cross_null_sort_values() {
+    let executor = create_cursor_executor();
+    executor
+        .connection()
+        .execute_batch(
+            "INSERT INTO expenses (user_id, category, memo, amount, month) VALUES
+                (1, 'food', NULL, 100, '2024-03'),
+                (1, 'food', NULL, 200, '2024-03');",
+        )
+        .unwrap();
+    let cases = [
+        (
+            SortDirection::Asc,
+            Some(NullsOrder::First),
+            vec![5, 6, 2, 1, 3],
+        ),
+        (
+            SortDirection::Asc,
+            Some(NullsOrder::Last),
+            vec![2, 1, 3, 5, 6],
+        ),
+        // SQLite places NULL last in descending order by default
+        (SortDirection::Desc, None, vec![3, 1, 2, 5, 6]),
+    ];
+
+    for (direction, nulls, expected) in cases {
+        let mut ds = create_paged_source(None);
+        ds.sort = Some(
+            Sort {
+                field: "memo".to_string(),
+                direction,
+                nulls,
+            }
+            .into(),
+        );
+        ds.limit = Some(1);
+
+        // One row per page, so every boundary (including NULL to NULL) is crossed
+        let pages = cursor_pages(&executor, ds);
+
+        assert_eq!(pages.concat(), expected);
+        assert_eq!(pages.len(), expected.len());
+    }
+}
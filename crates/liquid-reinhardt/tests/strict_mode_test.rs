@@ -0,0 +1,147 @@
+// FR-07: Deny-by-default (Strict Mode) Tests
+
+use liquid_reinhardt::converter::{ConvertedQuery, QueryCondition, QueryValue};
+use liquid_reinhardt::policy::PolicyDocument;
+use liquid_reinhardt::security::{CurrentUser, SecurityEnforcer, SecurityPolicy};
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a CurrentUser without permissions
+fn create_user(id: u64) -> CurrentUser {
+    CurrentUser::new(id, vec![])
+}
+
+/// Creates a strict SecurityEnforcer with an `expenses` ownership policy
+fn create_strict_enforcer() -> SecurityEnforcer {
+    let mut enforcer = SecurityEnforcer::new().with_strict_mode(true);
+    enforcer.add_policy_for_resource("expenses", SecurityPolicy::custom_field("owner_id"));
+    enforcer
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_strict_mode_rejects_unregistered_resource() {
+    let enforcer = create_strict_enforcer();
+    let mut query = ConvertedQuery::new("payroll".to_string());
+
+    let err = enforcer.enforce(&mut query, &create_user(1)).unwrap_err();
+
+    assert_eq!(err.code(), "ACCESS_DENIED");
+    assert!(err.message().contains("payroll"));
+    assert!(query.conditions().is_empty());
+}
+
+#[test]
+fn test_strict_mode_allows_registered_resource() {
+    let enforcer = create_strict_enforcer();
+    let mut query = ConvertedQuery::new("expenses".to_string());
+
+    enforcer.enforce(&mut query, &create_user(9)).unwrap();
+
+    assert_eq!(
+        query.system_conditions(),
+        &[QueryCondition::Eq {
+            field: "owner_id".to_string(),
+            value: QueryValue::Integer(9),
+        }]
+    );
+}
+
+#[test]
+fn test_wildcard_policies_do_not_register_resources() {
+    let mut enforcer = create_strict_enforcer();
+    enforcer.add_default_policy(SecurityPolicy::new("everyone", |_, _| true));
+    let mut query = ConvertedQuery::new("payroll".to_string());
+
+    assert!(enforcer.enforce(&mut query, &create_user(1)).is_err());
+}
+
+#[test]
+fn test_wildcard_allow_policy_keeps_user_id_fallback() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.add_default_policy(SecurityPolicy::new("everyone", |_, _| true));
+    let mut query = ConvertedQuery::new("orders".to_string());
+
+    enforcer.enforce(&mut query, &create_user(4)).unwrap();
+
+    // 全リソース共通の許可ポリシーは所有者の行フィルタを置き換えない
+    assert_eq!(
+        query.system_conditions(),
+        &[QueryCondition::Eq {
+            field: "user_id".to_string(),
+            value: QueryValue::Integer(4),
+        }]
+    );
+}
+
+#[test]
+fn test_public_resource_has_no_row_filter() {
+    let mut enforcer = create_strict_enforcer();
+    enforcer.allow_public_resource("exchange_rates");
+    let mut query = ConvertedQuery::new("exchange_rates".to_string());
+
+    enforcer.enforce(&mut query, &create_user(1)).unwrap();
+
+    assert!(enforcer.is_public_resource("exchange_rates"));
+    assert!(query.conditions().is_empty());
+}
+
+#[test]
+fn test_public_resource_still_checks_default_policies() {
+    let mut enforcer = create_strict_enforcer();
+    enforcer.allow_public_resource("exchange_rates");
+    enforcer.add_default_policy(SecurityPolicy::deny("blocked", |user, _| user.id() == 13));
+
+    let mut allowed = ConvertedQuery::new("exchange_rates".to_string());
+    let mut blocked = ConvertedQuery::new("exchange_rates".to_string());
+
+    assert!(enforcer.enforce(&mut allowed, &create_user(1)).is_ok());
+    assert!(enforcer.enforce(&mut blocked, &create_user(13)).is_err());
+}
+
+#[test]
+fn test_public_resource_without_strict_mode() {
+    let mut enforcer = SecurityEnforcer::new();
+    enforcer.allow_public_resource("countries");
+
+    let mut public = ConvertedQuery::new("countries".to_string());
+    let mut private = ConvertedQuery::new("orders".to_string());
+    enforcer.enforce(&mut public, &create_user(2)).unwrap();
+    enforcer.enforce(&mut private, &create_user(2)).unwrap();
+
+    // 公開リソースには user_id が注入されない
+    assert!(public.conditions().is_empty());
+    assert_eq!(private.system_conditions().len(), 1);
+}
+
+#[test]
+fn test_declarative_public_resources() {
+    let document = PolicyDocument::from_toml(
+        r#"
+public_resources = ["exchange_rates"]
+
+[[policies]]
+name = "owner"
+resource = "expenses"
+inject = [{ field = "owner_id", from = "user.id" }]
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        PolicyDocument::from_toml(&document.to_toml().unwrap()).unwrap(),
+        document
+    );
+
+    let mut enforcer = SecurityEnforcer::new().with_strict_mode(true);
+    enforcer.load_policies(document).unwrap();
+
+    let mut rates = ConvertedQuery::new("exchange_rates".to_string());
+    let mut orders = ConvertedQuery::new("orders".to_string());
+    assert!(enforcer.enforce(&mut rates, &create_user(1)).is_ok());
+    assert!(enforcer.enforce(&mut orders, &create_user(1)).is_err());
+}
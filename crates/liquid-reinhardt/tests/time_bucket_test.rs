@@ -0,0 +1,337 @@
+// FR-06: Time-Bucketed Group By Tests
+
+use liquid_protocol::{Aggregation, AggregationType, DataSource, TimeBucket, TimeUnit};
+use liquid_reinhardt::bucket::fill_empty_buckets;
+use liquid_reinhardt::clock::Tz;
+use liquid_reinhardt::converter::{
+    ConversionError, ConvertedQuery, DataSourceConverter, QueryAggregation, QueryMetric, QuerySort,
+    QueryTimeBucket,
+};
+use liquid_reinhardt::dialect::{Dialect, MySqlDialect, PostgresDialect, SqliteDialect};
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use liquid_reinhardt::sql::SqlBuilder;
+use serde_json::{json, Value};
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a converter whose `expenses` resource declares typed fields
+fn create_converter() -> DataSourceConverter {
+    DataSourceConverter::with_registry(
+        ResourceRegistry::new().with_resource(
+            ResourceDefinition::new("expenses")
+                .field("date", FieldType::Date)
+                .field("created_at", FieldType::DateTime)
+                .field("amount", FieldType::Integer)
+                .field("memo", FieldType::String),
+        ),
+    )
+}
+
+/// Creates an `expenses` DataSource summing `amount` per bucket of `by`
+fn create_data_source(by: Option<&str>, bucket: TimeBucket) -> DataSource {
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: None,
+        aggregation: Some(Aggregation {
+            agg_type: AggregationType::Sum,
+            field: "amount".to_string(),
+            by: by.map(String::from),
+            bucket: Some(bucket),
+        }),
+        metrics: None,
+        group_by: None,
+        sort: None,
+        limit: None,
+        page: None,
+    }
+}
+
+fn bucket(unit: TimeUnit, timezone: Option<&str>) -> TimeBucket {
+    TimeBucket {
+        unit,
+        timezone: timezone.map(String::from),
+        zero_fill: false,
+    }
+}
+
+/// Converts a bucketed DataSource and returns its QueryTimeBucket
+fn convert_bucket(converter: &DataSourceConverter, ds: &DataSource) -> QueryTimeBucket {
+    let query = converter.convert(ds).unwrap();
+    query.aggregation().unwrap().bucket().unwrap().clone()
+}
+
+/// Builds the SELECT list / GROUP BY for a bucket of `created_at`
+fn build_with(dialect: impl Dialect + 'static, unit: TimeUnit, timezone: Option<Tz>) -> String {
+    try_build_with(dialect, unit, timezone).unwrap()
+}
+
+fn try_build_with(
+    dialect: impl Dialect + 'static,
+    unit: TimeUnit,
+    timezone: Option<Tz>,
+) -> Result<String, ConversionError> {
+    let mut query = ConvertedQuery::new("expenses".to_string());
+    query.set_aggregation(
+        QueryAggregation::new(
+            AggregationType::Sum,
+            "amount".to_string(),
+            Some("created_at".to_string()),
+        )
+        .with_bucket(QueryTimeBucket::new(
+            "created_at".to_string(),
+            unit,
+            timezone,
+            false,
+        )),
+    );
+    SqlBuilder::with_dialect(dialect)
+        .build(&query)
+        .map(|sql| sql.sql().to_string())
+}
+
+/// Creates a monthly zero-filled query over `month`
+fn zero_fill_query(sort: Option<QuerySort>) -> ConvertedQuery {
+    let mut query = ConvertedQuery::new("expenses".to_string());
+    query.set_aggregation(
+        QueryAggregation::with_metrics(
+            vec![
+                QueryMetric::new(
+                    "total".to_string(),
+                    AggregationType::Sum,
+                    "amount".to_string(),
+                ),
+                QueryMetric::new(
+                    "average".to_string(),
+                    AggregationType::Avg,
+                    "amount".to_string(),
+                ),
+            ],
+            vec!["month".to_string()],
+        )
+        .with_bucket(QueryTimeBucket::new(
+            "month".to_string(),
+            TimeUnit::Month,
+            None,
+            true,
+        )),
+    );
+    if let Some(sort) = sort {
+        query.add_sort(sort);
+    }
+    query
+}
+
+fn month_row(month: &str, total: i64) -> Value {
+    json!({ "month": month, "total": total, "average": total })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_datetime_bucket_uses_timezone() {
+    let converter = create_converter().with_timezone(chrono_tz::Asia::Tokyo);
+
+    let default_tz = convert_bucket(
+        &converter,
+        &create_data_source(Some("created_at"), bucket(TimeUnit::Month, None)),
+    );
+    let explicit_tz = convert_bucket(
+        &converter,
+        &create_data_source(
+            Some("created_at"),
+            bucket(TimeUnit::Week, Some("America/New_York")),
+        ),
+    );
+
+    assert_eq!(
+        default_tz,
+        QueryTimeBucket::new(
+            "created_at".to_string(),
+            TimeUnit::Month,
+            Some(chrono_tz::Asia::Tokyo),
+            false
+        )
+    );
+    assert_eq!(explicit_tz.unit(), TimeUnit::Week);
+    assert_eq!(explicit_tz.timezone(), Some(chrono_tz::America::New_York));
+}
+
+#[test]
+fn test_date_and_utc_buckets_skip_timezone_conversion() {
+    let converter = create_converter().with_timezone(chrono_tz::Asia::Tokyo);
+
+    let date = convert_bucket(
+        &converter,
+        &create_data_source(Some("date"), bucket(TimeUnit::Month, None)),
+    );
+    let utc = convert_bucket(
+        &converter,
+        &create_data_source(Some("created_at"), bucket(TimeUnit::Month, Some("UTC"))),
+    );
+
+    assert_eq!(date.timezone(), None);
+    assert_eq!(utc.timezone(), None);
+}
+
+#[test]
+fn test_invalid_buckets_are_rejected() {
+    let converter = create_converter();
+    let cases = [
+        (
+            create_data_source(Some("memo"), bucket(TimeUnit::Month, None)),
+            "INVALID_TIME_BUCKET",
+        ),
+        (
+            create_data_source(Some("created_at"), bucket(TimeUnit::Day, Some("Mars/Base"))),
+            "INVALID_TIMEZONE",
+        ),
+        (
+            create_data_source(None, bucket(TimeUnit::Day, None)),
+            "INVALID_AGGREGATION",
+        ),
+    ];
+
+    for (ds, code) in cases {
+        assert_eq!(converter.convert(&ds).unwrap_err().code(), code);
+    }
+}
+
+#[test]
+fn test_postgres_bucket_sql() {
+    let sql = build_with(
+        PostgresDialect,
+        TimeUnit::Month,
+        Some(chrono_tz::Asia::Tokyo),
+    );
+
+    let bucket =
+        "to_char(date_trunc('month', \"created_at\" AT TIME ZONE 'Asia/Tokyo'), 'YYYY-MM-DD')";
+    assert_eq!(
+        sql,
+        format!(
+            "SELECT {} AS \"created_at\", SUM(\"amount\") AS \"amount_sum\" FROM \"expenses\" GROUP BY {}",
+            bucket, bucket
+        )
+    );
+}
+
+#[test]
+fn test_mysql_bucket_sql() {
+    let sql = build_with(MySqlDialect, TimeUnit::Quarter, None);
+
+    let bucket =
+        "CONCAT(YEAR(`created_at`), '-', LPAD(QUARTER(`created_at`) * 3 - 2, 2, '0'), '-01')";
+    assert_eq!(
+        sql,
+        format!(
+            "SELECT {} AS `created_at`, SUM(`amount`) AS `amount_sum` FROM `expenses` GROUP BY {}",
+            bucket, bucket
+        )
+    );
+}
+
+#[test]
+fn test_sqlite_bucket_sql_in_utc() {
+    let sql = build_with(SqliteDialect, TimeUnit::Year, None);
+
+    assert_eq!(
+        sql,
+        "SELECT strftime('%Y-01-01', \"created_at\") AS \"created_at\", \
+         SUM(\"amount\") AS \"amount_sum\" FROM \"expenses\" \
+         GROUP BY strftime('%Y-01-01', \"created_at\")"
+    );
+}
+
+#[test]
+fn test_sqlite_and_mysql_reject_timezone_buckets() {
+    let tokyo = Some(chrono_tz::Asia::Tokyo);
+
+    let sqlite = try_build_with(SqliteDialect, TimeUnit::Day, tokyo).unwrap_err();
+    // CONVERT_TZ would silently yield NULL without the server's time zone tables
+    let mysql = try_build_with(MySqlDialect, TimeUnit::Day, tokyo).unwrap_err();
+
+    assert_eq!(sqlite.code(), "UNSUPPORTED_TIME_BUCKET");
+    assert_eq!(mysql.code(), "UNSUPPORTED_TIME_BUCKET");
+}
+
+#[test]
+fn test_zero_fill_inserts_empty_buckets() {
+    let mut rows = vec![month_row("2024-04-01", 50), month_row("2024-01-01", 100)];
+
+    fill_empty_buckets(&zero_fill_query(None), &mut rows);
+
+    assert_eq!(
+        rows,
+        vec![
+            month_row("2024-01-01", 100),
+            json!({ "month": "2024-02-01", "total": 0, "average": null }),
+            json!({ "month": "2024-03-01", "total": 0, "average": null }),
+            month_row("2024-04-01", 50),
+        ]
+    );
+}
+
+#[test]
+fn test_zero_fill_keeps_descending_bucket_order() {
+    let sort = QuerySort::new("month".to_string(), liquid_protocol::SortDirection::Desc);
+    let mut rows = vec![month_row("2024-03-01", 30), month_row("2024-01-01", 10)];
+
+    fill_empty_buckets(&zero_fill_query(Some(sort)), &mut rows);
+
+    let months: Vec<&str> = rows
+        .iter()
+        .map(|row| row["month"].as_str().unwrap())
+        .collect();
+    assert_eq!(months, vec!["2024-03-01", "2024-02-01", "2024-01-01"]);
+}
+
+#[test]
+fn test_zero_fill_keeps_metric_sort_order() {
+    let sort = QuerySort::new("total".to_string(), liquid_protocol::SortDirection::Desc);
+    let mut rows = vec![month_row("2024-01-01", 100), month_row("2024-04-01", 50)];
+    let expected = rows.clone();
+
+    fill_empty_buckets(&zero_fill_query(Some(sort)), &mut rows);
+
+    // 補完した行をソート順に配置できないため補完しない
+    assert_eq!(rows, expected);
+}
+
+#[test]
+fn test_zero_fill_does_not_exceed_limit() {
+    let mut query = zero_fill_query(None);
+    query.set_limit(2);
+    let mut rows = vec![month_row("2024-01-01", 100), month_row("2024-04-01", 50)];
+    let expected = rows.clone();
+
+    fill_empty_buckets(&query, &mut rows);
+
+    assert_eq!(rows, expected);
+}
+
+#[test]
+fn test_zero_fill_is_opt_in() {
+    let mut query = create_converter()
+        .convert(&create_data_source(
+            Some("date"),
+            bucket(TimeUnit::Month, None),
+        ))
+        .unwrap();
+    query.add_sort(QuerySort::new(
+        "amount_sum".to_string(),
+        liquid_protocol::SortDirection::Desc,
+    ));
+    let mut rows = vec![
+        json!({ "date": "2024-03-01", "amount_sum": 30 }),
+        json!({ "date": "2024-01-01", "amount_sum": 10 }),
+    ];
+    let expected = rows.clone();
+
+    fill_empty_buckets(&query, &mut rows);
+
+    assert_eq!(rows, expected);
+}
@@ -0,0 +1,246 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use liquid_protocol::{DataSource, Filter, FilterOperator, FilterValue, FilterValueScalar};
+use liquid_reinhardt::converter::{DataSourceConverter, QueryCondition, QueryValue};
+use liquid_reinhardt::registry::{FieldType, ResourceDefinition, ResourceRegistry};
+use liquid_reinhardt::sql::{SqlBuilder, SqlValue};
+
+// FR-10: Typed Filter Values Tests
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+/// Creates a converter whose `expenses` resource declares one field of every type
+fn create_converter() -> DataSourceConverter {
+    DataSourceConverter::with_registry(
+        ResourceRegistry::new().with_resource(
+            ResourceDefinition::new("expenses")
+                .field("id", FieldType::Integer)
+                .field("category", FieldType::String)
+                .field("amount", FieldType::Float)
+                .field("approved", FieldType::Boolean)
+                .field("date", FieldType::Date)
+                .field("created_at", FieldType::DateTime),
+        ),
+    )
+}
+
+/// Creates an `expenses` DataSource with a single filter
+fn create_data_source(field: &str, op: FilterOperator, value: FilterValue) -> DataSource {
+    DataSource {
+        resource: "expenses".to_string(),
+        filters: Some(vec![Filter {
+            field: field.to_string(),
+            op,
+            value,
+        }
+        .into()]),
+        aggregation: None,
+        metrics: None,
+        group_by: None,
+        sort: None,
+        limit: None,
+        page: None,
+    }
+}
+
+/// Converts a single filter and returns the resulting condition
+fn convert_one(field: &str, op: FilterOperator, value: FilterValue) -> QueryCondition {
+    let ds = create_data_source(field, op, value);
+    let query = create_converter().convert(&ds).unwrap();
+    query.conditions()[0].clone()
+}
+
+/// Converts a single filter and returns the error code
+fn convert_error(field: &str, op: FilterOperator, value: FilterValue) -> String {
+    let ds = create_data_source(field, op, value);
+    create_converter()
+        .convert(&ds)
+        .unwrap_err()
+        .code()
+        .to_string()
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_date_string_becomes_date_comparison() {
+    let condition = convert_one(
+        "date",
+        FilterOperator::Gte,
+        FilterValue::String("2024-01-01".to_string()),
+    );
+
+    assert_eq!(
+        condition,
+        QueryCondition::Gte {
+            field: "date".to_string(),
+            value: QueryValue::Date(date(2024, 1, 1)),
+        }
+    );
+}
+
+#[test]
+fn test_datetime_string_is_normalized_to_utc() {
+    let condition = convert_one(
+        "created_at",
+        FilterOperator::Lt,
+        FilterValue::String("2024-01-01T09:00:00+09:00".to_string()),
+    );
+
+    assert_eq!(
+        condition,
+        QueryCondition::Lt {
+            field: "created_at".to_string(),
+            value: QueryValue::DateTime(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+        }
+    );
+}
+
+#[test]
+fn test_integer_and_float_fields() {
+    let id = convert_one("id", FilterOperator::Eq, FilterValue::Number(7.0));
+    let amount = convert_one("amount", FilterOperator::Gt, FilterValue::Number(7.5));
+
+    assert_eq!(
+        id,
+        QueryCondition::Eq {
+            field: "id".to_string(),
+            value: QueryValue::Integer(7),
+        }
+    );
+    assert_eq!(
+        amount,
+        QueryCondition::Gt {
+            field: "amount".to_string(),
+            value: QueryValue::Float(7.5),
+        }
+    );
+}
+
+#[test]
+fn test_boolean_field_stays_boolean() {
+    let condition = convert_one("approved", FilterOperator::Eq, FilterValue::Boolean(true));
+
+    assert_eq!(
+        condition,
+        QueryCondition::Eq {
+            field: "approved".to_string(),
+            value: QueryValue::Boolean(true),
+        }
+    );
+}
+
+#[test]
+fn test_in_values_are_typed() {
+    let condition = convert_one(
+        "id",
+        FilterOperator::In,
+        FilterValue::Array(vec![
+            FilterValueScalar::Number(1.0),
+            FilterValueScalar::Number(2.0),
+        ]),
+    );
+
+    assert_eq!(
+        condition,
+        QueryCondition::In {
+            field: "id".to_string(),
+            values: vec![QueryValue::Integer(1), QueryValue::Integer(2)],
+        }
+    );
+}
+
+#[test]
+fn test_type_mismatches_are_rejected() {
+    let cases = [
+        ("date", FilterOperator::Eq, FilterValue::Number(20240101.0)),
+        (
+            "date",
+            FilterOperator::Gte,
+            FilterValue::String("01/02/2024".to_string()),
+        ),
+        ("id", FilterOperator::Eq, FilterValue::Number(1.5)),
+        (
+            "id",
+            FilterOperator::Eq,
+            FilterValue::String("1".to_string()),
+        ),
+        ("amount", FilterOperator::Gt, FilterValue::Boolean(true)),
+        (
+            "approved",
+            FilterOperator::Eq,
+            FilterValue::String("true".to_string()),
+        ),
+        ("category", FilterOperator::Eq, FilterValue::Number(1.0)),
+        (
+            "created_at",
+            FilterOperator::Lt,
+            FilterValue::String("noon".to_string()),
+        ),
+    ];
+
+    for (field, op, value) in cases {
+        assert_eq!(
+            convert_error(field, op.clone(), value.clone()),
+            "FILTER_TYPE_MISMATCH",
+            "{} {:?} {:?}",
+            field,
+            op,
+            value
+        );
+    }
+}
+
+#[test]
+fn test_ordering_on_boolean_field_is_rejected() {
+    let code = convert_error("approved", FilterOperator::Gt, FilterValue::Boolean(false));
+
+    assert_eq!(code, "FILTER_TYPE_MISMATCH");
+}
+
+#[test]
+fn test_contains_requires_string_field() {
+    let code = convert_error(
+        "date",
+        FilterOperator::Contains,
+        FilterValue::String("2024".to_string()),
+    );
+
+    assert_eq!(code, "FILTER_TYPE_MISMATCH");
+}
+
+#[test]
+fn test_in_with_mismatched_element_is_rejected() {
+    let code = convert_error(
+        "id",
+        FilterOperator::In,
+        FilterValue::Array(vec![
+            FilterValueScalar::Number(1.0),
+            FilterValueScalar::String("two".to_string()),
+        ]),
+    );
+
+    assert_eq!(code, "FILTER_TYPE_MISMATCH");
+}
+
+#[test]
+fn test_typed_values_are_bound_as_typed_params() {
+    let ds = create_data_source(
+        "date",
+        FilterOperator::Lte,
+        FilterValue::String("2024-03-31".to_string()),
+    );
+    let query = create_converter().convert(&ds).unwrap();
+
+    let sql = SqlBuilder::new().build(&query).unwrap();
+
+    assert_eq!(sql.sql(), "SELECT * FROM \"expenses\" WHERE \"date\" <= $1");
+    assert_eq!(sql.params(), &[SqlValue::Date(date(2024, 3, 31))]);
+}
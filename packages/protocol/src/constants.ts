@@ -19,19 +19,60 @@ export const VALID_FILTER_OPERATORS = [
   "lt",
   "lte",
   "in",
+  "not_in",
+  "between",
+  "is_null",
+  "is_not_null",
   "contains",
+  "icontains",
+  "starts_with",
+  "ends_with",
 ] as const;
 
 /**
  * Valid aggregation types for DataSource queries
+ * (percentile is the object form { percentile: p })
  */
-export const VALID_AGGREGATION_TYPES = ["sum", "avg", "count", "min", "max"] as const;
+export const VALID_AGGREGATION_TYPES = [
+  "sum",
+  "avg",
+  "count",
+  "min",
+  "max",
+  "count_distinct",
+  "median",
+] as const;
+
+/**
+ * Valid time bucket units for Aggregation.bucket
+ */
+export const VALID_TIME_UNITS = ["day", "week", "month", "quarter", "year"] as const;
 
 /**
  * Valid sort directions
  */
 export const VALID_SORT_DIRECTIONS = ["asc", "desc"] as const;
 
+/**
+ * Valid positions of null values in a sort
+ */
+export const VALID_SORT_NULLS = ["first", "last"] as const;
+
+/**
+ * Valid table pagination modes
+ */
+export const VALID_PAGINATION_MODES = ["offset", "cursor"] as const;
+
+/**
+ * Maximum nesting depth of filter groups (a top-level filter is depth 1)
+ */
+export const MAX_FILTER_DEPTH = 4;
+
+/**
+ * Maximum nesting depth of layouts (the root layout is depth 1)
+ */
+export const MAX_LAYOUT_DEPTH = 4;
+
 /**
  * Valid chart variants
  */
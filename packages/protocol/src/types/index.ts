@@ -39,6 +39,8 @@ export interface GridLayout {
   columns: number;
   /** Grid gap in pixels (default: 16) */
   gap?: number;
+  /** Nested layouts and components placed in the grid */
+  children?: LayoutChild[];
 }
 
 /**
@@ -50,8 +52,16 @@ export interface StackLayout {
   direction: "horizontal" | "vertical";
   /** Item spacing in pixels (default: 8) */
   gap?: number;
+  /** Nested layouts and components placed in the stack */
+  children?: LayoutChild[];
 }
 
+/**
+ * Layout child: either a nested layout or a component
+ * Both are tagged by `type` with disjoint values (grid/stack vs. chart/table)
+ */
+export type LayoutChild = Layout | Component;
+
 /**
  * UI Component types
  */
@@ -75,8 +85,8 @@ export interface ChartComponent extends BaseComponent {
   variant: ChartVariant;
   /** X-axis field name */
   xAxis?: string;
-  /** Y-axis field name */
-  yAxis?: string;
+  /** Y-axis field name, or a list of fields drawn as one series each */
+  yAxis?: string | string[];
 }
 
 export type ChartVariant = "bar" | "line" | "pie" | "area";
@@ -90,8 +100,15 @@ export interface TableComponent extends BaseComponent {
   columns: string[];
   /** Enable sorting (default: false) */
   sortable?: boolean;
+  /** Paging of the bound data_source (requires its limit) */
+  pagination?: PaginationMode;
 }
 
+/**
+ * Table paging: offset pages or backend-issued cursors
+ */
+export type PaginationMode = "offset" | "cursor";
+
 /**
  * Data source definition
  * Converted to ORM queries in backend
@@ -99,16 +116,39 @@ export interface TableComponent extends BaseComponent {
 export interface DataSource {
   /** Resource name (table/model name) */
   resource: string;
-  /** Filter conditions */
-  filters?: Filter[];
-  /** Aggregation method */
+  /** Filter conditions (all top-level nodes must match) */
+  filters?: FilterNode[];
+  /** Single aggregation (shorthand for one metric aliased `{field}_{type}`) */
   aggregation?: Aggregation;
-  /** Sort condition */
-  sort?: Sort;
-  /** Result limit */
+  /** Named metrics computed per group (cannot be combined with aggregation) */
+  metrics?: Metric[];
+  /** GROUP BY fields for metrics */
+  group_by?: string[];
+  /** Sort condition (a list orders by each key in turn) */
+  sort?: Sort | Sort[];
+  /** Result limit (the page size when paging) */
   limit?: number;
+  /** Page to fetch (requires limit) */
+  page?: Page;
 }
 
+/**
+ * Page of a data source's results
+ * Cursors are opaque tokens signed by the backend; they only resume the
+ * query (and user) they were issued for
+ */
+export type Page = { offset: number } | { after: string };
+
+/**
+ * Node of a boolean filter tree
+ * Groups are single-key objects; anything else is a Filter
+ */
+export type FilterNode =
+  | { and: FilterNode[] } // All child nodes must match
+  | { or: FilterNode[] } // At least one child node must match
+  | { not: FilterNode } // The child node must not match
+  | Filter;
+
 /**
  * Filter condition
  */
@@ -117,10 +157,31 @@ export interface Filter {
   field: string;
   /** Filter operator */
   op: FilterOperator;
-  /** Filter value */
-  value: string | number | boolean | Array<string | number>;
+  /** Filter value (omitted for "is_null" / "is_not_null"; "between" takes [low, high]) */
+  value?: string | number | boolean | Array<string | number> | RelativeDate;
 }
 
+/**
+ * Relative date kinds
+ */
+export type RelativeDateKind =
+  | "today" // Start of today
+  | "yesterday" // Start of yesterday
+  | "start_of_week" // Start of the current week (Monday)
+  | "start_of_month" // First day of the current month
+  | "start_of_quarter" // First day of the current quarter
+  | "start_of_year" // First day of the current year
+  | "last_n_days" // Start of the day n days before today
+  | "last_n_months"; // Same day n months before today
+
+/**
+ * Date relative to the time the query runs, resolved by the backend
+ * in the dashboard timezone (e.g. { relative: "last_n_days", n: 30 })
+ */
+export type RelativeDate =
+  | { relative: Exclude<RelativeDateKind, "last_n_days" | "last_n_months"> }
+  | { relative: "last_n_days" | "last_n_months"; n: number };
+
 /**
  * Filter operators
  */
@@ -132,7 +193,14 @@ export type FilterOperator =
   | "lt" // Less than
   | "lte" // Less than or equal
   | "in" // In array
-  | "contains"; // Partial match
+  | "not_in" // Not in array
+  | "between" // Inclusive range [low, high]
+  | "is_null" // Value is NULL (no value)
+  | "is_not_null" // Value is not NULL (no value)
+  | "contains" // Partial match (case-sensitive)
+  | "icontains" // Partial match (case-insensitive)
+  | "starts_with" // Prefix match (case-sensitive)
+  | "ends_with"; // Suffix match (case-sensitive)
 
 /**
  * Aggregation method
@@ -144,9 +212,46 @@ export interface Aggregation {
   field: string;
   /** GROUP BY field (optional) */
   by?: string;
+  /** Truncate the `by` date/timestamp field to calendar buckets (optional) */
+  bucket?: TimeBucket;
+}
+
+/**
+ * Calendar bucket applied to the GROUP BY field
+ * Each group is keyed by the first day of its bucket (YYYY-MM-DD)
+ */
+export interface TimeBucket {
+  /** Bucket size */
+  unit: TimeUnit;
+  /** IANA timezone the calendar is evaluated in (default: the backend timezone) */
+  timezone?: string;
+  /** Emit empty buckets between the first and last group (default: false) */
+  zero_fill?: boolean;
 }
 
-export type AggregationType = "sum" | "avg" | "count" | "min" | "max";
+export type TimeUnit = "day" | "week" | "month" | "quarter" | "year";
+
+/**
+ * Named aggregated value
+ */
+export interface Metric {
+  /** Output column name (referenced by chart axes, table columns and sort) */
+  alias: string;
+  /** Aggregation type */
+  type: AggregationType;
+  /** Target field for aggregation */
+  field: string;
+}
+
+export type AggregationType =
+  | "sum" // Sum
+  | "avg" // Average
+  | "count" // Number of rows
+  | "min" // Minimum
+  | "max" // Maximum
+  | "count_distinct" // Number of distinct non-null values
+  | "median" // Continuous median
+  | { percentile: number }; // Continuous percentile, p in 0..=100 (e.g. { percentile: 90 })
 
 /**
  * Sort condition
@@ -156,8 +261,15 @@ export interface Sort {
   field: string;
   /** Sort direction */
   direction: "asc" | "desc";
+  /** Position of null values (database default when omitted) */
+  nulls?: NullsOrder;
 }
 
+/**
+ * Position of null values in a sort
+ */
+export type NullsOrder = "first" | "last";
+
 /**
  * Type guards
  */
@@ -200,6 +312,8 @@ export enum ValidationErrorCode {
   MISSING_RESOURCE = "MISSING_RESOURCE",
   INVALID_FILTER_OP = "INVALID_FILTER_OP",
   INVALID_AGGREGATION_TYPE = "INVALID_AGGREGATION_TYPE",
+  INVALID_PERCENTILE = "INVALID_PERCENTILE",
+  INVALID_TIME_UNIT = "INVALID_TIME_UNIT",
   INVALID_GRID_COLUMNS = "INVALID_GRID_COLUMNS",
   INVALID_CHART_VARIANT = "INVALID_CHART_VARIANT",
   EMPTY_TABLE_COLUMNS = "EMPTY_TABLE_COLUMNS",
@@ -210,7 +324,13 @@ export enum ValidationErrorCode {
   MISSING_AGGREGATION_FIELD = "MISSING_AGGREGATION_FIELD",
   INVALID_SORT_DIRECTION = "INVALID_SORT_DIRECTION",
   MISSING_SORT_FIELD = "MISSING_SORT_FIELD",
+  INVALID_SORT_NULLS = "INVALID_SORT_NULLS",
   INVALID_LIMIT = "INVALID_LIMIT",
+  INVALID_PAGE = "INVALID_PAGE",
+  INVALID_PAGINATION_MODE = "INVALID_PAGINATION_MODE",
+  FILTER_TOO_DEEP = "FILTER_TOO_DEEP",
+  EMPTY_FILTER_GROUP = "EMPTY_FILTER_GROUP",
+  LAYOUT_TOO_DEEP = "LAYOUT_TOO_DEEP",
 }
 
 /**
@@ -8,14 +8,45 @@ import {
   type ValidationError,
   ValidationErrorCode,
 } from "../types/index";
+import { MAX_FILTER_DEPTH, MAX_LAYOUT_DEPTH } from "../constants";
 
 const SUPPORTED_VERSIONS = ["1.0"] as const;
 const VALID_LAYOUT_TYPES = ["grid", "stack"] as const;
 const VALID_COMPONENT_TYPES = ["chart", "table"] as const;
 const VALID_CHART_VARIANTS = ["bar", "line", "pie", "area"] as const;
-const VALID_FILTER_OPERATORS = ["eq", "neq", "gt", "gte", "lt", "lte", "in", "contains"] as const;
-const VALID_AGGREGATION_TYPES = ["sum", "avg", "count", "min", "max"] as const;
+const VALID_FILTER_OPERATORS = [
+  "eq",
+  "neq",
+  "gt",
+  "gte",
+  "lt",
+  "lte",
+  "in",
+  "not_in",
+  "between",
+  "is_null",
+  "is_not_null",
+  "contains",
+  "icontains",
+  "starts_with",
+  "ends_with",
+] as const;
+const ARRAY_FILTER_OPERATORS = ["in", "not_in"];
+const VALUELESS_FILTER_OPERATORS = ["is_null", "is_not_null"];
+const FILTER_GROUP_KEYS = ["and", "or", "not"];
+const VALID_AGGREGATION_TYPES = [
+  "sum",
+  "avg",
+  "count",
+  "min",
+  "max",
+  "count_distinct",
+  "median",
+] as const;
+const VALID_TIME_UNITS = ["day", "week", "month", "quarter", "year"] as const;
 const VALID_SORT_DIRECTIONS = ["asc", "desc"] as const;
+const VALID_SORT_NULLS = ["first", "last"] as const;
+const VALID_PAGINATION_MODES = ["offset", "cursor"] as const;
 
 export class SchemaValidator {
   /**
@@ -80,7 +111,7 @@ export class SchemaValidator {
 
     // Validate layout
     if (s.layout) {
-      this.validateLayout(s.layout, errors);
+      this.validateLayout(s.layout, errors, "layout", 1);
     }
 
     // Validate components
@@ -97,7 +128,15 @@ export class SchemaValidator {
 
     // Cross-reference validation: Check data_source references
     if (s.components && s.data_sources) {
-      this.validateDataSourceReferences(s.components, s.data_sources, errors);
+      this.validateDataSourceReferences(s.components, s.data_sources, errors, "components");
+    }
+    if (s.layout && Array.isArray(s.layout.children) && s.data_sources) {
+      this.validateDataSourceReferences(
+        s.layout.children,
+        s.data_sources,
+        errors,
+        "layout.children"
+      );
     }
 
     return {
@@ -120,14 +159,27 @@ export class SchemaValidator {
   }
 
   /**
-   * Validates layout structure
+   * Validates layout structure and its nested children
    */
-  private validateLayout(layout: any, errors: ValidationError[]): void {
+  private validateLayout(
+    layout: any,
+    errors: ValidationError[],
+    path: string,
+    depth: number
+  ): void {
+    if (depth > MAX_LAYOUT_DEPTH) {
+      errors.push({
+        code: ValidationErrorCode.LAYOUT_TOO_DEEP,
+        message: `Layout nesting exceeds maximum depth of ${MAX_LAYOUT_DEPTH}`,
+        path,
+      });
+      return;
+    }
     if (!layout.type || !VALID_LAYOUT_TYPES.includes(layout.type)) {
       errors.push({
         code: ValidationErrorCode.INVALID_LAYOUT_TYPE,
         message: `Invalid layout type: ${layout.type}. Valid types: ${VALID_LAYOUT_TYPES.join(", ")}`,
-        path: "layout.type",
+        path: `${path}.type`,
       });
       return;
     }
@@ -139,11 +191,37 @@ export class SchemaValidator {
           errors.push({
             code: ValidationErrorCode.INVALID_GRID_COLUMNS,
             message: `Grid columns must be >= 1, got: ${layout.columns}`,
-            path: "layout.columns",
+            path: `${path}.columns`,
           });
         }
       }
     }
+
+    // Validate children (nested layouts or components)
+    if (layout.children !== undefined) {
+      if (!Array.isArray(layout.children)) {
+        errors.push({
+          code: ValidationErrorCode.INVALID_TYPE,
+          message: "Layout children must be an array",
+          path: `${path}.children`,
+        });
+        return;
+      }
+      layout.children.forEach((child: any, index: number) => {
+        const childPath = `${path}.children[${index}]`;
+        if (typeof child !== "object" || child === null) {
+          errors.push({
+            code: ValidationErrorCode.INVALID_TYPE,
+            message: "Layout child must be an object",
+            path: childPath,
+          });
+        } else if (isLayoutNode(child)) {
+          this.validateLayout(child, errors, childPath, depth + 1);
+        } else {
+          this.validateComponent(child, errors, childPath);
+        }
+      });
+    }
   }
 
   /**
@@ -179,6 +257,16 @@ export class SchemaValidator {
           path: `${path}.columns`,
         });
       }
+      if (
+        component.pagination !== undefined &&
+        !VALID_PAGINATION_MODES.includes(component.pagination)
+      ) {
+        errors.push({
+          code: ValidationErrorCode.INVALID_PAGINATION_MODE,
+          message: `Invalid pagination mode: ${component.pagination}. Valid values: ${VALID_PAGINATION_MODES.join(", ")}`,
+          path: `${path}.pagination`,
+        });
+      }
     }
   }
 
@@ -215,8 +303,8 @@ export class SchemaValidator {
 
     // Validate filters
     if (ds.filters && Array.isArray(ds.filters)) {
-      ds.filters.forEach((filter: any, index: number) => {
-        this.validateFilter(filter, errors, `${path}.filters[${index}]`);
+      ds.filters.forEach((node: any, index: number) => {
+        this.validateFilterNode(node, errors, `${path}.filters[${index}]`, 1);
       });
     }
 
@@ -225,8 +313,26 @@ export class SchemaValidator {
       this.validateAggregation(ds.aggregation, errors, `${path}.aggregation`);
     }
 
-    // Validate sort
-    if (ds.sort) {
+    // Validate metrics
+    if (ds.metrics && Array.isArray(ds.metrics)) {
+      ds.metrics.forEach((metric: any, index: number) => {
+        this.validateAggregation(metric, errors, `${path}.metrics[${index}]`);
+        if (!metric.alias) {
+          errors.push({
+            code: ValidationErrorCode.MISSING_AGGREGATION_FIELD,
+            message: "Metric must have an alias",
+            path: `${path}.metrics[${index}].alias`,
+          });
+        }
+      });
+    }
+
+    // Validate sort (one key or a list of keys)
+    if (Array.isArray(ds.sort)) {
+      ds.sort.forEach((sort: any, index: number) => {
+        this.validateSort(sort, errors, `${path}.sort[${index}]`);
+      });
+    } else if (ds.sort) {
       this.validateSort(ds.sort, errors, `${path}.sort`);
     }
 
@@ -234,6 +340,75 @@ export class SchemaValidator {
     if (ds.limit !== undefined) {
       this.validateLimit(ds.limit, errors, `${path}.limit`);
     }
+
+    // Validate page
+    if (ds.page !== undefined) {
+      this.validatePage(ds.page, errors, `${path}.page`);
+    }
+  }
+
+  /**
+   * Validates a filter tree node: an and/or/not group or a leaf Filter
+   */
+  private validateFilterNode(
+    node: any,
+    errors: ValidationError[],
+    path: string,
+    depth: number
+  ): void {
+    if (depth > MAX_FILTER_DEPTH) {
+      errors.push({
+        code: ValidationErrorCode.FILTER_TOO_DEEP,
+        message: `Filter nesting exceeds maximum depth of ${MAX_FILTER_DEPTH}`,
+        path,
+      });
+      return;
+    }
+    if (typeof node !== "object" || node === null || Array.isArray(node)) {
+      errors.push({
+        code: ValidationErrorCode.INVALID_TYPE,
+        message: "Filter must be an object",
+        path,
+      });
+      return;
+    }
+    if (!FILTER_GROUP_KEYS.some((key) => key in node)) {
+      this.validateFilter(node, errors, path);
+      return;
+    }
+
+    // Groups are single-key objects
+    const keys = Object.keys(node);
+    if (keys.length !== 1) {
+      errors.push({
+        code: ValidationErrorCode.INVALID_TYPE,
+        message: "Filter group must have exactly one of: and, or, not",
+        path,
+      });
+      return;
+    }
+    const group = keys[0];
+    const children = node[group];
+    const groupPath = `${path}.${group}`;
+    if (group === "not") {
+      this.validateFilterNode(children, errors, groupPath, depth + 1);
+    } else if (!Array.isArray(children)) {
+      errors.push({
+        code: ValidationErrorCode.INVALID_TYPE,
+        message: `Filter group "${group}" must be an array`,
+        path: groupPath,
+      });
+    } else if (children.length === 0) {
+      errors.push({
+        code: ValidationErrorCode.EMPTY_FILTER_GROUP,
+        message: `Filter group "${group}" must contain at least one filter`,
+        path: groupPath,
+      });
+    } else {
+      children.forEach((child: any, index: number) => {
+        this.validateFilterNode(child, errors, `${groupPath}[${index}]`, depth + 1);
+      });
+    }
   }
 
   /**
@@ -257,7 +432,7 @@ export class SchemaValidator {
       });
     }
 
-    if (filter.value === undefined) {
+    if (filter.value === undefined && !VALUELESS_FILTER_OPERATORS.includes(filter.op)) {
       errors.push({
         code: ValidationErrorCode.MISSING_FILTER_FIELD,
         message: "Filter must have a value property",
@@ -279,12 +454,30 @@ export class SchemaValidator {
     if (filter.op && filter.value !== undefined) {
       const isArray = Array.isArray(filter.value);
 
-      if (filter.op === "in") {
-        // "in" operator requires array value
+      if (ARRAY_FILTER_OPERATORS.includes(filter.op)) {
+        // "in" / "not_in" operators require array value
         if (!isArray) {
           errors.push({
             code: ValidationErrorCode.INVALID_FILTER_VALUE_TYPE,
-            message: `Filter operator "in" requires an array value`,
+            message: `Filter operator "${filter.op}" requires an array value`,
+            path: `${path}.value`,
+          });
+        }
+      } else if (filter.op === "between") {
+        // "between" operator requires [low, high]
+        if (!isArray || filter.value.length !== 2) {
+          errors.push({
+            code: ValidationErrorCode.INVALID_FILTER_VALUE_TYPE,
+            message: `Filter operator "between" requires a [low, high] array value`,
+            path: `${path}.value`,
+          });
+        }
+      } else if (VALUELESS_FILTER_OPERATORS.includes(filter.op)) {
+        // "is_null" / "is_not_null" operators take no value
+        if (filter.value !== null) {
+          errors.push({
+            code: ValidationErrorCode.INVALID_FILTER_VALUE_TYPE,
+            message: `Filter operator "${filter.op}" does not take a value`,
             path: `${path}.value`,
           });
         }
@@ -323,14 +516,41 @@ export class SchemaValidator {
       });
     }
 
-    // Validate aggregation type
-    if (aggregation.type && !VALID_AGGREGATION_TYPES.includes(aggregation.type)) {
+    // Validate aggregation type ({ percentile: p } takes p in 0..=100)
+    if (aggregation.type && typeof aggregation.type === "object" && "percentile" in aggregation.type) {
+      const percentile = aggregation.type.percentile;
+      if (typeof percentile !== "number" || percentile < 0 || percentile > 100) {
+        errors.push({
+          code: ValidationErrorCode.INVALID_PERCENTILE,
+          message: `Percentile must be between 0 and 100, got ${percentile}`,
+          path: `${path}.type`,
+        });
+      }
+    } else if (aggregation.type && !VALID_AGGREGATION_TYPES.includes(aggregation.type)) {
       errors.push({
         code: ValidationErrorCode.INVALID_AGGREGATION_TYPE,
         message: `Invalid aggregation type: ${aggregation.type}. Valid types: ${VALID_AGGREGATION_TYPES.join(", ")}`,
         path: `${path}.type`,
       });
     }
+
+    // Validate time bucket (truncates the GROUP BY field)
+    if (aggregation.bucket) {
+      if (!aggregation.by) {
+        errors.push({
+          code: ValidationErrorCode.MISSING_AGGREGATION_FIELD,
+          message: "Aggregation with a bucket must have a by field",
+          path: `${path}.by`,
+        });
+      }
+      if (!VALID_TIME_UNITS.includes(aggregation.bucket.unit)) {
+        errors.push({
+          code: ValidationErrorCode.INVALID_TIME_UNIT,
+          message: `Invalid time bucket unit: ${aggregation.bucket.unit}. Valid units: ${VALID_TIME_UNITS.join(", ")}`,
+          path: `${path}.bucket.unit`,
+        });
+      }
+    }
   }
 
   /**
@@ -363,6 +583,15 @@ export class SchemaValidator {
         path: `${path}.direction`,
       });
     }
+
+    // Validate nulls position
+    if (sort.nulls !== undefined && !VALID_SORT_NULLS.includes(sort.nulls)) {
+      errors.push({
+        code: ValidationErrorCode.INVALID_SORT_NULLS,
+        message: `Invalid sort nulls position: ${sort.nulls}. Valid values: ${VALID_SORT_NULLS.join(", ")}`,
+        path: `${path}.nulls`,
+      });
+    }
   }
 
   /**
@@ -389,26 +618,63 @@ export class SchemaValidator {
     }
   }
 
+  /**
+   * Validates Page ({ offset: n } or { after: cursor })
+   */
+  private validatePage(page: any, errors: ValidationError[], path: string): void {
+    const keys = typeof page === "object" && page !== null ? Object.keys(page) : [];
+    const valid =
+      keys.length === 1 &&
+      ((keys[0] === "offset" && Number.isInteger(page.offset) && page.offset >= 0) ||
+        (keys[0] === "after" && typeof page.after === "string"));
+
+    if (!valid) {
+      errors.push({
+        code: ValidationErrorCode.INVALID_PAGE,
+        message: 'Page must be { "offset": n } or { "after": cursor }',
+        path,
+      });
+    }
+  }
+
   /**
    * Validates that all data_source references in components exist
+   * (including components inside nested layouts)
    */
   private validateDataSourceReferences(
     components: any[],
     dataSources: any,
-    errors: ValidationError[]
+    errors: ValidationError[],
+    path: string
   ): void {
     if (!Array.isArray(components)) return;
 
     components.forEach((component: any, index: number) => {
-      if (component.data_source) {
+      const componentPath = `${path}[${index}]`;
+      if (typeof component !== "object" || component === null) return;
+      if (isLayoutNode(component)) {
+        this.validateDataSourceReferences(
+          component.children,
+          dataSources,
+          errors,
+          `${componentPath}.children`
+        );
+      } else if (component.data_source) {
         if (!dataSources[component.data_source]) {
           errors.push({
             code: ValidationErrorCode.DANGLING_DATA_SOURCE_REF,
             message: `Component references non-existent data_source: ${component.data_source}`,
-            path: `components[${index}].data_source`,
+            path: `${componentPath}.data_source`,
           });
         }
       }
     });
   }
 }
+
+/**
+ * Layout children are nested layouts when tagged with a layout type
+ */
+function isLayoutNode(child: any): boolean {
+  return VALID_LAYOUT_TYPES.includes(child.type);
+}
@@ -14,6 +14,7 @@ import {
   isTableComponent,
 } from "../src/types/index.js";
 import { SchemaValidator } from "../src/validators/schema.js";
+import { MAX_FILTER_DEPTH, MAX_LAYOUT_DEPTH } from "../src/constants.js";
 import {
   createBaseSchema,
   createGridLayout,
@@ -556,6 +557,171 @@ describe("SchemaValidator", () => {
 
     validateAndExpectError(validator, schema, ValidationErrorCode.INVALID_LIMIT);
   });
+
+  /**
+   * Test 34: ✅ Nested and/or/not filter groups
+   */
+  it("should validate nested filter groups", () => {
+    const schema = createBaseSchema({
+      data_sources: {
+        test_ds: createDataSource("expenses", {
+          filters: [
+            {
+              or: [
+                { field: "category", op: "eq", value: "travel" },
+                {
+                  and: [
+                    { field: "amount", op: "gte", value: 1000 },
+                    { not: { field: "status", op: "eq", value: "draft" } },
+                  ],
+                },
+              ],
+            },
+          ],
+        }),
+      },
+    });
+
+    validateAndExpectValid(validator, schema);
+  });
+
+  /**
+   * Test 35: ❌ Invalid filter inside a group
+   */
+  it("should validate filters inside groups", () => {
+    const schema = createBaseSchema({
+      data_sources: {
+        test_ds: createDataSource("expenses", {
+          filters: [{ or: [{ field: "amount", op: "invalid_op", value: 1 } as any] }],
+        }),
+      },
+    });
+
+    const result = validateAndExpectError(validator, schema, ValidationErrorCode.INVALID_FILTER_OP);
+    expect(result.errors[0].path).toBe("data_sources.test_ds.filters[0].or[0].op");
+  });
+
+  /**
+   * Test 36: ❌ Empty filter group
+   */
+  it("should reject empty filter groups", () => {
+    const schema = createBaseSchema({
+      data_sources: {
+        test_ds: createDataSource("expenses", {
+          filters: [{ and: [] }],
+        }),
+      },
+    });
+
+    const result = validateAndExpectError(
+      validator,
+      schema,
+      ValidationErrorCode.EMPTY_FILTER_GROUP
+    );
+    expect(result.errors[0].path).toBe("data_sources.test_ds.filters[0].and");
+  });
+
+  /**
+   * Test 37: ❌ Filter groups nested deeper than MAX_FILTER_DEPTH
+   */
+  it("should reject filter groups nested too deeply", () => {
+    let node: any = { field: "amount", op: "gt", value: 0 };
+    for (let depth = 0; depth < MAX_FILTER_DEPTH; depth++) {
+      node = { not: node };
+    }
+    const schema = createBaseSchema({
+      data_sources: {
+        test_ds: createDataSource("expenses", { filters: [node] }),
+      },
+    });
+
+    validateAndExpectError(validator, schema, ValidationErrorCode.FILTER_TOO_DEEP);
+  });
+
+  /**
+   * Test 38: ✅ Components inside nested layouts
+   */
+  it("should validate components inside nested layouts", () => {
+    const schema = createBaseSchema({
+      layout: {
+        ...createGridLayout(2),
+        children: [
+          {
+            ...createStackLayout("vertical"),
+            children: [createTableComponent(["category"], { data_source: "ds_expenses" })],
+          },
+          createChartComponent("bar", { data_source: "ds_expenses" }),
+        ],
+      },
+      data_sources: {
+        ds_expenses: createDataSource("expenses"),
+      },
+    });
+
+    validateAndExpectValid(validator, schema);
+  });
+
+  /**
+   * Test 39: ❌ Invalid component inside a nested layout
+   */
+  it("should report recursive paths for errors inside nested layouts", () => {
+    const schema = createBaseSchema({
+      layout: {
+        ...createGridLayout(2),
+        children: [
+          {
+            ...createStackLayout("vertical"),
+            children: [
+              createChartComponent("scatter" as any),
+              createTableComponent(["category"], { data_source: "missing" }),
+            ],
+          },
+        ],
+      },
+    });
+
+    const result = validator.validate(schema);
+    expect(result.valid).toBe(false);
+    expect(result.errors).toEqual([
+      expect.objectContaining({
+        code: ValidationErrorCode.INVALID_CHART_VARIANT,
+        path: "layout.children[0].children[0].variant",
+      }),
+      expect.objectContaining({
+        code: ValidationErrorCode.DANGLING_DATA_SOURCE_REF,
+        path: "layout.children[0].children[1].data_source",
+      }),
+    ]);
+  });
+
+  /**
+   * Test 40: ✅ Layouts nested up to MAX_LAYOUT_DEPTH
+   */
+  it("should allow layouts nested up to the maximum depth", () => {
+    let layout: any = { ...createGridLayout(1), children: [] };
+    for (let depth = 1; depth < MAX_LAYOUT_DEPTH; depth++) {
+      layout = { ...createStackLayout("vertical"), children: [layout] };
+    }
+
+    validateAndExpectValid(validator, createBaseSchema({ layout }));
+  });
+
+  /**
+   * Test 41: ❌ Layouts nested deeper than MAX_LAYOUT_DEPTH
+   */
+  it("should reject layouts nested too deeply", () => {
+    let layout: any = { ...createGridLayout(1), children: [] };
+    for (let depth = 0; depth < MAX_LAYOUT_DEPTH; depth++) {
+      layout = { ...createStackLayout("vertical"), children: [layout] };
+    }
+
+    const result = validateAndExpectError(
+      validator,
+      createBaseSchema({ layout }),
+      ValidationErrorCode.LAYOUT_TOO_DEEP
+    );
+    expect(result.errors[0].path).toBe(`layout${".children[0]".repeat(MAX_LAYOUT_DEPTH)}`);
+  });
 });
 
 /**